@@ -1,6 +1,6 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractevent, contractimpl, Address, Env};
+use soroban_sdk::{contract, contractevent, contractimpl, Address, Env, Vec};
 
 /// Mock Game Hub contract for game studio development
 ///
@@ -26,6 +26,12 @@ pub struct GameEnded {
     pub player1_won: bool,
 }
 
+#[contractevent]
+pub struct EliminationsReported {
+    pub session_id: u32,
+    pub eliminated: Vec<Address>,
+}
+
 #[contractimpl]
 impl MockGameHub {
     /// Start a game session
@@ -77,6 +83,30 @@ impl MockGameHub {
         }
         .publish(&env);
     }
+
+    /// Report every player eliminated in a finished session
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session that ended
+    /// * `eliminated` - Eliminated players, in elimination order
+    pub fn report_eliminations(env: Env, session_id: u32, eliminated: Vec<Address>) {
+        // No auth required for mock
+        EliminationsReported {
+            session_id,
+            eliminated,
+        }
+        .publish(&env);
+    }
+
+    /// Approve or veto a session before it starts
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `session_id` - The game session about to start
+    pub fn can_start(_env: Env, _game_id: Address, _session_id: u32) -> bool {
+        // Mock approves every session
+        true
+    }
 }
 
 #[cfg(test)]
@@ -93,5 +123,7 @@ mod test {
         let player2 = Address::generate(&env);
         client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
         client.end_game(&1, &true);
+        client.report_eliminations(&1, &soroban_sdk::vec![&env, player2]);
+        assert!(client.can_start(&game_id, &2));
     }
 }
@@ -19,8 +19,10 @@
 //! Calls `start_game()` / `end_game()` on the hackathon Game Hub.
 
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, Vec, contract, contractclient, contracterror,
-    contractimpl, contracttype, log, symbol_short,
+    Address, Bytes, BytesN, Env, Executable, IntoVal, Map, Symbol, TryFromVal, Val, Vec, contract,
+    contractclient, contracterror, contractevent, contractimpl, contracttype, log, symbol_short,
+    token, xdr::FromXdr,
+    crypto::bn254::{Bn254G1Affine, Bn254G2Affine, Fr},
 };
 
 // ============================================================================
@@ -39,26 +41,135 @@ pub trait GameHub {
         player2_points: i128,
     );
     fn end_game(env: Env, session_id: u32, player1_won: bool);
+    fn can_start(env: Env, game_id: Address, session_id: u32) -> bool;
+    fn report_eliminations(env: Env, session_id: u32, eliminated: Vec<Address>);
 }
 
 // ============================================================================
 // Constants
 // ============================================================================
 
+/// Release number reported by `get_version`; bump with every deployed
+/// WASM so clients can confirm an `upgrade` took effect
+const CONTRACT_VERSION: u32 = 1;
+
 /// Min players to start a game
 const MIN_PLAYERS: u32 = 2;
 
 /// Max players per session
 const MAX_PLAYERS: u32 = 3;
 
+/// Lobby size in team sessions (2v2)
+const TEAM_MAX_PLAYERS: u32 = 4;
+
+/// Most lobbies `get_open_lobbies` lists; the oldest drops off first
+const MAX_OPEN_LOBBIES: u32 = 100;
+
+/// Session ids per page of the active-session index
+const SESSION_PAGE_SIZE: u32 = 50;
+
 /// Number of chambers in the revolver
 const NUM_CHAMBERS: u32 = 6;
 
+/// Number of selectable avatar slots
+const NUM_AVATARS: u32 = 16;
+
+/// Sudden death never shrinks the cylinder below this many chambers
+const MIN_CHAMBERS: u32 = 1;
+
+/// Proof systems a session can require in `disparar`
+pub const PROOF_SYSTEM_FALLBACK: u32 = 0; // Non-zero structural check
+pub const PROOF_SYSTEM_GROTH16: u32 = 1;  // Groth16 over BN254
+
+/// Groth16 public inputs: the bullet commitment and the fired chamber
+const GROTH16_PUBLIC_INPUTS: u32 = 2;
+
+/// BN254 base field modulus, as little-endian 64-bit limbs
+const BN254_P: [u64; 4] = [
+    0x3c208c16d87cfd47,
+    0x97816a916871ca8d,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+
+/// `b` of the G2 twist `y² = x³ + 3 / (9 + i)`, as `(c0, c1)` limbs
+const BN254_G2_B: ([u64; 4], [u64; 4]) = (
+    [0x3267e6dc24a138e5, 0xb5b4c5e559dbefa3, 0x81be18991be06ac3, 0x2b149d40ceb8aaae],
+    [0xe4a2bd0685c315d2, 0xa74fa084e52d1852, 0xcd2cafadeed8fdf4, 0x009713b03af0fed4],
+);
+
+/// Cylinder visibility modes
+pub const MODE_STATIC: u32 = 0; // Practice: bullet position is public
+pub const MODE_HIDDEN: u32 = 1; // Competitive: queries/events never expose it
+
+/// `bullet_position` as `get_game` reports it while a hidden-mode game
+/// is unfinished
+pub const BULLET_POSITION_HIDDEN: u32 = u32::MAX;
+
+/// Event naming themes (mechanics are identical)
+pub const THEME_ROULETTE: u32 = 0; // boom / click / loaded ...
+pub const THEME_NEUTRAL: u32 = 1;  // out / safe / ready ... for platforms avoiding the theme
+
+/// How the winner receives the pot
+pub const PAYOUT_AUTO: u32 = 0;  // Pushed to the winner when the game ends (or the honor shot lands)
+pub const PAYOUT_CLAIM: u32 = 1; // Held until the winner calls `reclamar_premio`
+
+/// What an elimination-driven reload does to the cylinder
+pub const RELOAD_RESET: u32 = 0;    // Fresh cylinder: back to chamber 0, any position
+pub const RELOAD_CONTINUE: u32 = 1; // Keep turning: the bullet lands in an unfired chamber
+
+/// Who shoots first, and the order turns follow
+pub const TURN_ORDER_JOIN: u32 = 0;        // Seat (join) order
+pub const TURN_ORDER_POINTS_DESC: u32 = 1; // High roller shoots first
+pub const TURN_ORDER_POINTS_ASC: u32 = 2;  // Lowest points shoots first
+
+/// What a player must do next, as reported by `get_pending_actions`
+pub const ACTION_NONE: u32 = 0;
+pub const ACTION_SHOOT: u32 = 1;  // Their turn in `disparar`
+pub const ACTION_READY: u32 = 2;  // Reserved: the lobby has no ready check yet
+pub const ACTION_REVEAL: u32 = 3; // Host still owes `revelar_bala`
+pub const ACTION_STAKE: u32 = 4;  // Stakes are enabled and they haven't placed one
+pub const ACTION_RELOAD: u32 = 5; // Host owes `recargar` for the next cylinder
+pub const ACTION_CONFIRM: u32 = 6; // Their hit awaits `confirmar_eliminacion`
+
+/// What a `disparar` call did
+pub const SHOT_SURVIVED: u32 = 0;       // Click: the shooter lives and the turn moves on
+pub const SHOT_HIT: u32 = 1;            // Bang: the shooter was hit
+pub const SHOT_PROOF_REJECTED: u32 = 2; // Bad proof counted as an attempt; no shot, same turn
+pub const SHOT_KICKED: u32 = 3;         // Bad proof used the last attempt; the shooter was kicked
+
+/// How `resolver_manualmente` distributes the pot
+pub const RESOLVE_WINNER_TAKES_ALL: u32 = 0; // As if the declared winner won normally
+pub const RESOLVE_REFUND_ALL: u32 = 1;       // Every player gets their stakes back
+pub const RESOLVE_SPLIT_SURVIVORS: u32 = 2;  // Pot shared evenly by the players still alive
+
 /// Game state phases
 pub const PHASE_WAITING: u32 = 0;  // EsperandoPerkin
 pub const PHASE_PLAYING: u32 = 1;  // EnJuego
 pub const PHASE_FINISHED: u32 = 2; // Terminado
 
+/// Most tactical timeouts a session may grant each player
+const MAX_TACTICAL_TIMEOUTS: u32 = 3;
+
+/// Most points a player may join with, keeping sums over a table of
+/// joiners far from overflow
+const MAX_JOIN_POINTS: i128 = 1_000_000_000_000_000_000;
+
+/// Most recent wins kept per player by `get_player_wins`
+const MAX_WINS_HISTORY: u32 = 50;
+
+/// Most recent commitments `reject_reused_commitments` remembers; the
+/// oldest is forgotten once more are loaded
+const MAX_TRACKED_COMMITMENTS: u32 = 64;
+
+/// Default `skip_vote_idle_ledgers`: ~25 seconds of silence before a
+/// skip vote may open
+const DEFAULT_SKIP_VOTE_IDLE_LEDGERS: u32 = 5;
+
+/// Ledgers the host has to `recargar` when turns aren't timed (~1 hour);
+/// after that any living player may reload with `recargar_por_defecto`
+const RELOAD_TIMEOUT_LEDGERS: u32 = 720;
+
 /// Storage TTL — 30 days (~518,400 ledgers at 5s each)
 const GAME_TTL_LEDGERS: u32 = 518_400;
 
@@ -82,6 +193,44 @@ pub enum Error {
     InvalidChamber = 10,
     NotEnoughPlayers = 11,
     AlreadyStarted = 12,
+    InvalidAvatar = 13,
+    TurnNotExpired = 14,
+    InvalidConfig = 15,
+    NotWinner = 16,
+    HonorUnavailable = 17,
+    NothingToClaim = 18,
+    WrongMode = 19,
+    PendingReveal = 20,
+    InconsistentState = 21,
+    TokenNotAllowed = 22,
+    InvalidStake = 23,
+    AlreadyRevealed = 24,
+    DevModeDisabled = 25,
+    EliminationPending = 26,
+    NoPendingElimination = 27,
+    InsuranceUnavailable = 28,
+    UnknownSchema = 29,
+    NoTimeoutsLeft = 30,
+    CommitmentMismatch = 31,
+    InvalidReloadProof = 32,
+    AwaitingReload = 33,
+    UnknownHub = 34,
+    InvalidPoints = 35,
+    ExceedsRecoverable = 36,
+    TooManyActiveGames = 37,
+    HubRejected = 38,
+    RevealTooEarly = 39,
+    AvatarTaken = 40,
+    UnsupportedProofSystem = 41,
+    RateLimited = 42,
+    AlreadyVoted = 43,
+    RegistrationClosed = 44,
+    ProofTooLarge = 45,
+    CommitmentReused = 46,
+    InvalidSession = 47,
+    NotRevealed = 48,
+    Overflow = 49,
+    PotCapExceeded = 51,
 }
 
 // ============================================================================
@@ -94,32 +243,421 @@ pub struct Jugador {
     pub address: Address,
     pub is_alive: bool,
     pub points: i128,
+    pub avatar: u32,                 // Visual slot (0..15), unique per session
+    pub bond: i128,                  // Anti-grief bond held in escrow (0 once settled)
+    pub shots_survived: u32,         // Clicks this player walked away from
+    pub stakes: Map<Address, i128>,  // Token → amount staked into the pot (refunded on leave)
+    pub final_points: i128,          // Points recorded at the finish, consolation included (0 until then)
+    pub insured: bool,               // Next hit is absorbed (see `comprar_seguro`)
+    pub ante_paid: i128,             // Ante paid towards the current round
+    pub timeouts_left: u32,          // Tactical timeouts still available
+    pub team: u32,                   // 0 or 1 in team sessions (always 0 otherwise)
+}
+
+/// Per-session rules, chosen by the host before anyone else joins
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionConfig {
+    /// Once `shots_fired` exceeds this, each reload removes one chamber
+    /// from the cylinder (0 = disabled)
+    pub sudden_death_after: u32,
+    /// Which verifier `disparar` runs (`PROOF_SYSTEM_*`)
+    pub proof_system: u32,
+    /// How the pot reaches the winner (`PAYOUT_*`)
+    pub payout_mode: u32,
+    /// Whether the bullet position may be shown (`MODE_*`)
+    pub mode: u32,
+    /// Ledgers after the final shot before queries reveal the winner
+    pub reveal_delay_ledgers: u32,
+    /// Whether a passed skip vote eliminates the current player (`false`
+    /// just passes their turn)
+    pub skip_vote_eliminates: bool,
+    /// Ledgers the game must sit idle (since `last_action_ledger`) before
+    /// a skip vote may open. At least 1, and below the hard turn timeout
+    /// when turns are timed.
+    pub skip_vote_idle_ledgers: u32,
+    /// Re-draw the alive players' turn order from the seed on each reload
+    pub shuffle_each_round: bool,
+    /// Last ledger new players may join on (0 = open until full or loaded)
+    pub registration_deadline_ledger: u32,
+    /// Initial turn order (`TURN_ORDER_*`)
+    pub turn_order_mode: u32,
+    /// How the cylinder reloads after an elimination (`RELOAD_*`)
+    pub reload_mode: u32,
+    /// Ledgers a hit player stays pending before the elimination is final,
+    /// for death animations (0 = immediate)
+    pub elimination_delay_ledgers: u32,
+    /// Entry fee each later joiner pays the host directly, outside the
+    /// pot (0 = free)
+    pub host_fee: i128,
+    /// Token `host_fee` is paid in (required when the fee is set)
+    pub host_fee_token: Option<Address>,
+    /// Load one more bullet on every reload, up to `num_chambers - 1`
+    pub bullet_escalation: bool,
+    /// Pot shares in basis points by finishing position (winner first,
+    /// then the last eliminated, ...), summing to 10000. Empty = winner
+    /// takes all.
+    pub prize_ladder: Vec<u32>,
+    /// Each alive player must pay this much per round with `pagar_ante`,
+    /// or be eliminated when the round ends (0 = no ante)
+    pub ante_per_round: i128,
+    /// Token the ante is paid in (required when the ante is set)
+    pub ante_token: Option<Address>,
+    /// Tactical timeouts (`pedir_tiempo`) each player gets per game, up
+    /// to `MAX_TACTICAL_TIMEOUTS`
+    pub timeouts_per_player: u32,
+    /// Ledgers one timeout adds to the turn deadline
+    pub timeout_extension_ledgers: u32,
+    /// Static mode: shift the host's committed first bullet by a
+    /// per-session offset, so a commitment reused across sessions still
+    /// loads different cylinders
+    pub bind_session_nonce: bool,
+    /// Two teams (joiners fill the smaller one, up to 2v2); the game ends
+    /// when only one team has living members and that team splits the pot
+    pub team_mode: bool,
+    /// Merkle root of the host's future reload positions. When set, each
+    /// reload waits for the host to reveal the next leaf with `recargar`
+    /// instead of deriving it on-chain.
+    pub bullet_sequence_commitment: Option<BytesN<32>>,
+    /// Failed proofs the shooter may submit in one turn before being
+    /// kicked (0 = a bad proof is simply rejected)
+    pub max_proof_attempts: u32,
+    /// Buy-back: basis points of their stakes an eliminated player gets
+    /// back on the spot, out of the pot (below 10000, so the winner
+    /// always keeps something)
+    pub loser_refund_bps: u32,
+    /// Ledgers after the finish before `revelar_bala` is accepted, so
+    /// auditors get a window where the commitment stands alone
+    pub reveal_after_ledgers: u32,
+    /// Distinct fatal chambers each auto-reload draws, below
+    /// `NUM_CHAMBERS` (0 = one bullet, or the escalation count)
+    pub bullets_per_round: u32,
+}
+
+/// Contract-wide policy, set by the admin
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Config {
+    /// Token join bonds are posted in (`None` = no bonds)
+    pub bond_token: Option<Address>,
+    /// Refundable bond each joiner posts; forfeited to the pot on an AFK kick
+    pub join_bond: i128,
+    /// Ledgers the current player has to shoot (0 = no turn timeout)
+    pub turn_timeout_ledgers: u32,
+    /// Points a winner gains by surviving `tiro_de_honor` (and loses if hit)
+    pub bravery_bonus: i128,
+    /// Share of the pot, in basis points, a winner hit by `tiro_de_honor`
+    /// forfeits to protocol fees (at most 10000)
+    pub honor_forfeit_bps: u32,
+    /// Ledgers after the finish during which the winner may take the
+    /// honor shot, with the pot held meanwhile (0 = honor shots disabled)
+    pub honor_window_ledgers: u32,
+    /// Joins one address may make per ledger, across all sessions (0 = unlimited)
+    pub max_joins_per_ledger: u32,
+    /// Event naming theme (`THEME_*`)
+    pub theme: u32,
+    /// Ceiling on the points each player is registered with at the hub
+    pub max_hub_points: i128,
+    /// Tokens players may stake into the pot with `apostar`
+    pub stake_tokens: Vec<Address>,
+    /// Participation credit added to each loser's `final_points`
+    pub consolation_points: i128,
+    /// Enables dev-only tools such as `reset_session`; keep off in production
+    pub dev_mode: bool,
+    /// Longest proof `disparar` / `tiro_de_honor` accept, in bytes (0 = unlimited)
+    pub max_proof_bytes: u32,
+    /// Track recently loaded commitments and refuse reuse in hidden-mode
+    /// sessions
+    pub reject_reused_commitments: bool,
+    /// Widen strong players' danger zone per `handicap_table`
+    pub handicap: bool,
+    /// `(min_win_rate_bps, extra_chambers)` rows: a shooter whose win rate
+    /// (in basis points) reaches a row is also hit by the bullet that many
+    /// chambers early. The largest matching row applies.
+    pub handicap_table: Vec<(u32, u32)>,
+    /// Minimum premium for `comprar_seguro` (0 = insurance not offered)
+    pub insurance_price: i128,
+    /// Most a session's pot may hold in any one token from stakes and
+    /// premiums (`i128::MAX` = unlimited)
+    pub max_pot: i128,
+    /// Unfinished sessions one address may be seated in at once (0 =
+    /// unlimited)
+    pub max_active_games: u32,
+    /// Protocol fee in basis points, skimmed off each pot before it is
+    /// paid out and held for `withdraw_fees` (below 10000)
+    pub protocol_fee_bps: u32,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PartidaRuleta {
     pub players: Vec<Jugador>,
+    pub seats: Map<Address, u32>,    // Address → index into players
     pub phase: u32,
     pub current_turn: u32,           // Index into players (0, 1, 2)
-    pub current_chamber: u32,        // Which chamber is next (0..5)
+    pub current_chamber: u32,        // Which chamber is next (0..num_chambers-1)
+    pub num_chambers: u32,           // Chambers in the cylinder (shrinks in sudden death)
+    pub num_bullets: u32,            // Bullets in the current cylinder (grows with escalation)
     pub bullet_commitment: BytesN<32>, // SHA256(salt || bullet_position)
+    pub bullet_salt: Option<BytesN<32>>, // Salt the host opened the commitment with at load
+    pub salt_revealed: bool,         // `revelar_bala` made the salt public (queries hide it until then)
     pub bullet_position: u32,        // Actual chamber holding the bullet (0..5)
+    pub bullet_history: Vec<u32>,    // Initial position, then every reload's position
+    pub fatal_chambers: Vec<u64>,    // Bitmask of every loaded chamber, one entry per round
     pub eliminated: Vec<Address>,    // Dead players
     pub winner: Option<Address>,
     pub session_id: u32,
+    pub host: Address,               // Loads, reloads and reveals the revolver
     pub shots_fired: u32,            // Total shots taken
     // Game Hub tracking (2-player interface)
     pub hub_player1: Address,
     pub hub_player2: Address,
+    pub config: SessionConfig,
+    pub bond_token: Option<Address>, // Token bonds and pot are held in
+    pub pot: Map<Address, i128>,     // Token → stakes and forfeited bonds owed to the winner
+    pub turn_deadline_ledger: u32,   // Current turn can be kicked after this (0 = never)
+    pub finished_ledger: u32,        // Ledger the game ended on
+    pub reveal_winner_at_ledger: u32, // Queries withhold the winner before this
+    pub honor_shot_taken: bool,
+    pub last_action_ledger: u32,     // Ledger of the most recent state change
+    pub round: u32,                  // Reloads so far (0 = the host's cylinder)
+    pub seed_shots_fired: u32,       // `shots_fired` the live cylinder was derived from
+    pub skip_votes: Vec<Address>,    // Votes to skip the current turn (cleared when it moves)
+    pub turn_order: Vec<u32>,        // Seat order turns follow (empty = by seat)
+    pub pending_elimination: Option<Address>, // Hit player awaiting `confirmar_eliminacion`
+    pub pending_until_ledger: u32,   // The pending elimination can be confirmed from this ledger
+    pub phase_timestamps: Map<u32, u32>, // Phase → ledger it was (last) entered on
+    pub hub_override: Option<Address>, // Hub this session reports to instead of the global one
+    pub last_actor: Address,         // Player behind the most recent state change
+    pub actions_count: Map<Address, u32>, // Player → state-changing calls made (fee reconciliation)
+    pub shot_log: Vec<ShotRecord>,   // Every shot in order, for `auditar_partida`
+    pub awaiting_reload: bool,       // Emptied cylinder waits for the host's `recargar`
+    pub invalid_proof_attempts: u32, // Failed proofs in the current turn
+}
+
+/// Rarely-changing half of a stored session: roster, rules, commitment
+/// and settlement. Calls that only move the turn along leave it alone.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameStatic {
+    pub players: Vec<Jugador>,       // `shots_survived` is kept in GameDynamic
+    pub seats: Map<Address, u32>,
+    pub num_chambers: u32,
+    pub num_bullets: u32,
+    pub bullet_commitment: BytesN<32>,
+    pub bullet_salt: Option<BytesN<32>>,
+    pub salt_revealed: bool,
+    pub bullet_position: u32,
+    pub bullet_history: Vec<u32>,
+    pub fatal_chambers: Vec<u64>,
+    pub winner: Option<Address>,
+    pub session_id: u32,
+    pub host: Address,
+    pub hub_player1: Address,
+    pub hub_player2: Address,
+    pub config: SessionConfig,
+    pub bond_token: Option<Address>,
+    pub pot: Map<Address, i128>,
+    pub finished_ledger: u32,
+    pub reveal_winner_at_ledger: u32,
+    pub honor_shot_taken: bool,
+    pub round: u32,
+    pub seed_shots_fired: u32,
+    pub turn_order: Vec<u32>,
+    pub phase_timestamps: Map<u32, u32>,
+    pub hub_override: Option<Address>,
+}
+
+/// Per-shot half of a stored session, the only part a miss rewrites
+/// besides appending to the shot log
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameDynamic {
+    pub phase: u32,
+    pub current_turn: u32,
+    pub current_chamber: u32,
+    pub shots_fired: u32,
+    pub turn_deadline_ledger: u32,
+    pub last_action_ledger: u32,
+    pub skip_votes: Vec<Address>,
+    pub shots_survived: Vec<u32>,    // By seat
+    pub pending_elimination: Option<Address>,
+    pub pending_until_ledger: u32,
+    pub last_actor: Address,
+    pub actions_count: Map<Address, u32>,
+    pub awaiting_reload: bool,
+    pub invalid_proof_attempts: u32,
+}
+
+/// A session moved between phases (`PHASE_*`)
+#[contractevent]
+pub struct PhaseChanged {
+    #[topic]
+    pub session_id: u32,
+    pub from_phase: u32,
+    pub to_phase: u32,
+    pub at_ledger: u32,
+}
+
+/// A player's record across finished sessions
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub games_played: u32,
+    pub wins: u32,
+}
+
+/// Redacted session snapshot that is safe to show publicly
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ObserverInfo {
+    pub phase: u32,
+    pub current_player: Option<Address>, // Whose turn it is (None unless playing)
+    pub alive: Vec<Address>,
+    pub shots_fired: u32,
+    pub round: u32,
+    pub bullet_commitment: BytesN<32>,
+    pub bullet_position: Option<u32>, // Static mode only; never set in hidden mode
+}
+
+/// What a cylinder widget needs to draw the current round
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CylinderState {
+    pub num_chambers: u32,
+    pub current_chamber: u32,
+    pub fired_chambers: Vec<u32>,     // Chambers already pulled in this cylinder
+    pub bullet_visible: Option<u32>,  // Static mode only; never set in hidden mode
+}
+
+/// Where the current round stands, without the bullet
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundInfo {
+    pub round: u32,
+    pub current_chamber: u32,
+    pub num_chambers: u32,
+    pub shots_fired: u32,
+}
+
+/// Shareable opening of a finished game's bullet commitment. Anyone can
+/// re-check it: `SHA256(salt || bullet_position) == commitment`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FairnessProof {
+    pub commitment: BytesN<32>,
+    pub salt: BytesN<32>,
+    pub bullet_position: u32, // The host's initial position
+    pub sha_matches: bool,
+}
+
+/// A Groth16 proof over BN254. Points use the host's uncompressed
+/// Ethereum-style encoding; `public_inputs` are big-endian scalars.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Groth16Proof {
+    pub a: BytesN<64>,
+    pub b: BytesN<128>,
+    pub c: BytesN<64>,
+    pub public_inputs: Vec<BytesN<32>>, // [commitment (253-bit), chamber]
+}
+
+/// Groth16 verifying key for the shot circuit, set by the admin
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifyingKey {
+    pub alpha: BytesN<64>,
+    pub beta: BytesN<128>,
+    pub gamma: BytesN<128>,
+    pub delta: BytesN<128>,
+    pub ic: Vec<BytesN<64>>, // One point per public input, plus the constant
+}
+
+/// A game's result, kept in persistent storage so it outlives the
+/// temporary game entries
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FinishedGame {
+    pub winner: Address,
+    pub finished_ledger: u32,
+    pub reveal_winner_at_ledger: u32,
+}
+
+/// One `disparar` call, as the contract judged it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShotRecord {
+    pub shooter: Address,
+    pub chamber: u32,
+    pub reach: u32, // Extra chambers a handicapped shooter's danger zone covered
+    pub hit: bool,
+}
+
+/// The cylinder auto-reloaded after an elimination. The new position is
+/// only included in static (practice) mode.
+#[contractevent]
+pub struct CylinderReloaded {
+    #[topic]
+    pub session_id: u32,
+    pub round: u32,
+    pub bullet_position: Option<u32>,
+}
+
+/// The shooter's proof failed; at `max_proof_attempts` they are kicked
+#[contractevent]
+pub struct ProofFail {
+    #[topic]
+    pub session_id: u32,
+    pub player: Address,
+    pub attempts: u32,
+}
+
+/// A player took a seat in the lobby, so clients can build the roster
+/// from events
+#[contractevent]
+pub struct PlayerJoined {
+    #[topic]
+    pub session_id: u32,
+    pub player: Address,
+    pub seat_index: u32,
+    pub total_players: u32,
+}
+
+/// A timed turn began: `player` must shoot by `turn_deadline_ledger` or
+/// can be kicked, so clients can show a countdown
+#[contractevent]
+pub struct TurnStart {
+    #[topic]
+    pub session_id: u32,
+    pub player: Address,
+    pub turn_deadline_ledger: u32,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
-    Game(u32),
+    GameStatic(u32),  // session_id → GameStatic
+    GameDynamic(u32), // session_id → GameDynamic
+    ShotLog(u32),     // session_id → every shot in order
+    EliminatedLog(u32), // session_id → eliminated players in order
     GameHubAddress,
     Admin,
+    Config,
+    JoinCount(Address, u32), // (player, ledger sequence) → joins made
+    UsedCommitment(BytesN<32>), // Persistent: commitment was loaded by some session
+    RecentCommitments,          // Persistent: tracked commitments, oldest first
+    Stats(Address),             // Persistent: player → PlayerStats
+    TokenVolume(Address),       // Persistent: token → net amount staked, all sessions
+    FeeBalance(Address),        // Persistent: token → protocol fees not yet withdrawn
+    Wins(Address),              // Persistent: player → session ids won, oldest first
+    SessionPage(u32),           // Persistent: page → session ids that may still hold escrow
+    SessionPages,               // Persistent: number of session index pages
+    SessionPageOf(u32),         // Persistent: session → its index page
+    OpenLobbies,                // Persistent: waiting sessions with a free seat, oldest first
+    VerifyingKey,               // Groth16 key for PROOF_SYSTEM_GROTH16 sessions
+    FinishedGame(u32),          // Persistent: session_id → FinishedGame
+    ApprovedHubs,               // Hubs a session may report to instead of the global one
+    ActiveGames(Address),       // Persistent: player → unfinished sessions they're seated in
 }
 
 // ============================================================================
@@ -137,6 +675,9 @@ impl ZkMafiaContract {
         env.storage()
             .instance()
             .set(&DataKey::GameHubAddress, &game_hub);
+        env.storage()
+            .instance()
+            .set(&DataKey::ApprovedHubs, &Vec::from_array(&env, [game_hub]));
     }
 
     // ====================================================================
@@ -144,53 +685,89 @@ impl ZkMafiaContract {
     // ====================================================================
     /// Register a player into the session. Supports 2-3 players.
     /// The host must call `cargar_revolver` once enough players have joined.
+    /// `avatar` picks a visual slot that no other player in the session holds.
+    /// `points` must be in `0..=MAX_JOIN_POINTS`.
     pub fn entrar_a_la_ruleta(
         env: Env,
         session_id: u32,
         player: Address,
         points: i128,
+        avatar: u32,
     ) -> Result<u32, Error> {
         player.require_auth();
 
-        let key = DataKey::Game(session_id);
-
-        let mut game: PartidaRuleta = env.storage().temporary().get(&key).unwrap_or_else(|| {
-            PartidaRuleta {
-                players: Vec::new(&env),
-                phase: PHASE_WAITING,
-                current_turn: 0,
-                current_chamber: 0,
-                bullet_commitment: BytesN::from_array(&env, &[0u8; 32]),
-                bullet_position: 0,
-                eliminated: Vec::new(&env),
-                winner: None,
-                session_id,
-                shots_fired: 0,
-                hub_player1: player.clone(),
-                hub_player2: player.clone(),
-            }
-        });
+        Self::check_session_id(session_id)?;
+        if avatar >= NUM_AVATARS {
+            return Err(Error::InvalidAvatar);
+        }
+        if !(0..=MAX_JOIN_POINTS).contains(&points) {
+            return Err(Error::InvalidPoints);
+        }
+        Self::check_join_rate(&env, &player)?;
+
+        let mut game = Self::load_game(&env, session_id)
+            .unwrap_or_else(|| Self::new_game(&env, session_id, &player));
 
         if game.phase != PHASE_WAITING {
             return Err(Error::WrongPhase);
         }
-        if game.players.len() >= MAX_PLAYERS {
+        if game.players.len() >= Self::lobby_capacity(&game) {
             return Err(Error::LobbyFull);
         }
+        let deadline = game.config.registration_deadline_ledger;
+        if deadline > 0 && env.ledger().sequence() > deadline {
+            return Err(Error::RegistrationClosed);
+        }
 
         // Check not already joined
-        for i in 0..game.players.len() {
-            let p = game.players.get(i).unwrap();
-            if p.address == player {
-                return Err(Error::AlreadyJoined);
+        if game.seats.contains_key(player.clone()) {
+            return Err(Error::AlreadyJoined);
+        }
+        for p in game.players.iter() {
+            if p.avatar == avatar {
+                return Err(Error::AvatarTaken);
             }
         }
+        let limit = Self::load_config(&env).max_active_games;
+        if limit > 0 && Self::get_active_game_count(env.clone(), player.clone()) >= limit {
+            return Err(Error::TooManyActiveGames);
+        }
+        Self::add_active_game(&env, &player, session_id, true);
 
-        let jugador = Jugador {
-            address: player.clone(),
-            is_alive: true,
-            points,
-        };
+        // Post the anti-grief bond
+        let mut bond = 0;
+        if let Some(token_addr) = &game.bond_token {
+            bond = Self::load_config(&env).join_bond;
+            if bond > 0 {
+                token::TokenClient::new(&env, token_addr).transfer(
+                    &player,
+                    env.current_contract_address(),
+                    &bond,
+                );
+            }
+        }
+
+        // Later joiners pay the host's entry fee straight to the host
+        if let Some(fee_token) = &game.config.host_fee_token {
+            if game.config.host_fee > 0 && !game.players.is_empty() {
+                token::TokenClient::new(&env, fee_token).transfer(
+                    &player,
+                    &game.host,
+                    &game.config.host_fee,
+                );
+            }
+        }
+
+        let mut jugador = Self::new_player(&env, &player, points, avatar, bond);
+        if game.config.team_mode {
+            let on_team_1 = game.players.iter().filter(|p| p.team == 1).count() as u32;
+            jugador.team = if on_team_1 * 2 < game.players.len() { 1 } else { 0 };
+        }
+        if game.players.is_empty() {
+            Self::set_session_active(&env, session_id, true);
+        }
+        let seat_index = game.players.len();
+        game.seats.set(player.clone(), seat_index);
         game.players.push_back(jugador);
 
         let player_count = game.players.len();
@@ -202,42 +779,229 @@ impl ZkMafiaContract {
             game.hub_player2 = player.clone();
         }
 
-        env.events().publish(
-            (symbol_short!("lobby"), session_id),
-            player_count,
-        );
+        Self::set_lobby_open(&env, session_id, player_count < Self::lobby_capacity(&game));
+        Self::publish(&env, symbol_short!("lobby"), session_id, player_count);
+        PlayerJoined {
+            session_id,
+            player: player.clone(),
+            seat_index,
+            total_players: player_count,
+        }
+        .publish(&env);
 
-        env.storage().temporary().set(&key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Self::record_action(&mut game, &player)?;
+        Self::save_game(&env, &mut game);
+
+        Ok(player_count)
+    }
+
+    // ====================================================================
+    // 🏃 salir_de_la_ruleta — Leave the lobby before the game starts
+    // ====================================================================
+    /// Leave a lobby that hasn't started yet. The player's bond is
+    /// refunded. If the host leaves, the next player becomes host; an
+    /// emptied lobby is deleted. Returns the remaining player count.
+    pub fn salir_de_la_ruleta(env: Env, session_id: u32, player: Address) -> Result<u32, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_WAITING {
+            return Err(Error::WrongPhase);
+        }
+        let seat = game.seats.get(player.clone()).ok_or(Error::NotPlayer)?;
+
+        let mut leaving = game.players.get(seat).unwrap();
+        Self::refund_bond(&env, &game, &mut leaving);
+        for (token_addr, amount) in leaving.stakes.iter() {
+            token::TokenClient::new(&env, &token_addr).transfer(
+                &env.current_contract_address(),
+                &player,
+                &amount,
+            );
+            Self::add_token_volume(&env, &token_addr, -amount)?;
+            let held = game.pot.get(token_addr.clone()).unwrap_or(0) - amount;
+            if held > 0 {
+                game.pot.set(token_addr, held);
+            } else {
+                game.pot.remove(token_addr);
+            }
+        }
+        game.players.remove(seat);
+        Self::add_active_game(&env, &player, session_id, false);
+        Self::reindex_seats(&env, &mut game);
+
+        let player_count = game.players.len();
+        Self::publish(&env, symbol_short!("left"), session_id, player.clone());
+
+        if player_count == 0 {
+            Self::remove_game(&env, session_id);
+            Self::set_session_active(&env, session_id, false);
+            Self::set_lobby_open(&env, session_id, false);
+            return Ok(0);
+        }
+        Self::set_lobby_open(&env, session_id, true);
+
+        if game.host == player {
+            game.host = game.players.get(0).unwrap().address;
+        }
+        // Hub pair is always the first two seats
+        game.hub_player1 = game.players.get(0).unwrap().address;
+        game.hub_player2 = match game.players.get(1) {
+            Some(p) => p.address,
+            None => game.hub_player1.clone(),
+        };
+
+        Self::record_action(&mut game, &player)?;
+        Self::save_game(&env, &mut game);
 
         Ok(player_count)
     }
 
+    // ====================================================================
+    // 💵 apostar — Stake a token into the pot
+    // ====================================================================
+    /// Stake `amount` of an admin-approved token into the pot while the
+    /// lobby is open. Lobbies may mix currencies; the winner receives each
+    /// token's balance separately. Returns the player's total in `token_addr`.
+    pub fn apostar(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        token_addr: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        player.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidStake);
+        }
+        let config = Self::load_config(&env);
+        if !config.stake_tokens.contains(&token_addr) {
+            return Err(Error::TokenNotAllowed);
+        }
+
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_WAITING {
+            return Err(Error::WrongPhase);
+        }
+        let seat = game.seats.get(player.clone()).ok_or(Error::NotPlayer)?;
+        let held = Self::pot_after(&game, &config, &token_addr, amount)?;
+
+        token::TokenClient::new(&env, &token_addr).transfer(
+            &player,
+            env.current_contract_address(),
+            &amount,
+        );
+
+        let mut jugador = game.players.get(seat).unwrap();
+        let staked = jugador
+            .stakes
+            .get(token_addr.clone())
+            .unwrap_or(0)
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+        jugador.stakes.set(token_addr.clone(), staked);
+        game.players.set(seat, jugador);
+        game.pot.set(token_addr.clone(), held);
+        Self::add_token_volume(&env, &token_addr, amount)?;
+
+        Self::record_action(&mut game, &player)?;
+        Self::publish(&env, symbol_short!("stake"), session_id, (player, amount));
+
+        Self::save_game(&env, &mut game);
+
+        Ok(staked)
+    }
+
+    // ====================================================================
+    // ⚙️ configurar_partida — Host sets the session rules
+    // ====================================================================
+    /// The host (first player) chooses the session rules. Only allowed
+    /// while the host is alone in the lobby, so nobody joins under one
+    /// set of rules and plays under another.
+    pub fn configurar_partida(
+        env: Env,
+        session_id: u32,
+        host: Address,
+        config: SessionConfig,
+    ) -> Result<(), Error> {
+        host.require_auth();
+
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_WAITING || game.players.len() != 1 {
+            return Err(Error::WrongPhase);
+        }
+        if game.host != host {
+            return Err(Error::NotPlayer);
+        }
+        if config.proof_system > PROOF_SYSTEM_GROTH16 {
+            return Err(Error::UnsupportedProofSystem);
+        }
+        let turn_timeout = Self::load_config(&env).turn_timeout_ledgers;
+        if config.payout_mode > PAYOUT_CLAIM
+            || config.mode > MODE_HIDDEN
+            || config.turn_order_mode > TURN_ORDER_POINTS_ASC
+            || config.reload_mode > RELOAD_CONTINUE
+            || config.host_fee < 0
+            || (config.host_fee > 0 && config.host_fee_token.is_none())
+            || config.ante_per_round < 0
+            || config.timeouts_per_player > MAX_TACTICAL_TIMEOUTS
+            || config.loser_refund_bps >= 10_000
+            || config.bullets_per_round >= NUM_CHAMBERS
+            || config.skip_vote_idle_ledgers == 0
+            || (turn_timeout > 0 && config.skip_vote_idle_ledgers >= turn_timeout)
+            || config.reveal_delay_ledgers > GAME_TTL_LEDGERS
+            || config.elimination_delay_ledgers > GAME_TTL_LEDGERS
+            || (config.ante_per_round > 0 && config.ante_token.is_none())
+            || (!config.prize_ladder.is_empty()
+                && config.prize_ladder.iter().map(u64::from).sum::<u64>() != 10_000)
+        {
+            return Err(Error::InvalidConfig);
+        }
+
+        game.config = config;
+
+        Self::record_action(&mut game, &host)?;
+        Self::save_game(&env, &mut game);
+
+        Ok(())
+    }
+
     // ====================================================================
     // 🔫 cargar_revolver — Commit bullet position (host calls once)
     // ====================================================================
     /// The first player (host) sets the bullet position and starts the game.
-    /// Requires at least 2 players (max 3).
-    /// Also registers the session with the Game Hub.
+    /// Requires at least 2 players (max 3). `salt` must open
+    /// `bullet_commitment` to `bullet_position`. It is stored so it can
+    /// later be revealed, and queries hide it until `revelar_bala`.
+    /// Also registers the session with the Game Hub — `hub_override`, if
+    /// given, replaces the global hub for this session (e.g. a
+    /// tournament's own hub) and must be one of the approved hubs. With
+    /// `shuffle_seating` every seat is drawn at random before play begins,
+    /// so anyone may shoot first, and the hub pair follows the new first
+    /// two seats.
+    #[allow(clippy::too_many_arguments)]
     pub fn cargar_revolver(
         env: Env,
         session_id: u32,
         player: Address,
         bullet_commitment: BytesN<32>,
         bullet_position: u32,
+        salt: BytesN<32>,
+        hub_override: Option<Address>,
+        shuffle_seating: bool,
     ) -> Result<(), Error> {
         player.require_auth();
+        Self::check_session_id(session_id)?;
 
-        let key = DataKey::Game(session_id);
-        let mut game: PartidaRuleta = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
 
-        if game.players.len() < MIN_PLAYERS {
+        if game.players.len() < MIN_PLAYERS
+            || (game.config.team_mode && game.players.iter().all(|p| p.team == 0))
+        {
             return Err(Error::NotEnoughPlayers);
         }
         if game.phase == PHASE_FINISHED {
@@ -246,49 +1010,88 @@ impl ZkMafiaContract {
         if game.phase == PHASE_PLAYING {
             return Err(Error::AlreadyStarted);
         }
-        if bullet_position >= NUM_CHAMBERS {
+        if bullet_position >= game.num_chambers {
             return Err(Error::InvalidChamber);
         }
 
-        // Only the first player (host) can load the revolver
-        let p0 = game.players.get(0).unwrap();
-        if p0.address != player {
+        // Only the host can load the revolver
+        if game.host != player {
             return Err(Error::NotPlayer);
         }
+        if let Some(hub) = &hub_override {
+            if !Self::get_approved_hubs(env.clone()).contains(hub) {
+                return Err(Error::UnknownHub);
+            }
+        }
+        // The hub gets a veto before the session goes live. The standard
+        // hub has no `can_start`, so a failed call counts as allowed.
+        game.hub_override = hub_override;
+        let hub = GameHubClient::new(&env, &Self::hub_address(&env, &game));
+        if let Ok(Ok(false)) = hub.try_can_start(&env.current_contract_address(), &session_id) {
+            return Err(Error::HubRejected);
+        }
+        // The commitment has to actually open to the position being loaded
+        let opened = Self::compute_bullet_hash(env.clone(), salt.clone(), bullet_position);
+        if opened != bullet_commitment {
+            return Err(Error::CommitmentMismatch);
+        }
+
+        // A reused (salt, position) commitment leaks the position to anyone
+        // who saw it opened before, so competitive sessions may refuse it
+        if Self::load_config(&env).reject_reused_commitments {
+            let used_key = DataKey::UsedCommitment(bullet_commitment.clone());
+            if game.config.mode == MODE_HIDDEN && env.storage().persistent().has(&used_key) {
+                return Err(Error::CommitmentReused);
+            }
+            Self::track_commitment(&env, &bullet_commitment);
+        }
 
         game.bullet_commitment = bullet_commitment;
+        game.bullet_salt = Some(salt);
         game.bullet_position = bullet_position;
-        game.phase = PHASE_PLAYING;
+        if game.config.bind_session_nonce && game.config.mode == MODE_STATIC {
+            game.bullet_position =
+                (bullet_position + Self::session_offset(&env, session_id)) % game.num_chambers;
+        }
+        game.bullet_history.push_back(game.bullet_position);
+        game.fatal_chambers.push_back(1 << game.bullet_position);
+        Self::set_phase(&env, &mut game, PHASE_PLAYING);
+        Self::set_lobby_open(&env, session_id, false);
+        if shuffle_seating {
+            Self::shuffle_seating(&env, &mut game);
+        }
         game.current_turn = 0;
         game.current_chamber = 0;
+        for seat in 0..game.players.len() {
+            let mut p = game.players.get(seat).unwrap();
+            p.timeouts_left = game.config.timeouts_per_player;
+            game.players.set(seat, p);
+        }
+        match game.config.turn_order_mode {
+            TURN_ORDER_JOIN => {}
+            _ => Self::sort_turn_order_by_points(&env, &mut game),
+        }
+        Self::ensure_turn_alive(&mut game)?;
+        Self::reset_turn_deadline(&env, &mut game)?;
 
-        // Register with Game Hub (2-player interface: first two players)
+        // Register with Game Hub (2-player interface: first two players),
+        // clamping points so one game can't inject arbitrary scores
         let p1 = game.players.get(0).unwrap();
         let p2 = game.players.get(1).unwrap();
-        let hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub not set");
-        let hub = GameHubClient::new(&env, &hub_addr);
+        let max_points = Self::load_config(&env).max_hub_points;
         hub.start_game(
             &env.current_contract_address(),
             &session_id,
             &p1.address,
             &p2.address,
-            &p1.points,
-            &p2.points,
+            &p1.points.min(max_points),
+            &p2.points.min(max_points),
         );
 
-        env.events().publish(
-            (symbol_short!("loaded"), session_id),
-            true,
-        );
+        Self::publish(&env, symbol_short!("loaded"), session_id, true);
 
-        env.storage().temporary().set(&key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Self::record_action(&mut game, &player)?;
+        Self::save_game(&env, &mut game);
 
         Ok(())
     }
@@ -304,194 +1107,2943 @@ impl ZkMafiaContract {
     /// After elimination, if 2+ players remain, the cylinder auto-reloads
     /// with a new deterministic bullet position for the next round.
     ///
+    /// An insured shooter who is hit survives instead: the policy is
+    /// consumed and the cylinder reloads.
+    ///
+    /// With `max_proof_attempts` set, a failed proof doesn't error (an
+    /// error would roll the count back): it counts against the shooter
+    /// (`SHOT_PROOF_REJECTED`, turn unchanged), and the last allowed
+    /// failure kicks them like an AFK player (`SHOT_KICKED`).
+    ///
+    /// A game found in play with one side left, or none, is over: the
+    /// shot is refused with `GameAlreadyEnded`.
+    ///
     /// # Returns
-    /// `true` if the player was hit (eliminated), `false` if survived.
+    /// `SHOT_HIT` if the player was hit, `SHOT_SURVIVED` if they survived,
+    /// or one of the no-shot outcomes above (`SHOT_*`).
     pub fn disparar(
         env: Env,
         session_id: u32,
         player: Address,
-        zk_proof: BytesN<32>,
-    ) -> Result<bool, Error> {
+        zk_proof: Bytes,
+    ) -> Result<u32, Error> {
         player.require_auth();
+        Self::check_session_id(session_id)?;
 
-        let key = DataKey::Game(session_id);
-        let mut game: PartidaRuleta = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
-
-        if game.phase != PHASE_PLAYING {
-            return Err(Error::WrongPhase);
-        }
-
-        // Verify it's this player's turn
-        let current_player = game.players.get(game.current_turn).unwrap();
-        if current_player.address != player {
-            return Err(Error::NotYourTurn);
-        }
-        if !current_player.is_alive {
-            return Err(Error::PlayerEliminated);
-        }
-
-        // Verify chamber is valid
-        if game.current_chamber >= NUM_CHAMBERS {
-            return Err(Error::InvalidChamber);
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        if game.phase == PHASE_PLAYING && Self::sides_alive(&game) <= 1 {
+            return Err(Error::GameAlreadyEnded);
         }
+        Self::check_shot(&game, &player)?;
 
         // ── ZK Proof Verification ──────────────────────────────
-        // Structural validity check (non-zero proof).
-        // Full Groth16/Pedersen verification ready for mainnet.
-        Self::verify_zk_proof(&env, &zk_proof, &game.bullet_commitment)?;
+        // Fallback: structural check. Groth16: pairing check bound to
+        // this commitment and the chamber being fired.
+        if let Err(e) = Self::verify_zk_proof(
+            &env,
+            game.config.proof_system,
+            &zk_proof,
+            &game.bullet_commitment,
+            game.current_chamber,
+        ) {
+            // With a grace limit, a bad proof is a counted attempt rather
+            // than an error (an error would roll the count back)
+            let max = game.config.max_proof_attempts;
+            if max == 0 || e == Error::UnsupportedProofSystem {
+                return Err(e);
+            }
+            game.invalid_proof_attempts =
+                game.invalid_proof_attempts.checked_add(1).ok_or(Error::Overflow)?;
+            let attempts = game.invalid_proof_attempts;
+            ProofFail {
+                session_id,
+                player: player.clone(),
+                attempts,
+            }
+            .publish(&env);
+            let kicked = attempts >= max;
+            if kicked {
+                Self::kick_current(&env, &mut game)?;
+            }
+            Self::record_action(&mut game, &player)?;
+            if kicked {
+                Self::save_game(&env, &mut game);
+                return Ok(SHOT_KICKED);
+            }
+            Self::save_turn(&env, &mut game);
+            return Ok(SHOT_PROOF_REJECTED);
+        }
 
-        game.shots_fired += 1;
+        game.shots_fired = game.shots_fired.checked_add(1).ok_or(Error::Overflow)?;
 
         // ── STATIC CYLINDER: contract determines hit/miss ──────
-        let is_hit = game.current_chamber == game.bullet_position;
+        // A handicapped shooter's danger zone starts a few chambers early
+        let reach = Self::handicap_offset(&env, &player);
+        let is_hit = game.current_chamber <= game.bullet_position
+            && game.current_chamber.checked_add(reach).ok_or(Error::Overflow)?
+                >= game.bullet_position;
+        game.shot_log.push_back(ShotRecord {
+            shooter: player.clone(),
+            chamber: game.current_chamber,
+            reach,
+            hit: is_hit,
+        });
 
-        if is_hit {
-            // Player got the bullet — eliminated!
-            let mut dead_player = game.players.get(game.current_turn).unwrap();
-            dead_player.is_alive = false;
-            game.players.set(game.current_turn, dead_player);
-            game.eliminated.push_back(player.clone());
-
-            env.events().publish(
-                (symbol_short!("boom"), session_id),
-                player.clone(),
-            );
+        let mut shooter = game.players.get(game.current_turn).unwrap();
+        if is_hit && shooter.insured {
+            // Insurance absorbs the bullet: the shooter lives, the policy
+            // is spent and the cylinder reloads as if the round had ended
+            shooter.insured = false;
+            game.players.set(game.current_turn, shooter);
+            Self::publish(&env, symbol_short!("absorbed"), session_id, player.clone());
+            Self::end_round(&env, &mut game)?;
+        } else if is_hit {
+            Self::publish(&env, symbol_short!("boom"), session_id, player.clone());
 
-            // Check how many alive
-            let alive = Self::count_alive(&game);
+            // Optionally hold the elimination so front-ends can animate it
+            let delay = game.config.elimination_delay_ledgers;
+            if delay > 0 {
+                game.pending_elimination = Some(player.clone());
+                game.pending_until_ledger =
+                    env.ledger().sequence().checked_add(delay).ok_or(Error::Overflow)?;
+                game.turn_deadline_ledger = 0;
+            } else {
+                Self::eliminate_shooter(&env, &mut game)?;
+            }
+        } else {
+            // Survived — click!
+            Self::publish(&env, symbol_short!("click"), session_id, player.clone());
 
-            if alive == 1 {
-                // Game over — find the winner
-                let winner = Self::find_last_alive(&game).unwrap();
-                game.phase = PHASE_FINISHED;
-                game.winner = Some(winner.clone());
+            shooter.shots_survived =
+                shooter.shots_survived.checked_add(1).ok_or(Error::Overflow)?;
+            game.players.set(game.current_turn, shooter);
 
-                Self::report_to_hub(&env, session_id, &game, &winner);
+            game.current_chamber = game.current_chamber.checked_add(1).ok_or(Error::Overflow)?;
+            Self::advance_turn(&mut game);
+            Self::reset_turn_deadline(&env, &mut game)?;
+        }
 
-                env.events().publish(
-                    (symbol_short!("winner"), session_id),
-                    winner,
-                );
-            } else {
-                // 2+ alive — auto-reload cylinder for next round
-                // Deterministic new bullet position from SHA256(session_id || shots_fired)
-                let mut seed = Bytes::new(&env);
-                seed.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-                seed.append(&Bytes::from_array(&env, &game.shots_fired.to_be_bytes()));
-                let hash = env.crypto().sha256(&seed);
-                let arr = hash.to_array();
-                game.bullet_position = (arr[0] as u32) % NUM_CHAMBERS;
-                game.current_chamber = 0;
+        Self::record_action(&mut game, &player)?;
+        if is_hit {
+            Self::save_game(&env, &mut game);
+        } else {
+            // A click leaves the roster and cylinder as they were
+            Self::save_shot_log(&env, &game);
+            Self::save_turn(&env, &mut game);
+        }
 
-                Self::advance_turn(&mut game);
+        Ok(if is_hit { SHOT_HIT } else { SHOT_SURVIVED })
+    }
 
-                env.events().publish(
-                    (symbol_short!("reload"), session_id),
-                    game.bullet_position,
-                );
+    // ====================================================================
+    // 🛡️ comprar_seguro — Insure against the next hit
+    // ====================================================================
+    /// During play, an alive player pays a premium (at least
+    /// `insurance_price`, in an approved stake token) into the pot. Their
+    /// next hit is absorbed instead of eliminating them. One policy at a
+    /// time.
+    pub fn comprar_seguro(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        token_addr: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let config = Self::load_config(&env);
+        if config.insurance_price == 0 {
+            return Err(Error::InsuranceUnavailable);
+        }
+        if amount < config.insurance_price {
+            return Err(Error::InvalidStake);
+        }
+        if !config.stake_tokens.contains(&token_addr) {
+            return Err(Error::TokenNotAllowed);
+        }
+
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_PLAYING {
+            return Err(Error::WrongPhase);
+        }
+        let seat = game.seats.get(player.clone()).ok_or(Error::NotPlayer)?;
+        let mut jugador = game.players.get(seat).unwrap();
+        if !jugador.is_alive {
+            return Err(Error::PlayerEliminated);
+        }
+        if jugador.insured {
+            return Err(Error::InsuranceUnavailable);
+        }
+        let held = Self::pot_after(&game, &config, &token_addr, amount)?;
+
+        token::TokenClient::new(&env, &token_addr).transfer(
+            &player,
+            env.current_contract_address(),
+            &amount,
+        );
+        game.pot.set(token_addr, held);
+        jugador.insured = true;
+        game.players.set(seat, jugador);
+
+        Self::record_action(&mut game, &player)?;
+        Self::publish(&env, symbol_short!("seguro"), session_id, (player, amount));
+
+        Self::save_game(&env, &mut game);
+
+        Ok(())
+    }
+
+    // ====================================================================
+    // 🪙 pagar_ante — Pay this round's ante
+    // ====================================================================
+    /// In an ante session, each alive player pays at least
+    /// `ante_per_round` per round into the pot (adding to their stake).
+    /// Anyone short when the round ends is eliminated and their stake
+    /// stays in the pot. Returns what they've paid this round.
+    pub fn pagar_ante(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        player.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidStake);
+        }
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        let Some(token_addr) = game.config.ante_token.clone() else {
+            return Err(Error::WrongMode);
+        };
+        if game.phase != PHASE_PLAYING {
+            return Err(Error::WrongPhase);
+        }
+        let seat = game.seats.get(player.clone()).ok_or(Error::NotPlayer)?;
+        let mut jugador = game.players.get(seat).unwrap();
+        if !jugador.is_alive {
+            return Err(Error::PlayerEliminated);
+        }
+        let config = Self::load_config(&env);
+        if !config.stake_tokens.contains(&token_addr) {
+            return Err(Error::TokenNotAllowed);
+        }
+        let held = Self::pot_after(&game, &config, &token_addr, amount)?;
+
+        token::TokenClient::new(&env, &token_addr).transfer(
+            &player,
+            env.current_contract_address(),
+            &amount,
+        );
+        game.pot.set(token_addr.clone(), held);
+        let staked = jugador
+            .stakes
+            .get(token_addr.clone())
+            .unwrap_or(0)
+            .checked_add(amount)
+            .ok_or(Error::Overflow)?;
+        jugador.stakes.set(token_addr.clone(), staked);
+        jugador.ante_paid = jugador.ante_paid.checked_add(amount).ok_or(Error::Overflow)?;
+        let paid = jugador.ante_paid;
+        game.players.set(seat, jugador);
+        Self::add_token_volume(&env, &token_addr, amount)?;
+
+        Self::record_action(&mut game, &player)?;
+        Self::publish(&env, symbol_short!("ante"), session_id, (player, amount));
+
+        Self::save_game(&env, &mut game);
+
+        Ok(paid)
+    }
+
+    // ====================================================================
+    // 💀 confirmar_eliminacion — Finalize a delayed elimination
+    // ====================================================================
+    /// Anyone may call this once a pending hit's delay has passed. The
+    /// hit player is eliminated and the game reloads or ends exactly as
+    /// an immediate hit would have. Returns the eliminated address.
+    pub fn confirmar_eliminacion(env: Env, session_id: u32) -> Result<Address, Error> {
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_PLAYING {
+            return Err(Error::WrongPhase);
+        }
+        let eliminated = game
+            .pending_elimination
+            .clone()
+            .ok_or(Error::NoPendingElimination)?;
+        if env.ledger().sequence() < game.pending_until_ledger {
+            return Err(Error::EliminationPending);
+        }
+
+        game.pending_elimination = None;
+        game.pending_until_ledger = 0;
+        Self::eliminate_shooter(&env, &mut game)?;
+
+        Self::save_game(&env, &mut game);
+
+        Ok(eliminated)
+    }
+
+    // ====================================================================
+    // ⏰ expulsar_por_afk — Kick a player who let their turn expire
+    // ====================================================================
+    /// Anyone may call this once the current turn's deadline has passed.
+    /// The AFK player is eliminated and their bond goes to the pot; the
+    /// next player faces the same chamber. Returns the kicked address.
+    pub fn expulsar_por_afk(env: Env, session_id: u32) -> Result<Address, Error> {
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_PLAYING {
+            return Err(Error::WrongPhase);
+        }
+        if game.awaiting_reload {
+            return Err(Error::AwaitingReload);
+        }
+        if game.turn_deadline_ledger == 0 || env.ledger().sequence() <= game.turn_deadline_ledger {
+            return Err(Error::TurnNotExpired);
+        }
+
+        let afk = game.players.get(game.current_turn).unwrap().address;
+        Self::publish(&env, symbol_short!("afk"), session_id, afk.clone());
+        Self::kick_current(&env, &mut game)?;
+
+        Self::save_game(&env, &mut game);
+
+        Ok(afk)
+    }
+
+    // ====================================================================
+    // 🔁 recargar — Host reveals the next preloaded bullet
+    // ====================================================================
+    /// With a `bullet_sequence_commitment`, every reload waits here. The
+    /// host opens reload `round - 1`'s leaf, `compute_bullet_hash(salt,
+    /// value)`, with a Merkle proof against the root. The cylinder is
+    /// loaded like any other reload, with the round's bullet count: the
+    /// first bullet lands at `current_chamber + value % chambers_left`, so
+    /// leaves committed up front fit whatever the cylinder looks like by
+    /// then, and any further bullets are drawn from the leaf's remaining
+    /// bytes. Returns the new bullet position.
+    pub fn recargar(
+        env: Env,
+        session_id: u32,
+        host: Address,
+        value: u32,
+        salt: BytesN<32>,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<u32, Error> {
+        host.require_auth();
+
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_PLAYING || !game.awaiting_reload {
+            return Err(Error::WrongPhase);
+        }
+        if game.host != host {
+            return Err(Error::NotPlayer);
+        }
+        let root = game.config.bullet_sequence_commitment.clone().unwrap();
+        // The leaf hashes the value as one byte, so larger values would alias
+        if value > u8::MAX as u32 {
+            return Err(Error::InvalidReloadProof);
+        }
+        let leaf = Self::compute_bullet_hash(env.clone(), salt, value);
+        if Self::merkle_root(&env, leaf.clone(), game.round - 1, &proof) != root {
+            return Err(Error::InvalidReloadProof);
+        }
+
+        let mut arr = leaf.to_array();
+        arr[0] = value as u8;
+        let loaded =
+            Self::reload_bullets(&arr, game.current_chamber, game.num_chambers, game.num_bullets);
+        game.bullet_position = loaded.trailing_zeros();
+        game.seed_shots_fired = game.shots_fired;
+        game.awaiting_reload = false;
+        Self::cylinder_loaded(&env, &mut game, loaded);
+        Self::reset_turn_deadline(&env, &mut game)?;
+
+        Self::record_action(&mut game, &host)?;
+        Self::save_game(&env, &mut game);
+
+        Ok(game.bullet_position)
+    }
+
+    // ====================================================================
+    // 🔁 recargar_por_defecto — Reload past a stalled host
+    // ====================================================================
+    /// If the host hasn't called `recargar` by the reload deadline, any
+    /// living player may reload instead. The bullet is derived on-chain
+    /// exactly as in a session without a preloaded sequence. Returns the
+    /// new bullet position.
+    pub fn recargar_por_defecto(
+        env: Env,
+        session_id: u32,
+        player: Address,
+    ) -> Result<u32, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_PLAYING || !game.awaiting_reload {
+            return Err(Error::WrongPhase);
+        }
+        let seat = game.seats.get(player.clone()).ok_or(Error::NotPlayer)?;
+        if !game.players.get(seat).unwrap().is_alive {
+            return Err(Error::PlayerEliminated);
+        }
+        if env.ledger().sequence() <= game.turn_deadline_ledger {
+            return Err(Error::TurnNotExpired);
+        }
+
+        let arr = Self::reload_hash(&env, session_id, game.shots_fired);
+        let loaded =
+            Self::reload_bullets(&arr, game.current_chamber, game.num_chambers, game.num_bullets);
+        game.bullet_position = loaded.trailing_zeros();
+        game.seed_shots_fired = game.shots_fired;
+        game.awaiting_reload = false;
+        Self::cylinder_loaded(&env, &mut game, loaded);
+        Self::reset_turn_deadline(&env, &mut game)?;
+
+        Self::record_action(&mut game, &player)?;
+        Self::save_game(&env, &mut game);
+
+        Ok(game.bullet_position)
+    }
+
+    // ====================================================================
+    // ⏸️ pedir_tiempo — Tactical timeout
+    // ====================================================================
+    /// The current shooter spends one of their timeouts to push the turn
+    /// deadline back by `timeout_extension_ledgers`. Only meaningful when
+    /// turns are timed. Returns the timeouts they have left.
+    pub fn pedir_tiempo(env: Env, session_id: u32, player: Address) -> Result<u32, Error> {
+        player.require_auth();
+
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        Self::check_shot(&game, &player)?;
+
+        if game.turn_deadline_ledger == 0 {
+            return Err(Error::WrongMode);
+        }
+        let mut jugador = game.players.get(game.current_turn).unwrap();
+        if jugador.timeouts_left == 0 {
+            return Err(Error::NoTimeoutsLeft);
+        }
+        jugador.timeouts_left -= 1;
+        let left = jugador.timeouts_left;
+        game.players.set(game.current_turn, jugador);
+        game.turn_deadline_ledger = game
+            .turn_deadline_ledger
+            .checked_add(game.config.timeout_extension_ledgers)
+            .ok_or(Error::Overflow)?;
+
+        Self::record_action(&mut game, &player)?;
+        let deadline = game.turn_deadline_ledger;
+        Self::publish(&env, symbol_short!("timeout"), session_id, (player, deadline));
+
+        Self::save_game(&env, &mut game);
+
+        Ok(left)
+    }
+
+    // ====================================================================
+    // 🗳️ votar_saltar — Vote to skip an unresponsive player
+    // ====================================================================
+    /// Once the game has sat idle for `skip_vote_idle_ledgers` (a soft
+    /// threshold, short of the hard timeout), the other alive players may
+    /// vote to skip whoever holds the turn, timed game or not. Before
+    /// that the first vote is refused (`TurnNotExpired`), so a fresh turn
+    /// can't be voted out; once a vote is open, later votes count until
+    /// the turn moves. A majority of the others skips the current player.
+    /// Eliminating instead, per `skip_vote_eliminates`, takes at least two
+    /// votes that are also a strict majority of all living players, so a
+    /// lone opponent can't vote the shooter out. Votes reset when a vote
+    /// passes. The current player cannot vote (`NotPlayer`). Returns
+    /// whether the vote passed.
+    pub fn votar_saltar(env: Env, session_id: u32, voter: Address) -> Result<bool, Error> {
+        voter.require_auth();
+
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_PLAYING {
+            return Err(Error::WrongPhase);
+        }
+        if game.pending_elimination.is_some() {
+            return Err(Error::EliminationPending);
+        }
+        if game.awaiting_reload {
+            return Err(Error::AwaitingReload);
+        }
+        let seat = game.seats.get(voter.clone()).ok_or(Error::NotPlayer)?;
+        if seat == game.current_turn {
+            return Err(Error::NotPlayer);
+        }
+        if !game.players.get(seat).unwrap().is_alive {
+            return Err(Error::PlayerEliminated);
+        }
+        if game.skip_votes.contains(&voter) {
+            return Err(Error::AlreadyVoted);
+        }
+        // Votes stamp `last_action_ledger` themselves, so only the first
+        // one is held to the idle window
+        let idle_from = game.last_action_ledger.saturating_add(game.config.skip_vote_idle_ledgers);
+        if game.skip_votes.is_empty() && env.ledger().sequence() < idle_from {
+            return Err(Error::TurnNotExpired);
+        }
+        game.skip_votes.push_back(voter.clone());
+        Self::record_action(&mut game, &voter)?;
+        Self::publish(&env, symbol_short!("skipvote"), session_id, voter);
+
+        let alive = Self::count_alive(&game);
+        let votes = game.skip_votes.len();
+        let passed = if game.config.skip_vote_eliminates {
+            votes >= 2 && votes * 2 > alive
+        } else {
+            votes > (alive - 1) / 2
+        };
+        if passed {
+            let skipped = game.players.get(game.current_turn).unwrap().address;
+            Self::publish(&env, symbol_short!("skipped"), session_id, skipped);
+            if game.config.skip_vote_eliminates {
+                Self::kick_current(&env, &mut game)?;
+                Self::save_game(&env, &mut game);
+                return Ok(passed);
+            }
+            Self::advance_turn(&mut game);
+            Self::reset_turn_deadline(&env, &mut game)?;
+        }
+
+        Self::save_turn(&env, &mut game);
+
+        Ok(passed)
+    }
+
+    // ====================================================================
+    // 🎖️ tiro_de_honor — Winner's optional bravery shot
+    // ====================================================================
+    /// Within the honor window after the game ends, the winner may fire
+    /// one more chamber. The cylinder is loaded the way the next round's
+    /// reload would load it, and the proof must cover the chamber fired.
+    /// Surviving adds `bravery_bonus` to the winner's recorded points;
+    /// getting hit takes it away and forfeits `honor_forfeit_bps` of the
+    /// pot to protocol fees. While the window is open the pot stays in
+    /// escrow, so a hit always has it at stake; in `PAYOUT_AUTO` sessions
+    /// the rest is paid out once the shot lands.
+    ///
+    /// # Returns
+    /// `true` if the winner was hit, `false` if they survived.
+    pub fn tiro_de_honor(
+        env: Env,
+        session_id: u32,
+        winner: Address,
+        zk_proof: Bytes,
+    ) -> Result<bool, Error> {
+        winner.require_auth();
+
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_FINISHED {
+            return Err(Error::WrongPhase);
+        }
+        if game.winner != Some(winner.clone()) {
+            return Err(Error::NotWinner);
+        }
+        let config = Self::load_config(&env);
+        if game.honor_shot_taken
+            || config.honor_window_ledgers == 0
+            || env.ledger().sequence()
+                > game.finished_ledger.saturating_add(config.honor_window_ledgers)
+        {
+            return Err(Error::HonorUnavailable);
+        }
+
+        // No reload ever uses the final shot count, so the spin is fresh
+        let arr = Self::reload_hash(&env, session_id, game.shots_fired);
+        let chamber = Self::reload_start(&game);
+        let num_bullets =
+            Self::reload_bullet_count(&game.config, game.num_bullets, game.num_chambers);
+        let honor_bullet = Self::first_bullet(&arr, chamber, game.num_chambers, num_bullets);
+        Self::verify_zk_proof(
+            &env,
+            game.config.proof_system,
+            &zk_proof,
+            &game.bullet_commitment,
+            chamber,
+        )?;
+
+        let is_hit = honor_bullet == chamber;
+        if is_hit && config.honor_forfeit_bps > 0 {
+            for (token_addr, amount) in game.pot.iter() {
+                let forfeit = Self::bps_of(amount, config.honor_forfeit_bps);
+                if forfeit > 0 {
+                    Self::add_fee_balance(&env, &token_addr, forfeit)?;
+                    game.pot.set(token_addr, amount - forfeit);
+                }
             }
+        }
+
+        let seat = game.seats.get(winner.clone()).unwrap();
+        let mut jugador = game.players.get(seat).unwrap();
+        let bonus = config.bravery_bonus;
+        let (points, final_points) = if is_hit {
+            (jugador.points.checked_sub(bonus), jugador.final_points.checked_sub(bonus))
         } else {
-            // Survived — click!
-            env.events().publish(
-                (symbol_short!("click"), session_id),
-                player.clone(),
-            );
+            (jugador.points.checked_add(bonus), jugador.final_points.checked_add(bonus))
+        };
+        jugador.points = points.ok_or(Error::Overflow)?;
+        jugador.final_points = final_points.ok_or(Error::Overflow)?;
+        game.players.set(seat, jugador);
+        game.honor_shot_taken = true;
 
-            game.current_chamber += 1;
-            Self::advance_turn(&mut game);
+        Self::publish(&env, symbol_short!("honor"), session_id, is_hit);
+        if game.config.payout_mode == PAYOUT_AUTO {
+            Self::pay_pot(&env, &mut game, &winner);
         }
 
-        env.storage().temporary().set(&key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Self::record_action(&mut game, &winner)?;
+        Self::save_game(&env, &mut game);
 
         Ok(is_hit)
     }
 
+    // ====================================================================
+    // 💰 reclamar_premio — Winner withdraws the pot (claim payout mode)
+    // ====================================================================
+    /// In `PAYOUT_CLAIM` sessions the pot stays in escrow after the game
+    /// ends; the winner pulls it here. Safer for contract-account winners
+    /// that might reject a pushed transfer. A pot held for the honor
+    /// window is pulled here too, and claiming it passes on the honor
+    /// shot. Returns each token and the amount paid in it.
+    pub fn reclamar_premio(
+        env: Env,
+        session_id: u32,
+        winner: Address,
+    ) -> Result<Vec<(Address, i128)>, Error> {
+        winner.require_auth();
+
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_FINISHED {
+            return Err(Error::WrongPhase);
+        }
+        if game.winner != Some(winner.clone()) {
+            return Err(Error::NotWinner);
+        }
+        let paid = Self::pot_balances(&env, &game);
+        if paid.is_empty() {
+            return Err(Error::NothingToClaim);
+        }
+
+        // Once the pot is out, a hit would have nothing left to forfeit
+        game.honor_shot_taken = true;
+        Self::pay_pot(&env, &mut game, &winner);
+        Self::publish(&env, symbol_short!("claimed"), session_id, paid.clone());
+
+        Self::record_action(&mut game, &winner)?;
+        Self::save_game(&env, &mut game);
+
+        Ok(paid)
+    }
+
+    // ====================================================================
+    // 🔓 revelar_bala — Host opens the bullet commitment after the game
+    // ====================================================================
+    /// Once the game is finished the host publishes the salt behind the
+    /// commitment, so anyone can check the initial bullet wasn't moved.
+    /// The salt was stored at load; this marks it revealed, after which
+    /// queries serve it. A salt that doesn't open the commitment is
+    /// refused. Refused until `reveal_after_ledgers` have passed since
+    /// the finish.
+    pub fn revelar_bala(
+        env: Env,
+        session_id: u32,
+        host: Address,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        host.require_auth();
+
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_FINISHED {
+            return Err(Error::WrongPhase);
+        }
+        if game.host != host {
+            return Err(Error::NotPlayer);
+        }
+        if game.salt_revealed {
+            return Err(Error::AlreadyRevealed);
+        }
+        let reveal_from = game.finished_ledger.saturating_add(game.config.reveal_after_ledgers);
+        if env.ledger().sequence() < reveal_from {
+            return Err(Error::RevealTooEarly);
+        }
+
+        let opened = Self::compute_bullet_hash(
+            env.clone(),
+            salt.clone(),
+            Self::committed_position(&env, &game),
+        );
+        if opened != game.bullet_commitment {
+            return Err(Error::CommitmentMismatch);
+        }
+        game.salt_revealed = true;
+        Self::publish(&env, symbol_short!("revealed"), session_id, salt);
+
+        Self::record_action(&mut game, &host)?;
+        Self::save_game(&env, &mut game);
+
+        Ok(())
+    }
+
     // ====================================================================
     // 🔍 Internal helpers
     // ====================================================================
 
-    /// Verify ZK proof structural validity.
-    /// Production: verify Groth16 proof. Hackathon: non-zero check.
+    /// Verify a shot's ZK proof with the session's proof system.
+    /// Fallback: non-zero structural check. Groth16: a pairing check
+    /// against the admin's verifying key, with the public inputs pinned to
+    /// this commitment and chamber so a proof can't be replayed elsewhere.
     fn verify_zk_proof(
         env: &Env,
-        zk_proof: &BytesN<32>,
-        _bullet_commitment: &BytesN<32>,
+        proof_system: u32,
+        zk_proof: &Bytes,
+        bullet_commitment: &BytesN<32>,
+        chamber: u32,
     ) -> Result<(), Error> {
-        let zero = BytesN::from_array(env, &[0u8; 32]);
-        if *zk_proof == zero {
-            return Err(Error::InvalidProof);
+        // Bound the hot path's cost before looking inside the proof
+        let max_bytes = Self::load_config(env).max_proof_bytes;
+        if max_bytes > 0 && zk_proof.len() > max_bytes {
+            return Err(Error::ProofTooLarge);
         }
 
-        log!(env, "ZK proof accepted (fallback). Full BN254 verification ready for mainnet.");
-        Ok(())
+        match proof_system {
+            PROOF_SYSTEM_FALLBACK => {
+                if zk_proof.iter().all(|b| b == 0) {
+                    return Err(Error::InvalidProof);
+                }
+
+                log!(env, "ZK proof accepted (fallback). Full BN254 verification ready for mainnet.");
+                Ok(())
+            }
+            PROOF_SYSTEM_GROTH16 => {
+                let vk: VerifyingKey = match env.storage().instance().get(&DataKey::VerifyingKey) {
+                    Some(vk) => vk,
+                    None => {
+                        log!(env, "Groth16 proof rejected: no verifying key configured.");
+                        return Err(Error::InvalidProof);
+                    }
+                };
+                let proof = Self::decode_groth16(env, zk_proof).ok_or(Error::InvalidProof)?;
+                if proof.public_inputs != Self::groth16_inputs(env, bullet_commitment, chamber) {
+                    return Err(Error::InvalidProof);
+                }
+                if !Self::groth16_pairing(env, &vk, &proof) {
+                    return Err(Error::InvalidProof);
+                }
+                Ok(())
+            }
+            _ => Err(Error::UnsupportedProofSystem),
+        }
     }
 
-    /// Count alive players
-    fn count_alive(game: &PartidaRuleta) -> u32 {
-        let mut count = 0u32;
-        for i in 0..game.players.len() {
-            if game.players.get(i).unwrap().is_alive {
-                count += 1;
+    /// Split a wire proof `a (64) || b (128) || c (64) || inputs (32 each)`.
+    /// Points that aren't valid curve encodings are refused here, since
+    /// the host traps on them instead of failing the pairing.
+    fn decode_groth16(env: &Env, zk_proof: &Bytes) -> Option<Groth16Proof> {
+        if zk_proof.len() != 256 + 32 * GROTH16_PUBLIC_INPUTS {
+            return None;
+        }
+        let mut public_inputs = Vec::new(env);
+        for i in 0..GROTH16_PUBLIC_INPUTS {
+            let at = 256 + 32 * i;
+            public_inputs.push_back(zk_proof.slice(at..at + 32).try_into().ok()?);
+        }
+        let proof = Groth16Proof {
+            a: zk_proof.slice(0..64).try_into().ok()?,
+            b: zk_proof.slice(64..192).try_into().ok()?,
+            c: zk_proof.slice(192..256).try_into().ok()?,
+            public_inputs,
+        };
+        let on_curve = Self::g1_on_curve(&proof.a.to_array())
+            && Self::g2_on_curve(&proof.b.to_array())
+            && Self::g1_on_curve(&proof.c.to_array());
+        on_curve.then_some(proof)
+    }
+
+    /// Every point of `vk` is a valid curve encoding
+    fn verifying_key_on_curve(vk: &VerifyingKey) -> bool {
+        Self::g1_on_curve(&vk.alpha.to_array())
+            && Self::g2_on_curve(&vk.beta.to_array())
+            && Self::g2_on_curve(&vk.gamma.to_array())
+            && Self::g2_on_curve(&vk.delta.to_array())
+            && vk.ic.iter().all(|p| Self::g1_on_curve(&p.to_array()))
+    }
+
+    /// `x || y`, both below the field modulus, with `y² = x³ + 3` (or all
+    /// zeros for the point at infinity)
+    fn g1_on_curve(bytes: &[u8; 64]) -> bool {
+        if bytes.iter().all(|b| *b == 0) {
+            return true;
+        }
+        let (Some(x), Some(y)) = (Self::fp_from_be(&bytes[..32]), Self::fp_from_be(&bytes[32..]))
+        else {
+            return false;
+        };
+        let x3 = Self::fp_mul(&Self::fp_mul(&x, &x), &x);
+        Self::fp_mul(&y, &y) == Self::fp_add(&x3, &[3, 0, 0, 0])
+    }
+
+    /// `x.c1 || x.c0 || y.c1 || y.c0`, every part below the field modulus,
+    /// on the twist `y² = x³ + b` (or all zeros for the point at
+    /// infinity). Subgroup membership is left to the host.
+    fn g2_on_curve(bytes: &[u8; 128]) -> bool {
+        if bytes.iter().all(|b| *b == 0) {
+            return true;
+        }
+        let mut parts = [[0u64; 4]; 4];
+        for (i, part) in parts.iter_mut().enumerate() {
+            match Self::fp_from_be(&bytes[32 * i..32 * (i + 1)]) {
+                Some(fp) => *part = fp,
+                None => return false,
             }
         }
-        count
+        let x = (parts[1], parts[0]);
+        let y = (parts[3], parts[2]);
+        let x3 = Self::fp2_mul(&Self::fp2_mul(&x, &x), &x);
+        let rhs = (
+            Self::fp_add(&x3.0, &BN254_G2_B.0),
+            Self::fp_add(&x3.1, &BN254_G2_B.1),
+        );
+        Self::fp2_mul(&y, &y) == rhs
     }
 
-    /// Find the last alive player
-    fn find_last_alive(game: &PartidaRuleta) -> Option<Address> {
-        for i in 0..game.players.len() {
-            let p = game.players.get(i).unwrap();
-            if p.is_alive {
-                return Some(p.address.clone());
+    /// A 32-byte big-endian field element, if it is below the modulus
+    fn fp_from_be(bytes: &[u8]) -> Option<[u64; 4]> {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let at = 8 * (3 - i);
+            *limb = u64::from_be_bytes(bytes[at..at + 8].try_into().ok()?);
+        }
+        Self::fp_lt(&limbs, &BN254_P).then_some(limbs)
+    }
+
+    fn fp_lt(a: &[u64; 4], b: &[u64; 4]) -> bool {
+        for i in (0..4).rev() {
+            if a[i] != b[i] {
+                return a[i] < b[i];
             }
         }
-        None
+        false
     }
 
-    /// Advance current_turn to the next alive player
-    fn advance_turn(game: &mut PartidaRuleta) {
-        let n = game.players.len();
-        if n == 0 { return; }
-        let mut next = (game.current_turn + 1) % n;
-        // Loop until we find an alive player (max n iterations)
+    /// `a + b mod p` for reduced inputs (the sum can't leave 256 bits)
+    fn fp_add(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+        let mut sum = [0u64; 4];
+        let mut carry = false;
+        for i in 0..4 {
+            let (s, c1) = a[i].overflowing_add(b[i]);
+            let (s, c2) = s.overflowing_add(carry as u64);
+            sum[i] = s;
+            carry = c1 || c2;
+        }
+        if Self::fp_lt(&sum, &BN254_P) {
+            return sum;
+        }
+        let mut borrow = false;
+        for i in 0..4 {
+            let (d, b1) = sum[i].overflowing_sub(BN254_P[i]);
+            let (d, b2) = d.overflowing_sub(borrow as u64);
+            sum[i] = d;
+            borrow = b1 || b2;
+        }
+        sum
+    }
+
+    /// `p - a mod p`
+    fn fp_neg(a: &[u64; 4]) -> [u64; 4] {
+        if a.iter().all(|l| *l == 0) {
+            return *a;
+        }
+        let mut out = [0u64; 4];
+        let mut borrow = false;
+        for i in 0..4 {
+            let (d, b1) = BN254_P[i].overflowing_sub(a[i]);
+            let (d, b2) = d.overflowing_sub(borrow as u64);
+            out[i] = d;
+            borrow = b1 || b2;
+        }
+        out
+    }
+
+    /// `a · b mod p` by double-and-add over the bits of `b`
+    fn fp_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+        let mut acc = [0u64; 4];
+        for bit in (0..256).rev() {
+            acc = Self::fp_add(&acc, &acc);
+            if (b[bit / 64] >> (bit % 64)) & 1 == 1 {
+                acc = Self::fp_add(&acc, a);
+            }
+        }
+        acc
+    }
+
+    /// `(a0 + a1·i)(b0 + b1·i)` with `i² = -1`
+    fn fp2_mul(a: &([u64; 4], [u64; 4]), b: &([u64; 4], [u64; 4])) -> ([u64; 4], [u64; 4]) {
+        let re = Self::fp_add(&Self::fp_mul(&a.0, &b.0), &Self::fp_neg(&Self::fp_mul(&a.1, &b.1)));
+        let im = Self::fp_add(&Self::fp_mul(&a.0, &b.1), &Self::fp_mul(&a.1, &b.0));
+        (re, im)
+    }
+
+    /// The public inputs a shot proof must carry. The commitment's top three
+    /// bits are cleared so it always fits the BN254 scalar field.
+    fn groth16_inputs(env: &Env, bullet_commitment: &BytesN<32>, chamber: u32) -> Vec<BytesN<32>> {
+        let mut commitment = bullet_commitment.to_array();
+        commitment[0] &= 0x1f;
+        let mut fired = [0u8; 32];
+        fired[28..].copy_from_slice(&chamber.to_be_bytes());
+        Vec::from_array(
+            env,
+            [BytesN::from_array(env, &commitment), BytesN::from_array(env, &fired)],
+        )
+    }
+
+    /// e(-A, B) · e(alpha, beta) · e(vk_x, gamma) · e(C, delta) == 1
+    fn groth16_pairing(env: &Env, vk: &VerifyingKey, proof: &Groth16Proof) -> bool {
+        let bn254 = env.crypto().bn254();
+        let mut vk_x = Bn254G1Affine::from_bytes(vk.ic.get(0).unwrap());
+        for (i, input) in proof.public_inputs.iter().enumerate() {
+            let point = Bn254G1Affine::from_bytes(vk.ic.get(i as u32 + 1).unwrap());
+            vk_x = bn254.g1_add(&vk_x, &bn254.g1_mul(&point, &Fr::from_bytes(input)));
+        }
+        let g1 = Vec::from_array(
+            env,
+            [
+                -Bn254G1Affine::from_bytes(proof.a.clone()),
+                Bn254G1Affine::from_bytes(vk.alpha.clone()),
+                vk_x,
+                Bn254G1Affine::from_bytes(proof.c.clone()),
+            ],
+        );
+        let g2 = Vec::from_array(
+            env,
+            [
+                Bn254G2Affine::from_bytes(proof.b.clone()),
+                Bn254G2Affine::from_bytes(vk.beta.clone()),
+                Bn254G2Affine::from_bytes(vk.gamma.clone()),
+                Bn254G2Affine::from_bytes(vk.delta.clone()),
+            ],
+        );
+        bn254.pairing_check(g1, g2)
+    }
+
+    /// `disparar`'s preconditions, short of the proof: game in play, the
+    /// caller's turn, caller alive, chamber in range, no hit still pending
+    fn check_shot(game: &PartidaRuleta, player: &Address) -> Result<(), Error> {
+        if game.phase != PHASE_PLAYING {
+            return Err(Error::WrongPhase);
+        }
+        if game.pending_elimination.is_some() {
+            return Err(Error::EliminationPending);
+        }
+        if game.awaiting_reload {
+            return Err(Error::AwaitingReload);
+        }
+
+        // Verify it's this player's turn
+        let current_player = game.players.get(game.current_turn).unwrap();
+        if current_player.address != *player {
+            return Err(Error::NotYourTurn);
+        }
+        if !current_player.is_alive {
+            return Err(Error::PlayerEliminated);
+        }
+
+        // Verify chamber is valid
+        if game.current_chamber >= game.num_chambers {
+            return Err(Error::InvalidChamber);
+        }
+        Ok(())
+    }
+
+    /// Count alive players
+    fn count_alive(game: &PartidaRuleta) -> u32 {
+        let mut count = 0u32;
+        for i in 0..game.players.len() {
+            if game.players.get(i).unwrap().is_alive {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Sides still in the game: alive players, or in team sessions the
+    /// teams with a living member. The game ends when this reaches 1.
+    fn sides_alive(game: &PartidaRuleta) -> u32 {
+        if !game.config.team_mode {
+            return Self::count_alive(game);
+        }
+        let mut teams = [false; 2];
+        for p in game.players.iter() {
+            if p.is_alive {
+                teams[p.team as usize] = true;
+            }
+        }
+        teams.iter().filter(|t| **t).count() as u32
+    }
+
+    /// Whether `p` shares in `winner`'s win (themselves, or a teammate)
+    fn on_winning_side(game: &PartidaRuleta, p: &Jugador, winner: &Address) -> bool {
+        if p.address == *winner {
+            return true;
+        }
+        let winner_team = game.players.iter().find(|w| w.address == *winner).map(|w| w.team);
+        game.config.team_mode && winner_team == Some(p.team)
+    }
+
+    /// Find the last alive player (in team sessions, once one side is
+    /// left, a member of the winning team)
+    fn find_last_alive(game: &PartidaRuleta) -> Option<Address> {
+        for i in 0..game.players.len() {
+            let p = game.players.get(i).unwrap();
+            if p.is_alive {
+                return Some(p.address.clone());
+            }
+        }
+        None
+    }
+
+    /// Advance current_turn to the next alive player
+    fn advance_turn(game: &mut PartidaRuleta) {
+        game.skip_votes = Vec::new(game.players.env());
+        if !game.turn_order.is_empty() {
+            let n = game.turn_order.len();
+            let pos = game.turn_order.first_index_of(game.current_turn).unwrap_or(0);
+            for step in 1..=n {
+                let seat = game.turn_order.get((pos + step) % n).unwrap();
+                if game.players.get(seat).unwrap().is_alive {
+                    game.current_turn = seat;
+                    return;
+                }
+            }
+            return;
+        }
+        let n = game.players.len();
+        if n == 0 { return; }
+        let mut next = (game.current_turn + 1) % n;
+        // Loop until we find an alive player (max n iterations)
         for _ in 0..n {
             if game.players.get(next).unwrap().is_alive {
                 game.current_turn = next;
                 return;
             }
-            next = (next + 1) % n;
+            next = (next + 1) % n;
+        }
+    }
+
+    /// Entering play: make sure the first turn belongs to a living
+    /// player, moving it along the turn order if not. Fails if fewer than
+    /// `MIN_PLAYERS` are alive.
+    fn ensure_turn_alive(game: &mut PartidaRuleta) -> Result<(), Error> {
+        let alive = game.players.iter().filter(|p| p.is_alive).count() as u32;
+        if alive < MIN_PLAYERS {
+            return Err(Error::NotEnoughPlayers);
+        }
+        let valid = match game.players.get(game.current_turn) {
+            Some(p) => p.is_alive,
+            None => false,
+        };
+        if !valid {
+            game.current_turn %= game.players.len();
+            Self::advance_turn(game);
+        }
+        Ok(())
+    }
+
+    /// Order seats by points for the session's `turn_order_mode` (ties
+    /// keep join order) and hand the first turn to the head of the order
+    fn sort_turn_order_by_points(env: &Env, game: &mut PartidaRuleta) {
+        let descending = game.config.turn_order_mode == TURN_ORDER_POINTS_DESC;
+        let mut order: Vec<u32> = Vec::new(env);
+        for seat in 0..game.players.len() {
+            let points = game.players.get(seat).unwrap().points;
+            // Insertion sort: walk past every seat that goes before this one
+            let mut at = 0;
+            while at < order.len() {
+                let other = game.players.get(order.get(at).unwrap()).unwrap().points;
+                let goes_before = if descending { other >= points } else { other <= points };
+                if !goes_before {
+                    break;
+                }
+                at += 1;
+            }
+            order.insert(at, seat);
+        }
+        game.current_turn = order.get(0).unwrap();
+        game.turn_order = order;
+    }
+
+    /// Fisher-Yates over every seat, driven by
+    /// SHA256(commitment || session_id || round) so simulation and
+    /// submission draw the same seating. Seats are rebuilt and the hub
+    /// pair follows the new first two seats; the host keeps their role
+    /// wherever they land.
+    fn shuffle_seating(env: &Env, game: &mut PartidaRuleta) {
+        let mut seed = Bytes::from_array(env, &game.bullet_commitment.to_array());
+        seed.append(&Bytes::from_array(env, &game.session_id.to_be_bytes()));
+        seed.append(&Bytes::from_array(env, &game.round.to_be_bytes()));
+        let seed = env.crypto().sha256(&seed).to_array();
+        let mut i = game.players.len();
+        while i > 1 {
+            i -= 1;
+            let j = seed[i as usize] as u32 % (i + 1);
+            let a = game.players.get(i).unwrap();
+            game.players.set(i, game.players.get(j).unwrap());
+            game.players.set(j, a);
+        }
+        Self::reindex_seats(env, game);
+        game.hub_player1 = game.players.get(0).unwrap().address;
+        game.hub_player2 = game.players.get(1).unwrap().address;
+    }
+
+    /// Fisher-Yates over the alive seats, driven by the reload's seed
+    /// hash, then hand the turn to whoever is drawn first
+    fn shuffle_turn_order(env: &Env, game: &mut PartidaRuleta, seed: &[u8; 32]) {
+        let mut order = Vec::new(env);
+        for i in 0..game.players.len() {
+            if game.players.get(i).unwrap().is_alive {
+                order.push_back(i);
+            }
+        }
+        let mut i = order.len();
+        while i > 1 {
+            i -= 1;
+            // Byte 0 picked the bullet; the shuffle draws from the rest
+            let j = seed[1 + i as usize] as u32 % (i + 1);
+            let a = order.get(i).unwrap();
+            order.set(i, order.get(j).unwrap());
+            order.set(j, a);
+        }
+        game.current_turn = order.get(0).unwrap();
+        game.turn_order = order;
+        game.skip_votes = Vec::new(env);
+    }
+
+    /// Eliminate the player holding the turn after a hit: finish the game
+    /// if one player is left, otherwise auto-reload for the next round
+    fn eliminate_shooter(env: &Env, game: &mut PartidaRuleta) -> Result<(), Error> {
+        let mut dead_player = game.players.get(game.current_turn).unwrap();
+        dead_player.is_alive = false;
+        game.players.set(game.current_turn, dead_player.clone());
+        Self::refund_loser_share(env, game, game.current_turn)?;
+        game.eliminated.push_back(dead_player.address);
+
+        if Self::sides_alive(game) == 1 {
+            // Game over — find the winner
+            let winner = Self::find_last_alive(game).unwrap();
+            Self::finish_game(env, game, winner)
+        } else {
+            // 2+ alive — auto-reload cylinder for next round
+            Self::end_round(env, game)
+        }
+    }
+
+    /// Round boundary: eliminate whoever didn't pay the round's ante
+    /// (unless nobody did), then reload, or finish if one player is left
+    fn end_round(env: &Env, game: &mut PartidaRuleta) -> Result<(), Error> {
+        let ante = game.config.ante_per_round;
+        if ante > 0 {
+            let mut defaulters = Vec::new(env);
+            for seat in 0..game.players.len() {
+                let p = game.players.get(seat).unwrap();
+                if p.is_alive && p.ante_paid < ante {
+                    defaulters.push_back(seat);
+                }
+            }
+            if defaulters.len() < Self::count_alive(game) {
+                for seat in defaulters.iter() {
+                    let mut p = game.players.get(seat).unwrap();
+                    p.is_alive = false;
+                    game.players.set(seat, p.clone());
+                    Self::refund_loser_share(env, game, seat)?;
+                    game.eliminated.push_back(p.address.clone());
+                    Self::publish(env, symbol_short!("noante"), game.session_id, p.address);
+                }
+            }
+            for seat in 0..game.players.len() {
+                let mut p = game.players.get(seat).unwrap();
+                p.ante_paid = 0;
+                game.players.set(seat, p);
+            }
+
+            if Self::sides_alive(game) == 1 {
+                let winner = Self::find_last_alive(game).unwrap();
+                return Self::finish_game(env, game, winner);
+            }
+        }
+        Self::reload_cylinder(env, game)
+    }
+
+    /// Deterministic reload randomness: SHA256(session_id || shots_fired)
+    fn reload_hash(env: &Env, session_id: u32, shots_fired: u32) -> [u8; 32] {
+        let mut seed = Bytes::new(env);
+        seed.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        seed.append(&Bytes::from_array(env, &shots_fired.to_be_bytes()));
+        env.crypto().sha256(&seed).to_array()
+    }
+
+    /// Per-session shift for `bind_session_nonce`, from the reload
+    /// derivation at zero shots (which no reload uses)
+    fn session_offset(env: &Env, session_id: u32) -> u32 {
+        Self::reload_hash(env, session_id, 0)[0] as u32
+    }
+
+    /// The first-round position the host committed to, undoing the
+    /// session shift if one was applied. Call only once loaded.
+    fn committed_position(env: &Env, game: &PartidaRuleta) -> u32 {
+        let loaded = game.bullet_history.get(0).unwrap();
+        if game.config.bind_session_nonce && game.config.mode == MODE_STATIC {
+            let shift = Self::session_offset(env, game.session_id) % NUM_CHAMBERS;
+            (loaded + NUM_CHAMBERS - shift) % NUM_CHAMBERS
+        } else {
+            loaded
+        }
+    }
+
+    /// Bitmask of `num_bullets` distinct chambers drawn from `arr` in
+    /// `from..num_chambers`: byte `i` picks `from + arr[i] % range`,
+    /// stepping forward past chambers already loaded
+    fn reload_bullets(arr: &[u8; 32], from: u32, num_chambers: u32, num_bullets: u32) -> u64 {
+        let range = num_chambers - from;
+        let mut loaded = 0u64;
+        for b in arr.iter().take(num_bullets.min(range) as usize) {
+            let mut offset = (*b as u32) % range;
+            while loaded & (1 << (from + offset)) != 0 {
+                offset = (offset + 1) % range;
+            }
+            loaded |= 1 << (from + offset);
+        }
+        loaded
+    }
+
+    /// Chamber a reload resumes firing from. Continue mode skips the
+    /// chambers already fired (0..=current) and falls back to a full
+    /// reset once none are left.
+    fn reload_start(game: &PartidaRuleta) -> u32 {
+        let next_chamber = game.current_chamber + 1;
+        if game.config.reload_mode == RELOAD_CONTINUE && next_chamber < game.num_chambers {
+            next_chamber
+        } else {
+            0
+        }
+    }
+
+    /// Earliest of the chambers `reload_bullets` draws. The cylinder is
+    /// fired in order and the first hit ends the round, so that chamber
+    /// is the only one that matters.
+    fn first_bullet(arr: &[u8; 32], from: u32, num_chambers: u32, num_bullets: u32) -> u32 {
+        match Self::reload_bullets(arr, from, num_chambers, num_bullets) {
+            0 => from,
+            loaded => loaded.trailing_zeros(),
+        }
+    }
+
+    /// Bullets the next reload loads: the session's `bullets_per_round`
+    /// if set, else one more than before under escalation
+    fn reload_bullet_count(config: &SessionConfig, num_bullets: u32, num_chambers: u32) -> u32 {
+        if config.bullets_per_round > 0 {
+            config.bullets_per_round.min(num_chambers - 1).max(1)
+        } else if config.bullet_escalation {
+            (num_bullets + 1).min(num_chambers - 1).max(1)
+        } else {
+            num_bullets
+        }
+    }
+
+    /// Load a fresh bullet position for the next round and hand the turn on
+    fn reload_cylinder(env: &Env, game: &mut PartidaRuleta) -> Result<(), Error> {
+        // Sudden death: past the threshold, drop one chamber per reload
+        let threshold = game.config.sudden_death_after;
+        if threshold > 0 && game.shots_fired > threshold && game.num_chambers > MIN_CHAMBERS {
+            game.num_chambers -= 1;
+            Self::publish(env, symbol_short!("shrink"), game.session_id, game.num_chambers);
+        }
+
+        game.num_bullets =
+            Self::reload_bullet_count(&game.config, game.num_bullets, game.num_chambers);
+
+        let arr = Self::reload_hash(env, game.session_id, game.shots_fired);
+        game.current_chamber = Self::reload_start(game);
+        game.round = game.round.checked_add(1).ok_or(Error::Overflow)?;
+
+        if game.config.shuffle_each_round {
+            Self::shuffle_turn_order(env, game, &arr);
+        } else {
+            Self::advance_turn(game);
+        }
+        if game.config.bullet_sequence_commitment.is_some() {
+            // The host reveals this round's bullet with `recargar`, which
+            // starts the turn clock. Until then the deadline is the host's.
+            game.awaiting_reload = true;
+            let timeout = match Self::load_config(env).turn_timeout_ledgers {
+                0 => RELOAD_TIMEOUT_LEDGERS,
+                timeout => timeout,
+            };
+            game.turn_deadline_ledger = env.ledger().sequence().saturating_add(timeout);
+            Self::publish(env, symbol_short!("awaiting"), game.session_id, game.round);
+            return Ok(());
+        }
+        Self::reset_turn_deadline(env, game)?;
+        let loaded =
+            Self::reload_bullets(&arr, game.current_chamber, game.num_chambers, game.num_bullets);
+        game.bullet_position = loaded.trailing_zeros();
+        game.seed_shots_fired = game.shots_fired;
+        Self::cylinder_loaded(env, game, loaded);
+        Ok(())
+    }
+
+    /// Record a reload's bullet and every chamber it loaded, and announce it
+    fn cylinder_loaded(env: &Env, game: &mut PartidaRuleta, loaded: u64) {
+        game.bullet_history.push_back(game.bullet_position);
+        game.fatal_chambers.push_back(loaded);
+
+        CylinderReloaded {
+            session_id: game.session_id,
+            round: game.round,
+            bullet_position: if game.config.mode == MODE_STATIC {
+                Some(game.bullet_position)
+            } else {
+                None
+            },
+        }
+        .publish(env);
+    }
+
+    /// Fold a Merkle proof from `leaf` up to the root. At each level the
+    /// index's low bit says whether the running node is the right child.
+    fn merkle_root(
+        env: &Env,
+        leaf: BytesN<32>,
+        index: u32,
+        proof: &Vec<BytesN<32>>,
+    ) -> BytesN<32> {
+        let mut node = leaf;
+        let mut index = index;
+        for sibling in proof.iter() {
+            let mut preimage = Bytes::new(env);
+            if index & 1 == 0 {
+                preimage.append(&node.into());
+                preimage.append(&sibling.into());
+            } else {
+                preimage.append(&sibling.into());
+                preimage.append(&node.into());
+            }
+            node = env.crypto().sha256(&preimage).into();
+            index >>= 1;
+        }
+        node
+    }
+
+    /// Eliminate the current player without a shot (AFK kick or a passed
+    /// skip vote): their bond goes to the pot and the next player faces
+    /// the same chamber. Returns the removed address.
+    fn kick_current(env: &Env, game: &mut PartidaRuleta) -> Result<Address, Error> {
+        let mut kicked = game.players.get(game.current_turn).unwrap();
+        kicked.is_alive = false;
+        game.players.set(game.current_turn, kicked.clone());
+        Self::forfeit_bond(game, game.current_turn)?;
+        Self::refund_loser_share(env, game, game.current_turn)?;
+        game.eliminated.push_back(kicked.address.clone());
+
+        if Self::sides_alive(game) == 1 {
+            let winner = Self::find_last_alive(game).unwrap();
+            Self::finish_game(env, game, winner)?;
+        } else {
+            Self::advance_turn(game);
+            Self::reset_turn_deadline(env, game)?;
+        }
+        Ok(kicked.address)
+    }
+
+    /// Move a player's bond into the pot
+    fn forfeit_bond(game: &mut PartidaRuleta, seat: u32) -> Result<(), Error> {
+        let mut p = game.players.get(seat).unwrap();
+        if let Some(token_addr) = &game.bond_token {
+            if p.bond > 0 {
+                let held = game.pot.get(token_addr.clone()).unwrap_or(0);
+                let held = held.checked_add(p.bond).ok_or(Error::Overflow)?;
+                game.pot.set(token_addr.clone(), held);
+            }
+        }
+        p.bond = 0;
+        game.players.set(seat, p);
+        Ok(())
+    }
+
+    /// Add a session to, or drop it from, the active-session index. The
+    /// index is paged in persistent storage, so no single entry grows
+    /// without bound; new sessions go on the last page once its expired
+    /// entries are pruned, or start a new page if it is still full.
+    fn set_session_active(env: &Env, session_id: u32, active: bool) {
+        let store = env.storage().persistent();
+        let page_key = DataKey::SessionPageOf(session_id);
+        let current: Option<u32> = store.get(&page_key);
+        match (current, active) {
+            (None, true) => {
+                let pages: u32 = store.get(&DataKey::SessionPages).unwrap_or(0);
+                let mut page = pages.saturating_sub(1);
+                let mut ids = Self::pruned_session_page(env, page);
+                if pages == 0 || ids.len() >= SESSION_PAGE_SIZE {
+                    page = pages;
+                    ids = Vec::new(env);
+                    store.set(&DataKey::SessionPages, &(pages + 1));
+                }
+                ids.push_back(session_id);
+                Self::store_session_page(env, page, &ids);
+                store.set(&page_key, &page);
+                store.extend_ttl(&page_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+            }
+            (Some(page), false) => {
+                let mut ids = Self::session_page(env, page);
+                if let Some(i) = ids.first_index_of(session_id) {
+                    ids.remove(i);
+                }
+                Self::store_session_page(env, page, &ids);
+                store.remove(&page_key);
+            }
+            _ => {}
+        }
+        if store.has(&DataKey::SessionPages) {
+            store.extend_ttl(&DataKey::SessionPages, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+    }
+
+    fn session_page(env: &Env, page: u32) -> Vec<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SessionPage(page))
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn store_session_page(env: &Env, page: u32, ids: &Vec<u32>) {
+        let key = DataKey::SessionPage(page);
+        env.storage().persistent().set(&key, ids);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    /// A page of the session index with sessions whose game has expired
+    /// dropped (and unindexed)
+    fn pruned_session_page(env: &Env, page: u32) -> Vec<u32> {
+        let ids = Self::session_page(env, page);
+        let mut live = Vec::new(env);
+        for session_id in ids.iter() {
+            if env.storage().temporary().has(&DataKey::GameStatic(session_id)) {
+                live.push_back(session_id);
+            } else {
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::SessionPageOf(session_id));
+            }
+        }
+        if live.len() != ids.len() {
+            Self::store_session_page(env, page, &live);
+        }
+        live
+    }
+
+    /// Seats a lobby holds: 2v2 in team sessions
+    fn lobby_capacity(game: &PartidaRuleta) -> u32 {
+        if game.config.team_mode {
+            TEAM_MAX_PLAYERS
+        } else {
+            MAX_PLAYERS
+        }
+    }
+
+    /// Add or remove a session in the open-lobby list, dropping the
+    /// oldest entry beyond `MAX_OPEN_LOBBIES`
+    fn set_lobby_open(env: &Env, session_id: u32, open: bool) {
+        let mut lobbies = Self::open_lobbies(env);
+        match (lobbies.first_index_of(session_id), open) {
+            (None, true) => {
+                lobbies.push_back(session_id);
+                if lobbies.len() > MAX_OPEN_LOBBIES {
+                    lobbies.pop_front();
+                }
+            }
+            (Some(i), false) => {
+                lobbies.remove(i);
+            }
+            _ => return,
+        }
+        Self::store_open_lobbies(env, &lobbies);
+    }
+
+    fn open_lobbies(env: &Env) -> Vec<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OpenLobbies)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn store_open_lobbies(env: &Env, lobbies: &Vec<u32>) {
+        env.storage().persistent().set(&DataKey::OpenLobbies, lobbies);
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::OpenLobbies, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    /// Every indexed session whose game is still live, pruning the rest
+    fn active_sessions(env: &Env) -> Vec<u32> {
+        let pages: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::SessionPages)
+            .unwrap_or(0);
+        let mut sessions = Vec::new(env);
+        for page in 0..pages {
+            sessions.append(&Self::pruned_session_page(env, page));
+        }
+        sessions
+    }
+
+    /// Assemble a session from its static and dynamic halves
+    fn load_game(env: &Env, session_id: u32) -> Option<PartidaRuleta> {
+        let st: GameStatic = env
+            .storage()
+            .temporary()
+            .get(&DataKey::GameStatic(session_id))?;
+        let dy: GameDynamic = env
+            .storage()
+            .temporary()
+            .get(&DataKey::GameDynamic(session_id))?;
+        let storage = env.storage().temporary();
+        let shot_log = storage
+            .get(&DataKey::ShotLog(session_id))
+            .unwrap_or(Vec::new(env));
+        let eliminated = storage
+            .get(&DataKey::EliminatedLog(session_id))
+            .unwrap_or(Vec::new(env));
+
+        let mut players = st.players;
+        for i in 0..players.len() {
+            let mut p = players.get(i).unwrap();
+            p.shots_survived = dy.shots_survived.get(i).unwrap_or(0);
+            players.set(i, p);
+        }
+        Some(PartidaRuleta {
+            players,
+            seats: st.seats,
+            phase: dy.phase,
+            current_turn: dy.current_turn,
+            current_chamber: dy.current_chamber,
+            num_chambers: st.num_chambers,
+            num_bullets: st.num_bullets,
+            bullet_commitment: st.bullet_commitment,
+            bullet_salt: st.bullet_salt,
+            salt_revealed: st.salt_revealed,
+            bullet_position: st.bullet_position,
+            bullet_history: st.bullet_history,
+            fatal_chambers: st.fatal_chambers,
+            eliminated,
+            winner: st.winner,
+            session_id: st.session_id,
+            host: st.host,
+            shots_fired: dy.shots_fired,
+            hub_player1: st.hub_player1,
+            hub_player2: st.hub_player2,
+            config: st.config,
+            bond_token: st.bond_token,
+            pot: st.pot,
+            turn_deadline_ledger: dy.turn_deadline_ledger,
+            finished_ledger: st.finished_ledger,
+            reveal_winner_at_ledger: st.reveal_winner_at_ledger,
+            honor_shot_taken: st.honor_shot_taken,
+            last_action_ledger: dy.last_action_ledger,
+            round: st.round,
+            seed_shots_fired: st.seed_shots_fired,
+            skip_votes: dy.skip_votes,
+            turn_order: st.turn_order,
+            pending_elimination: dy.pending_elimination,
+            pending_until_ledger: dy.pending_until_ledger,
+            phase_timestamps: st.phase_timestamps,
+            hub_override: st.hub_override,
+            last_actor: dy.last_actor,
+            actions_count: dy.actions_count,
+            shot_log,
+            awaiting_reload: dy.awaiting_reload,
+            invalid_proof_attempts: dy.invalid_proof_attempts,
+        })
+    }
+
+    /// Persist a whole game: both halves and both logs, extending every
+    /// TTL. Calls that only move the turn along use `save_turn` instead.
+    fn save_game(env: &Env, game: &mut PartidaRuleta) {
+        if game.phase == PHASE_FINISHED && Self::pot_balances(env, game).is_empty() {
+            Self::set_session_active(env, game.session_id, false);
+        }
+
+        let mut players = game.players.clone();
+        for i in 0..players.len() {
+            let mut p = players.get(i).unwrap();
+            p.shots_survived = 0;
+            players.set(i, p);
+        }
+        let st = GameStatic {
+            players,
+            seats: game.seats.clone(),
+            num_chambers: game.num_chambers,
+            num_bullets: game.num_bullets,
+            bullet_commitment: game.bullet_commitment.clone(),
+            bullet_salt: game.bullet_salt.clone(),
+            salt_revealed: game.salt_revealed,
+            bullet_position: game.bullet_position,
+            bullet_history: game.bullet_history.clone(),
+            fatal_chambers: game.fatal_chambers.clone(),
+            winner: game.winner.clone(),
+            session_id: game.session_id,
+            host: game.host.clone(),
+            hub_player1: game.hub_player1.clone(),
+            hub_player2: game.hub_player2.clone(),
+            config: game.config.clone(),
+            bond_token: game.bond_token.clone(),
+            pot: game.pot.clone(),
+            finished_ledger: game.finished_ledger,
+            reveal_winner_at_ledger: game.reveal_winner_at_ledger,
+            honor_shot_taken: game.honor_shot_taken,
+            round: game.round,
+            seed_shots_fired: game.seed_shots_fired,
+            turn_order: game.turn_order.clone(),
+            phase_timestamps: game.phase_timestamps.clone(),
+            hub_override: game.hub_override.clone(),
+        };
+
+        let storage = env.storage().temporary();
+        storage.set(&DataKey::GameStatic(game.session_id), &st);
+        storage.set(&DataKey::EliminatedLog(game.session_id), &game.eliminated);
+        Self::save_shot_log(env, game);
+        Self::save_turn(env, game);
+    }
+
+    /// Persist a call that only changed the turn state (a miss, a failed
+    /// proof, a vote that didn't pass): stamp the ledger of this change and
+    /// write the dynamic half. The static half and the logs are not
+    /// rewritten, only kept alive alongside it.
+    fn save_turn(env: &Env, game: &mut PartidaRuleta) {
+        game.last_action_ledger = env.ledger().sequence();
+
+        let mut shots_survived = Vec::new(env);
+        for p in game.players.iter() {
+            shots_survived.push_back(p.shots_survived);
+        }
+        let dy = GameDynamic {
+            phase: game.phase,
+            current_turn: game.current_turn,
+            current_chamber: game.current_chamber,
+            shots_fired: game.shots_fired,
+            turn_deadline_ledger: game.turn_deadline_ledger,
+            last_action_ledger: game.last_action_ledger,
+            skip_votes: game.skip_votes.clone(),
+            shots_survived,
+            pending_elimination: game.pending_elimination.clone(),
+            pending_until_ledger: game.pending_until_ledger,
+            last_actor: game.last_actor.clone(),
+            actions_count: game.actions_count.clone(),
+            awaiting_reload: game.awaiting_reload,
+            invalid_proof_attempts: game.invalid_proof_attempts,
+        };
+
+        let storage = env.storage().temporary();
+        storage.set(&DataKey::GameDynamic(game.session_id), &dy);
+        for key in [
+            DataKey::GameStatic(game.session_id),
+            DataKey::GameDynamic(game.session_id),
+            DataKey::ShotLog(game.session_id),
+            DataKey::EliminatedLog(game.session_id),
+        ] {
+            storage.extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+    }
+
+    /// Write the shot log, which only `disparar` appends to
+    fn save_shot_log(env: &Env, game: &PartidaRuleta) {
+        env.storage()
+            .temporary()
+            .set(&DataKey::ShotLog(game.session_id), &game.shot_log);
+    }
+
+    /// Delete every storage entry of a session
+    fn remove_game(env: &Env, session_id: u32) {
+        let storage = env.storage().temporary();
+        storage.remove(&DataKey::GameStatic(session_id));
+        storage.remove(&DataKey::GameDynamic(session_id));
+        storage.remove(&DataKey::ShotLog(session_id));
+        storage.remove(&DataKey::EliminatedLog(session_id));
+    }
+
+    /// Contract config, falling back to defaults (no bonds, no timeout)
+    fn load_config(env: &Env) -> Config {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .unwrap_or(Config {
+                bond_token: None,
+                join_bond: 0,
+                turn_timeout_ledgers: 0,
+                bravery_bonus: 0,
+                honor_forfeit_bps: 0,
+                honor_window_ledgers: 0,
+                max_joins_per_ledger: 0,
+                theme: THEME_ROULETTE,
+                max_hub_points: i128::MAX,
+                stake_tokens: Vec::new(env),
+                consolation_points: 0,
+                dev_mode: false,
+                max_proof_bytes: 0,
+                reject_reused_commitments: false,
+                handicap: false,
+                handicap_table: Vec::new(env),
+                insurance_price: 0,
+                max_pot: i128::MAX,
+                max_active_games: 0,
+                protocol_fee_bps: 0,
+            })
+    }
+
+    /// Remember a loaded commitment, forgetting the oldest beyond
+    /// `MAX_TRACKED_COMMITMENTS`
+    fn track_commitment(env: &Env, commitment: &BytesN<32>) {
+        let used_key = DataKey::UsedCommitment(commitment.clone());
+        if !env.storage().persistent().has(&used_key) {
+            let mut recent: Vec<BytesN<32>> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::RecentCommitments)
+                .unwrap_or(Vec::new(env));
+            recent.push_back(commitment.clone());
+            if recent.len() > MAX_TRACKED_COMMITMENTS {
+                let oldest = recent.pop_front().unwrap();
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::UsedCommitment(oldest));
+            }
+            env.storage()
+                .persistent()
+                .set(&DataKey::RecentCommitments, &recent);
+            env.storage().persistent().extend_ttl(
+                &DataKey::RecentCommitments,
+                GAME_TTL_LEDGERS,
+                GAME_TTL_LEDGERS,
+            );
+            env.storage().persistent().set(&used_key, &true);
+        }
+        env.storage()
+            .persistent()
+            .extend_ttl(&used_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    /// Session 0 is reserved: clients use it as an unset default
+    fn check_session_id(session_id: u32) -> Result<(), Error> {
+        if session_id == 0 {
+            return Err(Error::InvalidSession);
+        }
+        Ok(())
+    }
+
+    /// Count a join against the player's per-ledger quota. The counter
+    /// lives in temporary storage keyed by the current ledger, so old
+    /// ledgers' counters simply expire.
+    fn check_join_rate(env: &Env, player: &Address) -> Result<(), Error> {
+        let limit = Self::load_config(env).max_joins_per_ledger;
+        if limit == 0 {
+            return Ok(());
+        }
+        let key = DataKey::JoinCount(player.clone(), env.ledger().sequence());
+        let joins: u32 = env.storage().temporary().get(&key).unwrap_or(0);
+        if joins >= limit {
+            return Err(Error::RateLimited);
+        }
+        env.storage().temporary().set(&key, &(joins + 1));
+        Ok(())
+    }
+
+    /// Rebuild the seat index after `players` was reordered or shrunk
+    fn reindex_seats(env: &Env, game: &mut PartidaRuleta) {
+        let mut seats = Map::new(env);
+        for i in 0..game.players.len() {
+            seats.set(game.players.get(i).unwrap().address, i);
+        }
+        game.seats = seats;
+    }
+
+    /// Start the clock on the current player's turn and announce it. The
+    /// new shooter also gets a fresh set of proof attempts.
+    fn reset_turn_deadline(env: &Env, game: &mut PartidaRuleta) -> Result<(), Error> {
+        game.invalid_proof_attempts = 0;
+        let timeout = Self::load_config(env).turn_timeout_ledgers;
+        if timeout == 0 {
+            game.turn_deadline_ledger = 0;
+            return Ok(());
+        }
+        game.turn_deadline_ledger =
+            env.ledger().sequence().checked_add(timeout).ok_or(Error::Overflow)?;
+        TurnStart {
+            session_id: game.session_id,
+            player: game.players.get(game.current_turn).unwrap().address,
+            turn_deadline_ledger: game.turn_deadline_ledger,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// Move the game to `new_phase` and record the transition
+    fn set_phase(env: &Env, game: &mut PartidaRuleta, new_phase: u32) {
+        PhaseChanged {
+            session_id: game.session_id,
+            from_phase: game.phase,
+            to_phase: new_phase,
+            at_ledger: env.ledger().sequence(),
+        }
+        .publish(env);
+        game.phase = new_phase;
+        game.phase_timestamps.set(new_phase, env.ledger().sequence());
+    }
+
+    /// The pot's `token` balance once `amount` more is paid in, or
+    /// `PotCapExceeded` if that would pass the admin's `max_pot`
+    fn pot_after(
+        game: &PartidaRuleta,
+        config: &Config,
+        token: &Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        game.pot
+            .get(token.clone())
+            .unwrap_or(0)
+            .checked_add(amount)
+            .filter(|held| *held <= config.max_pot)
+            .ok_or(Error::PotCapExceeded)
+    }
+
+    /// A fresh lobby, hosted by whoever joins first
+    fn new_game(env: &Env, session_id: u32, host: &Address) -> PartidaRuleta {
+        PartidaRuleta {
+            players: Vec::new(env),
+            seats: Map::new(env),
+            phase: PHASE_WAITING,
+            current_turn: 0,
+            current_chamber: 0,
+            num_chambers: NUM_CHAMBERS,
+            num_bullets: 1,
+            bullet_commitment: BytesN::from_array(env, &[0u8; 32]),
+            bullet_salt: None,
+            salt_revealed: false,
+            bullet_position: 0,
+            bullet_history: Vec::new(env),
+            fatal_chambers: Vec::new(env),
+            eliminated: Vec::new(env),
+            winner: None,
+            session_id,
+            host: host.clone(),
+            shots_fired: 0,
+            hub_player1: host.clone(),
+            hub_player2: host.clone(),
+            config: Self::default_session_config(env),
+            bond_token: Self::load_config(env).bond_token,
+            pot: Map::new(env),
+            turn_deadline_ledger: 0,
+            finished_ledger: 0,
+            reveal_winner_at_ledger: 0,
+            honor_shot_taken: false,
+            last_action_ledger: 0,
+            round: 0,
+            seed_shots_fired: 0,
+            skip_votes: Vec::new(env),
+            turn_order: Vec::new(env),
+            pending_elimination: None,
+            pending_until_ledger: 0,
+            phase_timestamps: Map::from_array(env, [(PHASE_WAITING, env.ledger().sequence())]),
+            hub_override: None,
+            last_actor: host.clone(),
+            actions_count: Map::new(env),
+            shot_log: Vec::new(env),
+            awaiting_reload: false,
+            invalid_proof_attempts: 0,
+        }
+    }
+
+    /// Rules a session starts with until the host changes them
+    fn default_session_config(env: &Env) -> SessionConfig {
+        SessionConfig {
+            sudden_death_after: 0,
+            proof_system: PROOF_SYSTEM_FALLBACK,
+            payout_mode: PAYOUT_AUTO,
+            mode: MODE_STATIC,
+            reveal_delay_ledgers: 0,
+            skip_vote_eliminates: false,
+            skip_vote_idle_ledgers: DEFAULT_SKIP_VOTE_IDLE_LEDGERS,
+            shuffle_each_round: false,
+            registration_deadline_ledger: 0,
+            turn_order_mode: TURN_ORDER_JOIN,
+            reload_mode: RELOAD_RESET,
+            elimination_delay_ledgers: 0,
+            host_fee: 0,
+            host_fee_token: None,
+            bullet_escalation: false,
+            prize_ladder: Vec::new(env),
+            ante_per_round: 0,
+            ante_token: None,
+            timeouts_per_player: 0,
+            timeout_extension_ledgers: 0,
+            bind_session_nonce: false,
+            team_mode: false,
+            bullet_sequence_commitment: None,
+            max_proof_attempts: 0,
+            loser_refund_bps: 0,
+            reveal_after_ledgers: 0,
+            bullets_per_round: 0,
+        }
+    }
+
+    fn new_player(env: &Env, address: &Address, points: i128, avatar: u32, bond: i128) -> Jugador {
+        Jugador {
+            address: address.clone(),
+            is_alive: true,
+            points,
+            avatar,
+            bond,
+            shots_survived: 0,
+            stakes: Map::new(env),
+            final_points: 0,
+            insured: false,
+            ante_paid: 0,
+            timeouts_left: 0,
+            team: 0,
+        }
+    }
+
+    /// Attribute a state-changing call to `actor` for sponsored-fee
+    /// accounting. Permissionless calls (AFK kicks, confirmations) and admin
+    /// tools are not attributed.
+    fn record_action(game: &mut PartidaRuleta, actor: &Address) -> Result<(), Error> {
+        let count = game.actions_count.get(actor.clone()).unwrap_or(0);
+        let count = count.checked_add(1).ok_or(Error::Overflow)?;
+        game.actions_count.set(actor.clone(), count);
+        game.last_actor = actor.clone();
+        Ok(())
+    }
+
+    /// End the game: report to the hub, settle bonds and the pot
+    fn finish_game(env: &Env, game: &mut PartidaRuleta, winner: Address) -> Result<(), Error> {
+        Self::set_phase(env, game, PHASE_FINISHED);
+        game.winner = Some(winner.clone());
+        game.turn_deadline_ledger = 0;
+        game.pending_elimination = None;
+        game.finished_ledger = env.ledger().sequence();
+        game.reveal_winner_at_ledger = game
+            .finished_ledger
+            .checked_add(game.config.reveal_delay_ledgers)
+            .ok_or(Error::Overflow)?;
+
+        let key = DataKey::FinishedGame(game.session_id);
+        let result = FinishedGame {
+            winner: winner.clone(),
+            finished_ledger: game.finished_ledger,
+            reveal_winner_at_ledger: game.reveal_winner_at_ledger,
+        };
+        env.storage().persistent().set(&key, &result);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        Self::report_to_hub(env, game.session_id, game, &winner);
+        for p in game.players.iter() {
+            let won = Self::on_winning_side(game, &p, &winner);
+            Self::record_result(env, game.session_id, &p.address, won);
+            Self::add_active_game(env, &p.address, game.session_id, false);
+        }
+
+        // The hub only takes a winner flag, so consolation is recorded here
+        let config = Self::load_config(env);
+        let consolation = config.consolation_points;
+        for i in 0..game.players.len() {
+            let mut p = game.players.get(i).unwrap();
+            p.final_points = if Self::on_winning_side(game, &p, &winner) {
+                p.points
+            } else {
+                p.points.saturating_add(consolation)
+            };
+            Self::refund_bond(env, game, &mut p);
+            game.players.set(i, p);
+        }
+        // With honor shots on, the pot waits in escrow for the winner's shot
+        if game.config.payout_mode == PAYOUT_AUTO && config.honor_window_ledgers == 0 {
+            Self::pay_pot(env, game, &winner);
+        }
+
+        Self::publish(env, symbol_short!("winner"), game.session_id, winner);
+        Ok(())
+    }
+
+    /// Count a finished game in the player's persistent stats
+    fn record_result(env: &Env, session_id: u32, player: &Address, won: bool) {
+        let key = DataKey::Stats(player.clone());
+        let mut stats = Self::load_stats(env, player);
+        // Finishing can't fail, so these saturate rather than error
+        stats.games_played = stats.games_played.saturating_add(1);
+        if won {
+            stats.wins = stats.wins.saturating_add(1);
+
+            // Trophy case: drop the oldest win once full
+            let wins_key = DataKey::Wins(player.clone());
+            let mut wins = Self::load_wins(env, player);
+            if wins.len() >= MAX_WINS_HISTORY {
+                wins.pop_front();
+            }
+            wins.push_back(session_id);
+            env.storage().persistent().set(&wins_key, &wins);
+            env.storage()
+                .persistent()
+                .extend_ttl(&wins_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+        env.storage().persistent().set(&key, &stats);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    /// Add `session_id` to (or drop it from) the player's unfinished
+    /// sessions, pruning any that expired or were abandoned on the way
+    fn add_active_game(env: &Env, player: &Address, session_id: u32, joined: bool) {
+        let key = DataKey::ActiveGames(player.clone());
+        let mut sessions = Vec::new(env);
+        for id in Self::live_active_games(env, player).iter() {
+            if id != session_id {
+                sessions.push_back(id);
+            }
+        }
+        if joined {
+            sessions.push_back(session_id);
+        }
+        env.storage().persistent().set(&key, &sessions);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    /// The player's recorded sessions that are still around with them
+    /// seated. Games live in temporary storage, so one nobody finished
+    /// drops out here once it expires.
+    fn live_active_games(env: &Env, player: &Address) -> Vec<u32> {
+        let sessions: Vec<u32> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ActiveGames(player.clone()))
+            .unwrap_or(Vec::new(env));
+        let mut live = Vec::new(env);
+        for id in sessions.iter() {
+            let game: Option<GameStatic> = env.storage().temporary().get(&DataKey::GameStatic(id));
+            if game.is_some_and(|g| g.seats.contains_key(player.clone())) {
+                live.push_back(id);
+            }
+        }
+        live
+    }
+
+    fn load_wins(env: &Env, player: &Address) -> Vec<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Wins(player.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn load_stats(env: &Env, player: &Address) -> PlayerStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Stats(player.clone()))
+            .unwrap_or(PlayerStats {
+                games_played: 0,
+                wins: 0,
+            })
+    }
+
+    /// Extra chambers before the bullet that also count as a hit for
+    /// this shooter (0 unless the handicap is on and their win rate
+    /// reaches a table row)
+    fn handicap_offset(env: &Env, player: &Address) -> u32 {
+        let config = Self::load_config(env);
+        if !config.handicap {
+            return 0;
+        }
+        let stats = Self::load_stats(env, player);
+        if stats.games_played == 0 {
+            return 0;
+        }
+        let win_rate_bps = (stats.wins as u64 * 10_000 / stats.games_played as u64) as u32;
+        let mut offset = 0;
+        for (min_bps, extra) in config.handicap_table.iter() {
+            if win_rate_bps >= min_bps && extra > offset {
+                offset = extra;
+            }
+        }
+        offset
+    }
+
+    /// Transfer every token balance in the pot to the winner and empty
+    /// it. With a prize ladder, each finisher gets their position's share
+    /// and the winner also takes the rounding dust and unfilled places.
+    fn pay_pot(env: &Env, game: &mut PartidaRuleta, winner: &Address) {
+        Self::take_protocol_fee(env, game);
+        if game.config.team_mode {
+            Self::split_pot_among_team(env, game, winner);
+            return;
+        }
+        let order = Self::finishing_order(env, game, winner);
+        let ladder = game.config.prize_ladder.clone();
+        for (token_addr, amount) in game.pot.iter() {
+            if amount <= 0 {
+                continue;
+            }
+            let client = token::TokenClient::new(env, &token_addr);
+            let mut rest = amount;
+            for (place, bps) in ladder.iter().enumerate().skip(1) {
+                let Some(player) = order.get(place as u32) else {
+                    break;
+                };
+                let share = Self::bps_of(amount, bps);
+                if share > 0 {
+                    client.transfer(&env.current_contract_address(), &player, &share);
+                    rest -= share;
+                }
+            }
+            if rest > 0 {
+                client.transfer(&env.current_contract_address(), winner, &rest);
+            }
+        }
+        game.pot = Map::new(env);
+    }
+
+    /// Move the protocol fee's share of each pot token into the
+    /// contract's fee balance
+    fn take_protocol_fee(env: &Env, game: &mut PartidaRuleta) {
+        let fee_bps = Self::load_config(env).protocol_fee_bps;
+        if fee_bps == 0 {
+            return;
+        }
+        for (token_addr, amount) in game.pot.iter() {
+            let fee = Self::bps_of(amount, fee_bps);
+            if fee <= 0 {
+                continue;
+            }
+            // A fee the balance can't hold stays in the pot rather than
+            // blocking the payout
+            if Self::add_fee_balance(env, &token_addr, fee).is_ok() {
+                game.pot.set(token_addr, amount - fee);
+            }
+        }
+    }
+
+    /// `bps` basis points of `amount`, rounded down, without overflowing
+    /// the intermediate product
+    fn bps_of(amount: i128, bps: u32) -> i128 {
+        let bps = bps as i128;
+        amount / 10_000 * bps + amount % 10_000 * bps / 10_000
+    }
+
+    /// Hold `amount` of `token_addr` for `withdraw_fees`
+    fn add_fee_balance(env: &Env, token_addr: &Address, amount: i128) -> Result<(), Error> {
+        let key = DataKey::FeeBalance(token_addr.clone());
+        let held = Self::get_fee_balance(env.clone(), token_addr.clone());
+        let held = held.checked_add(amount).ok_or(Error::Overflow)?;
+        env.storage().persistent().set(&key, &held);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Ok(())
+    }
+
+    /// Team sessions: equal shares to every member of the winning team,
+    /// with the indivisible remainder going to `winner`
+    fn split_pot_among_team(env: &Env, game: &mut PartidaRuleta, winner: &Address) {
+        let mut team = Vec::new(env);
+        for p in game.players.iter() {
+            if Self::on_winning_side(game, &p, winner) {
+                team.push_back(p.address);
+            }
+        }
+        for (token_addr, amount) in game.pot.iter() {
+            if amount <= 0 {
+                continue;
+            }
+            let client = token::TokenClient::new(env, &token_addr);
+            let share = amount / team.len() as i128;
+            let mut rest = amount;
+            for member in team.iter() {
+                if member != *winner && share > 0 {
+                    client.transfer(&env.current_contract_address(), &member, &share);
+                    rest -= share;
+                }
+            }
+            client.transfer(&env.current_contract_address(), winner, &rest);
+        }
+        game.pot = Map::new(env);
+    }
+
+    /// Winner first, then anyone else still alive (a manual resolution),
+    /// then the eliminated from last out to first out
+    fn finishing_order(env: &Env, game: &PartidaRuleta, winner: &Address) -> Vec<Address> {
+        let mut order = Vec::new(env);
+        order.push_back(winner.clone());
+        for p in game.players.iter() {
+            if p.is_alive && p.address != *winner {
+                order.push_back(p.address);
+            }
+        }
+        for i in (0..game.eliminated.len()).rev() {
+            let out = game.eliminated.get(i).unwrap();
+            if out != *winner {
+                order.push_back(out);
+            }
+        }
+        order
+    }
+
+    /// Non-zero pot balances as `(token, amount)` pairs
+    fn pot_balances(env: &Env, game: &PartidaRuleta) -> Vec<(Address, i128)> {
+        let mut balances = Vec::new(env);
+        for (token_addr, amount) in game.pot.iter() {
+            if amount > 0 {
+                balances.push_back((token_addr, amount));
+            }
+        }
+        balances
+    }
+
+    /// Move a token's staked-volume counter (negative for refunds)
+    fn add_token_volume(env: &Env, token_addr: &Address, delta: i128) -> Result<(), Error> {
+        let key = DataKey::TokenVolume(token_addr.clone());
+        let volume: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        let volume = volume.checked_add(delta).ok_or(Error::Overflow)?;
+        env.storage().persistent().set(&key, &volume);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        Ok(())
+    }
+
+    /// Return every player's stakes out of the pot
+    fn refund_stakes(env: &Env, game: &mut PartidaRuleta) -> Result<(), Error> {
+        for i in 0..game.players.len() {
+            let mut p = game.players.get(i).unwrap();
+            for (token_addr, amount) in p.stakes.iter() {
+                token::TokenClient::new(env, &token_addr).transfer(
+                    &env.current_contract_address(),
+                    &p.address,
+                    &amount,
+                );
+                Self::add_token_volume(env, &token_addr, -amount)?;
+                let held = game.pot.get(token_addr.clone()).unwrap_or(0) - amount;
+                game.pot.set(token_addr, held);
+            }
+            p.stakes = Map::new(env);
+            game.players.set(i, p);
+        }
+        Ok(())
+    }
+
+    /// Settle the pot per `resolution_mode` and finish with `winner`
+    fn resolve_game(
+        env: &Env,
+        game: &mut PartidaRuleta,
+        winner: Address,
+        resolution_mode: u32,
+    ) -> Result<(), Error> {
+        match resolution_mode {
+            RESOLVE_REFUND_ALL => Self::refund_stakes(env, game)?,
+            RESOLVE_SPLIT_SURVIVORS => Self::split_pot_among_alive(env, game),
+            _ => {}
+        }
+        Self::publish(env, symbol_short!("resolved"), game.session_id, resolution_mode);
+        Self::finish_game(env, game, winner)
+    }
+
+    /// Buy-back: hand an eliminated player `loser_refund_bps` of each of
+    /// their stakes out of the pot, leaving the rest to the winner
+    fn refund_loser_share(env: &Env, game: &mut PartidaRuleta, seat: u32) -> Result<(), Error> {
+        let bps = game.config.loser_refund_bps;
+        if bps == 0 {
+            return Ok(());
+        }
+        let mut p = game.players.get(seat).unwrap();
+        for (token_addr, amount) in p.stakes.iter() {
+            let refund = Self::bps_of(amount, bps);
+            if refund == 0 {
+                continue;
+            }
+            token::TokenClient::new(env, &token_addr).transfer(
+                &env.current_contract_address(),
+                &p.address,
+                &refund,
+            );
+            Self::add_token_volume(env, &token_addr, -refund)?;
+            let held = game.pot.get(token_addr.clone()).unwrap_or(0) - refund;
+            game.pot.set(token_addr.clone(), held);
+            p.stakes.set(token_addr, amount - refund);
+        }
+        game.players.set(seat, p);
+        Ok(())
+    }
+
+    /// Pay each pot balance out in equal shares to the alive players,
+    /// leaving only the indivisible remainder in the pot
+    fn split_pot_among_alive(env: &Env, game: &mut PartidaRuleta) {
+        let alive = Self::count_alive(game) as i128;
+        // Nobody to split among: the pot stays for the declared winner
+        if alive == 0 {
+            return;
+        }
+        for (token_addr, amount) in game.pot.iter() {
+            let share = amount / alive;
+            if share == 0 {
+                continue;
+            }
+            let token_client = token::TokenClient::new(env, &token_addr);
+            for p in game.players.iter() {
+                if p.is_alive {
+                    token_client.transfer(&env.current_contract_address(), &p.address, &share);
+                }
+            }
+            game.pot.set(token_addr, amount - share * alive);
+        }
+    }
+
+    /// Return a player's bond from escrow
+    fn refund_bond(env: &Env, game: &PartidaRuleta, player: &mut Jugador) {
+        if player.bond > 0 {
+            if let Some(token_addr) = &game.bond_token {
+                token::TokenClient::new(env, token_addr).transfer(
+                    &env.current_contract_address(),
+                    &player.address,
+                    &player.bond,
+                );
+            }
+            player.bond = 0;
+        }
+    }
+
+    /// Publish a `(topic, session_id)` event in the tuple-topic layout
+    /// the frontend already listens for, renamed under the neutral theme.
+    #[allow(deprecated)]
+    fn publish<D: IntoVal<Env, Val>>(env: &Env, topic: Symbol, session_id: u32, data: D) {
+        let topic = if Self::load_config(env).theme == THEME_NEUTRAL {
+            Self::neutral_topic(topic)
+        } else {
+            topic
+        };
+        env.events().publish((topic, session_id), data);
+    }
+
+    /// Neutral-theme name for a roulette-themed event topic
+    fn neutral_topic(topic: Symbol) -> Symbol {
+        if topic == symbol_short!("boom") {
+            symbol_short!("out")
+        } else if topic == symbol_short!("click") {
+            symbol_short!("safe")
+        } else if topic == symbol_short!("loaded") {
+            symbol_short!("ready")
+        } else if topic == symbol_short!("honor") {
+            symbol_short!("bonus")
+        } else {
+            topic
+        }
+    }
+
+    /// Report result to the Game Hub: the 2-player result, then every
+    /// eliminated player in elimination order, so seats beyond the hub
+    /// pair are accounted for too. The standard hub has no
+    /// `report_eliminations`; a failed report is ignored.
+    fn report_to_hub(env: &Env, session_id: u32, game: &PartidaRuleta, winner: &Address) {
+        let hub = GameHubClient::new(env, &Self::hub_address(env, game));
+        hub.end_game(&session_id, &Self::hub_player1_won(game, winner));
+        let _ = hub.try_report_eliminations(&session_id, &game.eliminated);
+    }
+
+    /// The session's hub override, else the contract-global hub
+    fn hub_address(env: &Env, game: &PartidaRuleta) -> Address {
+        game.hub_override.clone().unwrap_or_else(|| {
+            env.storage()
+                .instance()
+                .get(&DataKey::GameHubAddress)
+                .expect("GameHub not set")
+        })
+    }
+
+    /// Hub result for the registered pair. In a 2-player game the pair is
+    /// the whole table, so this is simply `winner == hub_player1` in
+    /// either orientation (host eliminated → `false`, guest eliminated →
+    /// `true`). If the overall winner is a third player, both hub players
+    /// were eliminated or the admin declared the winner, so the one who
+    /// outlasted the other (eliminated later, or not at all) takes the
+    /// head-to-head. In team sessions it's whether
+    /// `hub_player1`'s team won.
+    fn hub_player1_won(game: &PartidaRuleta, winner: &Address) -> bool {
+        if game.config.team_mode {
+            let hub_p1 = game.players.iter().find(|p| p.address == game.hub_player1);
+            return hub_p1.is_some_and(|p| Self::on_winning_side(game, &p, winner));
+        }
+        if *winner == game.hub_player1 {
+            return true;
+        }
+        if *winner == game.hub_player2 {
+            return false;
+        }
+        // `None` means never eliminated, which outlasts any elimination
+        let p1_out = game.eliminated.first_index_of(&game.hub_player1);
+        let p2_out = game.eliminated.first_index_of(&game.hub_player2);
+        match (p1_out, p2_out) {
+            (Some(p1), Some(p2)) => p1 > p2,
+            (p1, _) => p1.is_none(),
+        }
+    }
+
+    // ====================================================================
+    // 📖 Query Functions
+    // ====================================================================
+
+    /// Get full game state. During a reveal delay the game already
+    /// reads as finished but `winner` is withheld, and `bullet_salt` is
+    /// withheld until `revelar_bala`. Hidden-mode games report
+    /// `BULLET_POSITION_HIDDEN` and empty `bullet_history` and
+    /// `fatal_chambers` until they finish.
+    pub fn get_game(env: Env, session_id: u32) -> Result<PartidaRuleta, Error> {
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase == PHASE_FINISHED && env.ledger().sequence() < game.reveal_winner_at_ledger {
+            game.winner = None;
+        }
+        if !game.salt_revealed {
+            game.bullet_salt = None;
+        }
+        if game.config.mode == MODE_HIDDEN && game.phase != PHASE_FINISHED {
+            game.bullet_position = BULLET_POSITION_HIDDEN;
+            game.bullet_history = Vec::new(&env);
+            game.fatal_chambers = Vec::new(&env);
+        }
+        Ok(game)
+    }
+
+    /// Decode an XDR-serialized `PartidaRuleta` (e.g. a saved `get_game`
+    /// result) for indexers and debugging scripts. Well-formed XDR of any
+    /// other shape, including a game from an older schema, returns
+    /// `UnknownSchema`; bytes that aren't XDR at all still trap in the host.
+    pub fn parse_game(env: Env, data: Bytes) -> Result<PartidaRuleta, Error> {
+        // The host traps on a struct whose field names don't match, so
+        // compare them (nested structs included) against fresh values first
+        let val = Val::from_xdr(&env, &data).map_err(|_| Error::UnknownSchema)?;
+        let anyone = env.current_contract_address();
+        let game_template = Self::new_game(&env, 0, &anyone).into_val(&env);
+        let game = Self::struct_fields(&env, val, game_template).ok_or(Error::UnknownSchema)?;
+        let field = |name: &str| game.get(Symbol::new(&env, name)).ok_or(Error::UnknownSchema);
+        let list = |name: &str| {
+            Vec::<Val>::try_from_val(&env, &field(name)?).map_err(|_| Error::UnknownSchema)
+        };
+
+        let config_template = Self::default_session_config(&env).into_val(&env);
+        Self::struct_fields(&env, field("config")?, config_template).ok_or(Error::UnknownSchema)?;
+        let player_template = Self::new_player(&env, &anyone, 0, 0, 0).into_val(&env);
+        for p in list("players")? {
+            Self::struct_fields(&env, p, player_template).ok_or(Error::UnknownSchema)?;
+        }
+        let shot_template = ShotRecord {
+            shooter: anyone,
+            chamber: 0,
+            reach: 0,
+            hit: false,
+        }
+        .into_val(&env);
+        for shot in list("shot_log")? {
+            Self::struct_fields(&env, shot, shot_template).ok_or(Error::UnknownSchema)?;
+        }
+
+        PartidaRuleta::try_from_val(&env, &val).map_err(|_| Error::UnknownSchema)
+    }
+
+    /// `val`'s fields if it is a struct with the same field names as
+    /// `template`
+    fn struct_fields(env: &Env, val: Val, template: Val) -> Option<Map<Symbol, Val>> {
+        let fields = Map::<Symbol, Val>::try_from_val(env, &val).ok()?;
+        let expected = Map::<Symbol, Val>::try_from_val(env, &template).ok()?;
+        (fields.keys() == expected.keys()).then_some(fields)
+    }
+
+    /// What each seated player must do next, in seat order, so a UI can
+    /// show what is holding the game up (`ACTION_*`). While the host owes
+    /// a reload or a hit is pending confirmation, nobody is asked to shoot.
+    pub fn get_pending_actions(env: Env, session_id: u32) -> Result<Vec<(Address, u32)>, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        let stakes_enabled = !Self::load_config(&env).stake_tokens.is_empty();
+
+        let mut actions = Vec::new(&env);
+        for seat in 0..game.players.len() {
+            let p = game.players.get(seat).unwrap();
+            let action = match game.phase {
+                PHASE_WAITING if stakes_enabled && p.stakes.is_empty() => ACTION_STAKE,
+                PHASE_PLAYING if game.awaiting_reload => {
+                    if p.address == game.host { ACTION_RELOAD } else { ACTION_NONE }
+                }
+                PHASE_PLAYING if game.pending_elimination.is_some() => {
+                    if game.pending_elimination == Some(p.address.clone()) {
+                        ACTION_CONFIRM
+                    } else {
+                        ACTION_NONE
+                    }
+                }
+                PHASE_PLAYING if seat == game.current_turn => ACTION_SHOOT,
+                PHASE_FINISHED if p.address == game.host && !game.salt_revealed => {
+                    ACTION_REVEAL
+                }
+                _ => ACTION_NONE,
+            };
+            actions.push_back((p.address, action));
+        }
+        Ok(actions)
+    }
+
+    /// The rules a lobby was configured with, without the rest of the state
+    pub fn get_session_config(env: Env, session_id: u32) -> Result<SessionConfig, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        Ok(game.config)
+    }
+
+    /// Reloads the session has gone through (0 = still on the host's cylinder)
+    pub fn get_round(env: Env, session_id: u32) -> Result<u32, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        Ok(game.round)
+    }
+
+    /// Shots fired so far, fatal ones included. Reads only the dynamic
+    /// half of the game, so it is cheaper than `get_game`.
+    pub fn get_shots_fired(env: Env, session_id: u32) -> Result<u32, Error> {
+        let dy: GameDynamic = env
+            .storage()
+            .temporary()
+            .get(&DataKey::GameDynamic(session_id))
+            .ok_or(Error::GameNotFound)?;
+        Ok(dy.shots_fired)
+    }
+
+    /// Round, chamber and shot counters in one read. Safe to show in any
+    /// mode: the bullet position is not included.
+    pub fn get_round_info(env: Env, session_id: u32) -> Result<RoundInfo, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        Ok(RoundInfo {
+            round: game.round,
+            current_chamber: game.current_chamber,
+            num_chambers: game.num_chambers,
+            shots_fired: game.shots_fired,
+        })
+    }
+
+    /// `(phase, ledger)` for every phase the session has entered, in phase
+    /// order — e.g. how long the lobby waited and how long play took
+    pub fn get_phase_timeline(env: Env, session_id: u32) -> Result<Vec<(u32, u32)>, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        let mut timeline = Vec::new(&env);
+        for (phase, ledger) in game.phase_timestamps.iter() {
+            timeline.push_back((phase, ledger));
+        }
+        Ok(timeline)
+    }
+
+    /// State-changing calls each player has made in the session, for
+    /// organizers reconciling sponsored fees. Accounting only.
+    pub fn get_action_counts(env: Env, session_id: u32) -> Result<Vec<(Address, u32)>, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        let mut counts = Vec::new(&env);
+        for (player, count) in game.actions_count.iter() {
+            counts.push_back((player, count));
+        }
+        Ok(counts)
+    }
+
+    /// Everything the contract owes, per token, across the active-session
+    /// index: pots (stakes, antes, insurance premiums and forfeited bonds)
+    /// plus bonds still held for players. Should never exceed the
+    /// contract's balance of each token.
+    pub fn total_liabilities(env: Env) -> Result<Map<Address, i128>, Error> {
+        let mut owed: Map<Address, i128> = Map::new(&env);
+        for session_id in Self::active_sessions(&env).iter() {
+            let Some(game) = Self::load_game(&env, session_id) else {
+                continue; // Expired
+            };
+            for (token_addr, amount) in game.pot.iter() {
+                let total = owed.get(token_addr.clone()).unwrap_or(0);
+                owed.set(token_addr, total.checked_add(amount).ok_or(Error::Overflow)?);
+            }
+            if let Some(token_addr) = &game.bond_token {
+                for p in game.players.iter() {
+                    if p.bond > 0 {
+                        let total = owed.get(token_addr.clone()).unwrap_or(0);
+                        let total = total.checked_add(p.bond).ok_or(Error::Overflow)?;
+                        owed.set(token_addr.clone(), total);
+                    }
+                }
+            }
+        }
+        Ok(owed)
+    }
+
+    /// Net amount staked in `token` across all sessions (refunds excluded)
+    pub fn get_token_volume(env: Env, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TokenVolume(token))
+            .unwrap_or(0)
+    }
+
+    /// Protocol fees collected in `token` and not yet withdrawn
+    pub fn get_fee_balance(env: Env, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FeeBalance(token))
+            .unwrap_or(0)
+    }
+
+    /// A player's games played and wins across finished sessions
+    pub fn get_player_stats(env: Env, player: Address) -> PlayerStats {
+        Self::load_stats(&env, &player)
+    }
+
+    /// Unfinished sessions the player is seated in, as checked against
+    /// `max_active_games` on join
+    pub fn get_active_game_count(env: Env, player: Address) -> u32 {
+        Self::live_active_games(&env, &player).len()
+    }
+
+    /// Session ids the player has won, oldest first (the latest
+    /// `MAX_WINS_HISTORY` only)
+    pub fn get_player_wins(env: Env, player: Address) -> Vec<u32> {
+        Self::load_wins(&env, &player)
+    }
+
+    /// Whether no session has loaded `commitment` yet. Only commitments
+    /// loaded while `reject_reused_commitments` is on are tracked, and
+    /// only the latest `MAX_TRACKED_COMMITMENTS` of them.
+    pub fn commitment_is_fresh(env: Env, commitment: BytesN<32>) -> bool {
+        !env.storage()
+            .persistent()
+            .has(&DataKey::UsedCommitment(commitment))
+    }
+
+    /// Dry-run `disparar`'s checks for `player` without a proof or any
+    /// state change: `Ok` if a shot would be accepted, else the exact
+    /// error, so a UI can disable the fire button with the reason.
+    pub fn validate_shot(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        Self::check_shot(&game, &player)
+    }
+
+    /// Get the winner of a finished game, once its reveal ledger is
+    /// reached (`None` while it is still being played). Falls back to the
+    /// persistent `FinishedGame` once the live entries have expired. The
+    /// delay only paces front-ends: the hub is told at the final shot and
+    /// the `winner` event is published then.
+    pub fn get_winner(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
+        let (winner, reveal_at) = match Self::load_game(&env, session_id) {
+            Some(game) if game.phase != PHASE_FINISHED => return Ok(None),
+            Some(game) => (game.winner.unwrap(), game.reveal_winner_at_ledger),
+            None => {
+                let finished: FinishedGame = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::FinishedGame(session_id))
+                    .ok_or(Error::GameNotFound)?;
+                (finished.winner, finished.reveal_winner_at_ledger)
+            }
+        };
+
+        if env.ledger().sequence() < reveal_at {
+            return Err(Error::PendingReveal);
+        }
+        Ok(Some(winner))
+    }
+
+    /// Get the two players registered with the Game Hub for this session.
+    /// Only meaningful once the revolver is loaded.
+    pub fn get_hub_pairing(env: Env, session_id: u32) -> Result<(Address, Address), Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase == PHASE_WAITING {
+            return Err(Error::WrongPhase);
+        }
+        Ok((game.hub_player1, game.hub_player2))
+    }
+
+    /// Every bullet position the session used, in order: the host's
+    /// initial position followed by each auto-reload's SHA256-derived
+    /// position. Only available once the game is finished.
+    pub fn get_bullet_sequence(env: Env, session_id: u32) -> Result<Vec<u32>, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_FINISHED {
+            return Err(Error::WrongPhase);
+        }
+        Ok(game.bullet_history)
+    }
+
+    /// Every chamber loaded in `round` (0 is the host's load, then one per
+    /// reload), in ascending order. Available once the round is over;
+    /// hidden-mode games disclose nothing until they finish.
+    pub fn get_fatal_chambers(env: Env, session_id: u32, round: u32) -> Result<Vec<u32>, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        let finished = game.phase == PHASE_FINISHED;
+        if !finished && (round >= game.round || game.config.mode == MODE_HIDDEN) {
+            return Err(Error::WrongPhase);
+        }
+        let mut loaded = game.fatal_chambers.get(round).ok_or(Error::WrongPhase)?;
+        let mut chambers = Vec::new(&env);
+        while loaded != 0 {
+            chambers.push_back(loaded.trailing_zeros());
+            loaded &= loaded - 1;
+        }
+        Ok(chambers)
+    }
+
+    /// The salt the host published with `revelar_bala`, for auditors
+    pub fn get_revealed_salt(env: Env, session_id: u32) -> Result<BytesN<32>, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if !game.salt_revealed {
+            return Err(Error::NotRevealed);
+        }
+        game.bullet_salt.ok_or(Error::NotRevealed)
+    }
+
+    /// The revealed commitment opening, packaged for sharing. Only
+    /// available after `revelar_bala`.
+    pub fn get_fairness_proof(env: Env, session_id: u32) -> Result<FairnessProof, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if !game.salt_revealed {
+            return Err(Error::WrongPhase);
+        }
+        let salt = game.bullet_salt.clone().ok_or(Error::WrongPhase)?;
+        let bullet_position = Self::committed_position(&env, &game);
+        let sha_matches = Self::compute_bullet_hash(env.clone(), salt.clone(), bullet_position)
+            == game.bullet_commitment;
+        Ok(FairnessProof {
+            commitment: game.bullet_commitment,
+            salt,
+            bullet_position,
+            sha_matches,
+        })
+    }
+
+    /// Full fairness audit. Given the host's salt, checks the commitment
+    /// opens to the initial bullet, re-derives every reload position
+    /// (sudden death included) and replays the shot log, confirming each
+    /// recorded hit or miss is what the cylinder dictated. Returns `false`
+    /// on the first inconsistency.
+    ///
+    /// A reload may match either reload mode, since the admin can switch
+    /// modes mid-game with `cambiar_reload_mode`.
+    pub fn auditar_partida(env: Env, session_id: u32, salt: BytesN<32>) -> Result<bool, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        let history = game.bullet_history.clone();
+        let mut bullet = history.get(0).ok_or(Error::WrongPhase)?;
+
+        let committed = Self::committed_position(&env, &game);
+        if Self::compute_bullet_hash(env.clone(), salt, committed) != game.bullet_commitment {
+            return Ok(false);
+        }
+
+        let threshold = game.config.sudden_death_after;
+        let shots = game.shot_log.len();
+        let mut num_chambers = NUM_CHAMBERS;
+        let mut num_bullets = 1;
+        // Chambers the next shot may legitimately be fired from
+        let mut chambers = (0, None);
+        let mut round = 0;
+        for (i, shot) in game.shot_log.iter().enumerate() {
+            let reach_end = shot.chamber.checked_add(shot.reach).ok_or(Error::Overflow)?;
+            let expected = shot.chamber <= bullet && reach_end >= bullet;
+            if (shot.chamber != chambers.0 && Some(shot.chamber) != chambers.1)
+                || shot.hit != expected
+            {
+                return Ok(false);
+            }
+            if !shot.hit {
+                chambers = (shot.chamber + 1, None);
+                continue;
+            }
+
+            // A hit reloads unless it ended the game (or awaits confirmation)
+            round += 1;
+            let Some(recorded) = history.get(round) else {
+                if i as u32 + 1 < shots {
+                    return Ok(false);
+                }
+                break;
+            };
+            let shots_fired = i as u32 + 1;
+            if threshold > 0 && shots_fired > threshold && num_chambers > MIN_CHAMBERS {
+                num_chambers -= 1;
+            }
+            num_bullets = Self::reload_bullet_count(&game.config, num_bullets, num_chambers);
+            let arr = Self::reload_hash(&env, session_id, shots_fired);
+            let next = shot.chamber + 1;
+            let (resets, continues) = if game.config.bullet_sequence_commitment.is_some() {
+                // Opened against the root by `recargar`; only the range is left
+                (recorded < num_chambers, next <= recorded && recorded < num_chambers)
+            } else {
+                let resets = recorded == Self::first_bullet(&arr, 0, num_chambers, num_bullets);
+                let continues = if next < num_chambers {
+                    recorded == Self::first_bullet(&arr, next, num_chambers, num_bullets)
+                } else {
+                    false // Nothing left to skip to: the contract reset instead
+                };
+                (resets, continues)
+            };
+            chambers = match (resets, continues) {
+                (true, true) => (0, Some(next)),
+                (true, false) => (0, None),
+                (false, true) => (next, None),
+                (false, false) => return Ok(false),
+            };
+            bullet = recorded;
+        }
+
+        // Every recorded reload must be backed by a hit in the log
+        Ok(round + 1 >= history.len())
+    }
+
+    /// The seed the current round's bullet was derived from:
+    /// `SHA256(session_id_be || shots_fired_be)`, with `shots_fired` as of
+    /// the hit that triggered the reload that loaded the live bullet (a
+    /// hit still awaiting confirmation, or one that ended the game, hasn't
+    /// reloaded). In reset mode with one bullet,
+    /// `bullet_position == seed[0] % num_chambers`. Static mode only, and
+    /// only after a reload (the host picks round 0's bullet).
+    pub fn get_current_seed(env: Env, session_id: u32) -> Result<BytesN<32>, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.config.mode != MODE_STATIC
+            || game.round == 0
+            || game.config.bullet_sequence_commitment.is_some()
+        {
+            return Err(Error::WrongPhase);
+        }
+        Ok(BytesN::from_array(
+            &env,
+            &Self::reload_hash(&env, session_id, game.seed_shots_fired),
+        ))
+    }
+
+    /// Pulls left before the bullet in the current round ("N clicks to
+    /// boom"). Static mode only, since it reveals the bullet's distance.
+    pub fn safe_chambers_remaining(env: Env, session_id: u32) -> Result<u32, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.config.mode != MODE_STATIC {
+            return Err(Error::WrongMode);
+        }
+        if game.phase != PHASE_PLAYING {
+            return Err(Error::WrongPhase);
+        }
+        if game.current_chamber <= game.bullet_position {
+            Ok(game.bullet_position.saturating_sub(game.current_chamber))
+        } else {
+            Ok(0)
+        }
+    }
+
+    /// Pulls left in the current cylinder before it has to be reloaded,
+    /// bullet or not. Reveals nothing about the bullet, so any mode.
+    pub fn chambers_remaining(env: Env, session_id: u32) -> Result<u32, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_PLAYING {
+            return Err(Error::WrongPhase);
+        }
+        Ok(game.num_chambers.saturating_sub(game.current_chamber))
+    }
+
+    /// Ledger of the session's most recent state change, for staleness
+    /// monitoring
+    pub fn get_last_action_ledger(env: Env, session_id: u32) -> Result<u32, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        Ok(game.last_action_ledger)
+    }
+
+    /// How many shots the winner survived, for the results screen
+    pub fn get_winner_margin(env: Env, session_id: u32) -> Result<u32, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        let winner = match (game.phase, &game.winner) {
+            (PHASE_FINISHED, Some(w)) => w.clone(),
+            _ => return Err(Error::WrongPhase),
+        };
+        let seat = game.seats.get(winner).unwrap();
+        Ok(game.players.get(seat).unwrap().shots_survived)
+    }
+
+    /// Check a session's state invariants (for fuzzing and debugging):
+    /// seat index matches `players`, eliminated players are exactly the
+    /// dead ones, the turn points at a living player and the chamber and
+    /// bullet are in range while playing, and a winner exists iff the
+    /// game is finished. Returns `InconsistentState` on any violation.
+    pub fn check_invariants(env: Env, session_id: u32) -> Result<(), Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        let total = game.players.len();
+        if game.seats.len() != total {
+            return Err(Error::InconsistentState);
+        }
+        for i in 0..total {
+            let p = game.players.get(i).unwrap();
+            if game.seats.get(p.address.clone()) != Some(i) {
+                return Err(Error::InconsistentState);
+            }
+            if p.is_alive == game.eliminated.contains(&p.address) {
+                return Err(Error::InconsistentState);
+            }
+        }
+        if Self::count_alive(&game) + game.eliminated.len() != total {
+            return Err(Error::InconsistentState);
         }
-    }
 
-    /// Report result to the Game Hub (2-player interface)
-    fn report_to_hub(env: &Env, session_id: u32, game: &PartidaRuleta, winner: &Address) {
-        let hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub not set");
-        let hub = GameHubClient::new(env, &hub_addr);
-        hub.end_game(&session_id, &(*winner == game.hub_player1));
+        if game.phase == PHASE_PLAYING {
+            let turn_alive = match game.players.get(game.current_turn) {
+                Some(p) => p.is_alive,
+                None => false,
+            };
+            if !turn_alive
+                || game.current_chamber >= game.num_chambers
+                || game.bullet_position >= game.num_chambers
+            {
+                return Err(Error::InconsistentState);
+            }
+        }
+        if game.winner.is_some() != (game.phase == PHASE_FINISHED) {
+            return Err(Error::InconsistentState);
+        }
+
+        Ok(())
     }
 
-    // ====================================================================
-    // 📖 Query Functions
-    // ====================================================================
+    /// Per-token pot balances, for mixed-currency lobbies
+    pub fn get_multi_pot(env: Env, session_id: u32) -> Result<Vec<(Address, i128)>, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        Ok(Self::pot_balances(&env, &game))
+    }
 
-    /// Get full game state
-    pub fn get_game(env: Env, session_id: u32) -> Result<PartidaRuleta, Error> {
-        let key = DataKey::Game(session_id);
-        env.storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)
+    /// Whether a session has been created (without the `GameNotFound` round-trip)
+    pub fn session_exists(env: Env, session_id: u32) -> bool {
+        env.storage().temporary().has(&DataKey::GameStatic(session_id))
     }
 
     /// Get alive players
@@ -499,12 +4051,7 @@ impl ZkMafiaContract {
         env: Env,
         session_id: u32,
     ) -> Result<Vec<Address>, Error> {
-        let key = DataKey::Game(session_id);
-        let game: PartidaRuleta = env
-            .storage()
-            .temporary()
-            .get(&key)
-            .ok_or(Error::GameNotFound)?;
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
 
         let mut alive = Vec::new(&env);
         for i in 0..game.players.len() {
@@ -516,6 +4063,133 @@ impl ZkMafiaContract {
         Ok(alive)
     }
 
+    /// Whether one player is still alive, without fetching the list
+    pub fn is_alive(env: Env, session_id: u32, player: Address) -> Result<bool, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        let seat = game.seats.get(player).ok_or(Error::NotPlayer)?;
+        Ok(game.players.get(seat).unwrap().is_alive)
+    }
+
+    /// The cylinder as drawn by a widget. Chambers before
+    /// `current_chamber` have been fired, including those a continue-mode
+    /// reload skipped past; the bullet is shown in static mode only.
+    pub fn get_cylinder_state(env: Env, session_id: u32) -> Result<CylinderState, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        let mut fired_chambers = Vec::new(&env);
+        for chamber in 0..game.current_chamber {
+            fired_chambers.push_back(chamber);
+        }
+        let bullet_visible = if game.config.mode == MODE_STATIC && game.phase != PHASE_WAITING {
+            Some(game.bullet_position)
+        } else {
+            None
+        };
+
+        Ok(CylinderState {
+            num_chambers: game.num_chambers,
+            current_chamber: game.current_chamber,
+            fired_chambers,
+            bullet_visible,
+        })
+    }
+
+    /// Pre-flight for `disparar`: would this proof pass the proof gate for
+    /// the chamber about to be fired? A malformed, oversized or mismatched
+    /// proof is `false`; games not in play are `WrongPhase`.
+    pub fn would_accept_proof(env: Env, session_id: u32, zk_proof: Bytes) -> Result<bool, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+        if game.phase != PHASE_PLAYING {
+            return Err(Error::WrongPhase);
+        }
+
+        match Self::verify_zk_proof(
+            &env,
+            game.config.proof_system,
+            &zk_proof,
+            &game.bullet_commitment,
+            game.current_chamber,
+        ) {
+            Ok(()) => Ok(true),
+            Err(Error::InvalidProof) | Err(Error::ProofTooLarge) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Snapshot for streaming overlays, polled each tick. Carries only
+    /// what is safe to display: the bullet position is withheld in
+    /// hidden mode, and the winner is left to `get_winner`.
+    pub fn subscribe_info(env: Env, session_id: u32) -> Result<ObserverInfo, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        let mut alive = Vec::new(&env);
+        for p in game.players.iter() {
+            if p.is_alive {
+                alive.push_back(p.address);
+            }
+        }
+        let current_player = if game.phase == PHASE_PLAYING {
+            game.players.get(game.current_turn).map(|p| p.address)
+        } else {
+            None
+        };
+        let bullet_position = if game.config.mode == MODE_STATIC && game.phase != PHASE_WAITING {
+            Some(game.bullet_position)
+        } else {
+            None
+        };
+
+        Ok(ObserverInfo {
+            phase: game.phase,
+            current_player,
+            alive,
+            shots_fired: game.shots_fired,
+            round: game.round,
+            bullet_commitment: game.bullet_commitment,
+            bullet_position,
+        })
+    }
+
+    /// Waiting sessions with a free seat, oldest first, for lobby
+    /// browsers. Ids whose lobby expired, started or closed registration
+    /// are pruned as they are found.
+    pub fn get_open_lobbies(env: Env) -> Vec<u32> {
+        let lobbies = Self::open_lobbies(&env);
+        let mut open = Vec::new(&env);
+        for session_id in lobbies.iter() {
+            let Some(game) = Self::load_game(&env, session_id) else {
+                continue;
+            };
+            let deadline = game.config.registration_deadline_ledger;
+            if game.phase == PHASE_WAITING
+                && game.players.len() < Self::lobby_capacity(&game)
+                && (deadline == 0 || env.ledger().sequence() <= deadline)
+            {
+                open.push_back(session_id);
+            }
+        }
+        if open.len() != lobbies.len() {
+            Self::store_open_lobbies(&env, &open);
+        }
+        open
+    }
+
+    /// Get a player's seat (index into `players`) via the seat index
+    pub fn get_seat(env: Env, session_id: u32, player: Address) -> Result<u32, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        game.seats.get(player).ok_or(Error::NotPlayer)
+    }
+
+    /// The session's host, who loads the revolver. Passes to the first
+    /// seat when the host leaves the lobby.
+    pub fn get_host(env: Env, session_id: u32) -> Result<Address, Error> {
+        let game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        Ok(game.host)
+    }
+
     /// SHA256 commitment helper (for off-chain use and testing)
     /// Returns SHA256(salt_bytes || position_byte)
     pub fn compute_bullet_hash(env: Env, salt: BytesN<32>, position: u32) -> BytesN<32> {
@@ -525,6 +4199,41 @@ impl ZkMafiaContract {
         env.crypto().sha256(&preimage).into()
     }
 
+    /// The position a reset-mode, one-bullet reload picks:
+    /// `SHA256(session_id_be || shots_fired_be)[0] % num_chambers`, with
+    /// `shots_fired` as of the hit. Lets clients re-derive a reload.
+    pub fn compute_reload_position(
+        env: Env,
+        session_id: u32,
+        shots_fired: u32,
+        num_chambers: u32,
+    ) -> Result<u32, Error> {
+        if num_chambers == 0 {
+            return Err(Error::InvalidChamber);
+        }
+        let arr = Self::reload_hash(&env, session_id, shots_fired);
+        Ok(Self::first_bullet(&arr, 0, num_chambers, 1))
+    }
+
+    /// Whether a game with these parameters must end with a winner, for
+    /// hosts checking their settings before loading. True when there is
+    /// at least one bullet that fits the cylinder, the reload mode is
+    /// known (both modes always reload a bullet into a chamber still to
+    /// be fired) and at least two players are seated. Every hit then
+    /// removes a player within `num_chambers` pulls.
+    pub fn config_is_terminating(
+        _env: Env,
+        num_chambers: u32,
+        num_bullets: u32,
+        reload_mode: u32,
+        num_players: u32,
+    ) -> bool {
+        num_bullets >= 1
+            && num_bullets <= num_chambers
+            && reload_mode <= RELOAD_CONTINUE
+            && num_players >= 2
+    }
+
     // ====================================================================
     // 🔧 Admin
     // ====================================================================
@@ -565,6 +4274,331 @@ impl ZkMafiaContract {
             .set(&DataKey::GameHubAddress, &new_hub);
     }
 
+    /// Hubs `cargar_revolver` may route a session to
+    pub fn get_approved_hubs(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ApprovedHubs)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Approve another hub for sessions to report to. It must be a
+    /// deployed contract.
+    pub fn add_hub(env: Env, hub: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        if !matches!(hub.executable(), Some(Executable::Wasm(_))) {
+            return Err(Error::InvalidConfig);
+        }
+        let mut hubs = Self::get_approved_hubs(env.clone());
+        if !hubs.contains(&hub) {
+            hubs.push_back(hub);
+            env.storage().instance().set(&DataKey::ApprovedHubs, &hubs);
+        }
+        Ok(())
+    }
+
+    /// Withdraw a hub's approval. Sessions already loaded keep reporting
+    /// to it.
+    pub fn remove_hub(env: Env, hub: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        let mut hubs = Self::get_approved_hubs(env.clone());
+        let index = hubs.first_index_of(&hub).ok_or(Error::UnknownHub)?;
+        hubs.remove(index);
+        env.storage().instance().set(&DataKey::ApprovedHubs, &hubs);
+        Ok(())
+    }
+
+    pub fn get_config(env: Env) -> Config {
+        Self::load_config(&env)
+    }
+
+    pub fn set_config(env: Env, config: Config) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        if config.join_bond < 0
+            || config.theme > THEME_NEUTRAL
+            || config.max_hub_points < 0
+            || config.consolation_points < 0
+            || config.insurance_price < 0
+            || config.max_pot < 0
+            || config.protocol_fee_bps >= 10_000
+            || config.honor_forfeit_bps > 10_000
+        {
+            return Err(Error::InvalidConfig);
+        }
+        env.storage().instance().set(&DataKey::Config, &config);
+        Ok(())
+    }
+
+    /// Install the Groth16 verifying key used by `PROOF_SYSTEM_GROTH16`
+    /// sessions. It must carry one IC point per public input plus one, all
+    /// of its points on the curve.
+    pub fn set_verifying_key(env: Env, vk: VerifyingKey) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        if vk.ic.len() != GROTH16_PUBLIC_INPUTS + 1 || !Self::verifying_key_on_curve(&vk) {
+            return Err(Error::InvalidConfig);
+        }
+        env.storage().instance().set(&DataKey::VerifyingKey, &vk);
+        Ok(())
+    }
+
+    /// Send tokens that reached the contract outside any game to `to`.
+    /// Only the surplus over `total_liabilities` and the fee balance can
+    /// leave, so active pots, held bonds and fees stay covered.
+    pub fn recuperar_token(
+        env: Env,
+        token: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidStake);
+        }
+
+        let client = token::TokenClient::new(&env, &token);
+        let contract = env.current_contract_address();
+        let escrowed = Self::total_liabilities(env.clone())?
+            .get(token.clone())
+            .unwrap_or(0)
+            .checked_add(Self::get_fee_balance(env.clone(), token))
+            .ok_or(Error::Overflow)?;
+        if amount > client.balance(&contract) - escrowed {
+            return Err(Error::ExceedsRecoverable);
+        }
+        client.transfer(&contract, &to, &amount);
+        Ok(())
+    }
+
+    /// Transfer the protocol fees collected in `token` to `to` and zero
+    /// the balance. Returns the amount sent.
+    pub fn withdraw_fees(env: Env, token: Address, to: Address) -> Result<i128, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let amount = Self::get_fee_balance(env.clone(), token.clone());
+        if amount <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::FeeBalance(token.clone()));
+        token::TokenClient::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount,
+        );
+        Ok(amount)
+    }
+
+    /// Migration-safety tool: if an in-flight game's `current_turn` no
+    /// longer points at a living player (e.g. stored under older turn
+    /// logic), move it to the next alive seat. Returns the resulting turn.
+    pub fn reconciliar_turno(env: Env, session_id: u32) -> Result<u32, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_PLAYING {
+            return Err(Error::WrongPhase);
+        }
+
+        let valid = match game.players.get(game.current_turn) {
+            Some(p) => p.is_alive,
+            None => false,
+        };
+        if !valid {
+            // An out-of-range turn wraps onto a seat that may itself be
+            // playable; only step past it when that seat is dead too
+            game.current_turn %= game.players.len();
+            if !game.players.get(game.current_turn).unwrap().is_alive {
+                Self::advance_turn(&mut game);
+            }
+            Self::reset_turn_deadline(&env, &mut game)?;
+            Self::save_game(&env, &mut game);
+        }
+
+        Ok(game.current_turn)
+    }
+
+    /// Admin dispute tool: end an in-progress game with a declared
+    /// winner (reported to the hub as usual) and settle the pot per
+    /// `resolution_mode` (`RESOLVE_*`). Whatever the refund or split
+    /// leaves, such as forfeited bonds or rounding dust, follows the
+    /// session's payout mode to the winner.
+    pub fn resolver_manualmente(
+        env: Env,
+        session_id: u32,
+        winner: Address,
+        resolution_mode: u32,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_PLAYING {
+            return Err(Error::WrongPhase);
+        }
+        if resolution_mode > RESOLVE_SPLIT_SURVIVORS {
+            return Err(Error::InvalidConfig);
+        }
+        let seat = game.seats.get(winner.clone()).ok_or(Error::NotPlayer)?;
+        if !game.players.get(seat).unwrap().is_alive {
+            return Err(Error::PlayerEliminated);
+        }
+
+        Self::resolve_game(&env, &mut game, winner, resolution_mode)?;
+        Self::save_game(&env, &mut game);
+
+        Ok(())
+    }
+
+    /// Admin abort: like `resolver_manualmente`, but the contract picks
+    /// the winner the hub is told about — the survivor with the most
+    /// points, ties going to the lowest address. Returns that winner.
+    pub fn abortar_partida(
+        env: Env,
+        session_id: u32,
+        resolution_mode: u32,
+    ) -> Result<Address, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_PLAYING {
+            return Err(Error::WrongPhase);
+        }
+        if resolution_mode > RESOLVE_SPLIT_SURVIVORS {
+            return Err(Error::InvalidConfig);
+        }
+
+        let mut best: Option<Jugador> = None;
+        for p in game.players.iter().filter(|p| p.is_alive) {
+            let beats_best = match &best {
+                None => true,
+                Some(b) => p.points > b.points || (p.points == b.points && p.address < b.address),
+            };
+            if beats_best {
+                best = Some(p);
+            }
+        }
+        let winner = best.ok_or(Error::GameAlreadyEnded)?.address;
+
+        Self::resolve_game(&env, &mut game, winner.clone(), resolution_mode)?;
+        Self::save_game(&env, &mut game);
+
+        Ok(winner)
+    }
+
+    /// Live-repair tool: switch an in-progress game's `reload_mode`. The
+    /// change applies from the next reload; if the current round's
+    /// chamber or bullet is out of step (the bullet already passed, or
+    /// either beyond the cylinder), the round restarts at chamber 0.
+    /// Returns the resulting `current_chamber`.
+    pub fn cambiar_reload_mode(env: Env, session_id: u32, new_mode: u32) -> Result<u32, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let mut game = Self::load_game(&env, session_id).ok_or(Error::GameNotFound)?;
+
+        if game.phase != PHASE_PLAYING {
+            return Err(Error::WrongPhase);
+        }
+        if new_mode > RELOAD_CONTINUE {
+            return Err(Error::InvalidConfig);
+        }
+
+        game.config.reload_mode = new_mode;
+        if game.bullet_position >= game.num_chambers {
+            game.bullet_position %= game.num_chambers;
+        }
+        if game.current_chamber > game.bullet_position {
+            game.current_chamber = 0;
+        }
+        Self::save_game(&env, &mut game);
+
+        Ok(game.current_chamber)
+    }
+
+    /// Dev-only: delete a session's storage so the same session id can
+    /// be recreated fresh. Escrowed bonds and stakes are not refunded.
+    /// Fails with `DevModeDisabled` unless `dev_mode` is on.
+    pub fn reset_session(env: Env, session_id: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+        if !Self::load_config(&env).dev_mode {
+            return Err(Error::DevModeDisabled);
+        }
+
+        if let Some(game) = Self::load_game(&env, session_id) {
+            if game.phase != PHASE_FINISHED {
+                for p in game.players.iter() {
+                    Self::add_active_game(&env, &p.address, session_id, false);
+                }
+            }
+        }
+        Self::remove_game(&env, session_id);
+        Self::set_session_active(&env, session_id, false);
+        Self::set_lobby_open(&env, session_id, false);
+        Ok(())
+    }
+
+    /// Release number of the WASM currently live (`CONTRACT_VERSION`)
+    pub fn get_version(_env: Env) -> u32 {
+        CONTRACT_VERSION
+    }
+
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
         let admin: Address = env
             .storage()
@@ -576,5 +4610,46 @@ impl ZkMafiaContract {
     }
 }
 
+// ============================================================================
+// Test vectors (test builds only)
+// ============================================================================
+
+/// Golden vector for client/circuit developers: a commitment, its
+/// opening, and the hit/miss result expected for every chamber of the
+/// committed round.
+#[cfg(test)]
+#[derive(Clone, Debug)]
+pub struct TestVector {
+    pub commitment: BytesN<32>,
+    pub salt: BytesN<32>,
+    pub bullet_position: u32, // Position the commitment opens to
+    pub loaded_position: u32, // Chamber actually loaded (shifted under nonce binding)
+    pub hits: Vec<bool>,      // Per chamber, following `loaded_position`
+}
+
+#[cfg(test)]
+impl ZkMafiaContract {
+    /// Build a `TestVector` for a loaded session's committed round from
+    /// the salt stored at load.
+    pub fn export_test_vector(env: &Env, session_id: u32) -> TestVector {
+        let game = Self::load_game(env, session_id).expect("game not found");
+        let salt = game.bullet_salt.clone().expect("revolver not loaded");
+        let loaded = game.bullet_history.get(0).expect("revolver not loaded");
+        let bullet_position = Self::committed_position(env, &game);
+
+        let mut hits = Vec::new(env);
+        for chamber in 0..game.num_chambers {
+            hits.push_back(chamber == loaded);
+        }
+        TestVector {
+            commitment: game.bullet_commitment,
+            salt,
+            bullet_position,
+            loaded_position: loaded,
+            hits,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test;
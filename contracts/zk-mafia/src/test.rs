@@ -2,7 +2,12 @@
 extern crate std;
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Bytes, BytesN, Env, Event as _, U256,
+    crypto::bn254::{Bn254G1Affine, Bn254G2Affine, Fr},
+};
 
 mod mock_game_hub {
     soroban_sdk::contractimport!(
@@ -25,18 +30,179 @@ fn setup_env() -> (Env, Address, Address, Address, Address, Address) {
     (env, contract, player1, player2, player3, game_hub)
 }
 
+/// Game Hub stand-in that records what it was told, so tests can read it back
+mod recording_hub {
+    use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Vec};
+
+    #[contracttype]
+    pub enum HubKey {
+        Started(u32),
+        Ended(u32),
+        Eliminated(u32),
+    }
+
+    #[contract]
+    pub struct RecordingHub;
+
+    #[contractimpl]
+    impl RecordingHub {
+        pub fn start_game(
+            env: Env,
+            _game_id: Address,
+            session_id: u32,
+            player1: Address,
+            player2: Address,
+            player1_points: i128,
+            player2_points: i128,
+        ) {
+            env.storage().instance().set(
+                &HubKey::Started(session_id),
+                &(player1, player2, player1_points, player2_points),
+            );
+        }
+
+        pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
+            env.storage()
+                .instance()
+                .set(&HubKey::Ended(session_id), &player1_won);
+        }
+
+        pub fn report_eliminations(env: Env, session_id: u32, eliminated: Vec<Address>) {
+            env.storage()
+                .instance()
+                .set(&HubKey::Eliminated(session_id), &eliminated);
+        }
+
+        pub fn can_start(_env: Env, _game_id: Address, _session_id: u32) -> bool {
+            true
+        }
+
+        pub fn started(env: Env, session_id: u32) -> Option<(Address, Address, i128, i128)> {
+            env.storage().instance().get(&HubKey::Started(session_id))
+        }
+
+        pub fn ended(env: Env, session_id: u32) -> Option<bool> {
+            env.storage().instance().get(&HubKey::Ended(session_id))
+        }
+
+        pub fn eliminated(env: Env, session_id: u32) -> Option<Vec<Address>> {
+            env.storage().instance().get(&HubKey::Eliminated(session_id))
+        }
+    }
+}
+
+/// Game Hub stand-in that vetoes every session
+mod veto_hub {
+    use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+    #[contract]
+    pub struct VetoHub;
+
+    #[contractimpl]
+    impl VetoHub {
+        pub fn start_game(
+            _env: Env,
+            _game_id: Address,
+            _session_id: u32,
+            _player1: Address,
+            _player2: Address,
+            _player1_points: i128,
+            _player2_points: i128,
+        ) {
+            panic!("vetoed session was started");
+        }
+
+        pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {}
+
+        pub fn report_eliminations(_env: Env, _session_id: u32, _eliminated: Vec<Address>) {}
+
+        pub fn can_start(_env: Env, _game_id: Address, _session_id: u32) -> bool {
+            false
+        }
+    }
+}
+
+/// The hackathon's standard hub: just `start_game` and `end_game`
+mod standard_hub {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    #[contract]
+    pub struct StandardHub;
+
+    #[contractimpl]
+    impl StandardHub {
+        pub fn start_game(
+            _env: Env,
+            _game_id: Address,
+            _session_id: u32,
+            _player1: Address,
+            _player2: Address,
+            _player1_points: i128,
+            _player2_points: i128,
+        ) {
+        }
+
+        pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {}
+    }
+}
+
+/// Like `setup_env`, but the contract reports to a `RecordingHub`
+fn setup_recording_env() -> (Env, Address, Address, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let game_hub = env.register(recording_hub::RecordingHub, ());
+    let contract = env.register(ZkMafiaContract, (&admin, &game_hub));
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    let player3 = Address::generate(&env);
+
+    (env, contract, player1, player2, player3, game_hub)
+}
+
+/// Helper: create a token and mint `amount` to each given player
+fn setup_token(env: &Env, players: &[&Address], amount: i128) -> Address {
+    let issuer = Address::generate(env);
+    let token_id = env.register_stellar_asset_contract_v2(issuer).address();
+    let sac = StellarAssetClient::new(env, &token_id);
+    for p in players {
+        sac.mint(p, &amount);
+    }
+    token_id
+}
+
+/// Helper: first topic of each event `contract` published in the last call
+fn event_topics(env: &Env, contract: &Address) -> std::vec::Vec<std::string::String> {
+    use soroban_sdk::xdr::{ContractEventBody, ScVal};
+    env.events()
+        .all()
+        .filter_by_contract(contract)
+        .events()
+        .iter()
+        .filter_map(|e| {
+            let ContractEventBody::V0(body) = &e.body;
+            match body.topics.first() {
+                Some(ScVal::Symbol(sym)) => Some(sym.0.to_utf8_string_lossy()),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
 /// Helper: register 3 players. Returns player count.
 fn join_all_players(
-    env: &Env,
+    _env: &Env,
     client: &ZkMafiaContractClient,
     session_id: u32,
     p1: &Address,
     p2: &Address,
     p3: &Address,
 ) -> u32 {
-    assert_eq!(client.entrar_a_la_ruleta(&session_id, p1, &100), 1);
-    assert_eq!(client.entrar_a_la_ruleta(&session_id, p2, &100), 2);
-    assert_eq!(client.entrar_a_la_ruleta(&session_id, p3, &100), 3);
+    assert_eq!(client.entrar_a_la_ruleta(&session_id, p1, &100, &0), 1);
+    assert_eq!(client.entrar_a_la_ruleta(&session_id, p2, &100, &1), 2);
+    assert_eq!(client.entrar_a_la_ruleta(&session_id, p3, &100, &2), 3);
     3
 }
 
@@ -48,8 +214,8 @@ fn join_two_players(
     p1: &Address,
     p2: &Address,
 ) -> u32 {
-    assert_eq!(client.entrar_a_la_ruleta(&session_id, p1, &100), 1);
-    assert_eq!(client.entrar_a_la_ruleta(&session_id, p2, &100), 2);
+    assert_eq!(client.entrar_a_la_ruleta(&session_id, p1, &100, &0), 1);
+    assert_eq!(client.entrar_a_la_ruleta(&session_id, p2, &100, &1), 2);
     2
 }
 
@@ -63,10 +229,27 @@ fn load_revolver(
 ) -> BytesN<32> {
     let salt = BytesN::from_array(env, &[42u8; 32]);
     let commitment = client.compute_bullet_hash(&salt, &bullet_pos);
-    client.cargar_revolver(&session_id, host, &commitment, &bullet_pos);
+    client.cargar_revolver(&session_id, host, &commitment, &bullet_pos, &salt, &None, &false);
     commitment
 }
 
+/// Helper: current player keeps firing until the game finishes. Returns turns taken.
+fn play_to_end(client: &ZkMafiaContractClient, session_id: u32, proof: &Bytes) -> u32 {
+    let mut turns = 0;
+    loop {
+        let g = client.get_game(&session_id);
+        if g.phase == PHASE_FINISHED {
+            return turns;
+        }
+        let current = g.players.get(g.current_turn).unwrap();
+        client.disparar(&session_id, &current.address, proof);
+        turns += 1;
+        if turns > 30 {
+            panic!("Game didn't end within 30 turns");
+        }
+    }
+}
+
 // ============================================================================
 // Test: 3 players join, lobby full
 // ============================================================================
@@ -113,7 +296,7 @@ fn test_cannot_join_full_lobby() {
     join_all_players(&env, &client, session_id, &p1, &p2, &p3);
 
     let p4 = Address::generate(&env);
-    let result = client.try_entrar_a_la_ruleta(&session_id, &p4, &100);
+    let result = client.try_entrar_a_la_ruleta(&session_id, &p4, &100, &3);
     assert!(result.is_err());
 }
 
@@ -136,6 +319,103 @@ fn test_cargar_revolver_starts_game() {
     assert_eq!(game.bullet_position, 3);
 }
 
+// ============================================================================
+// Test: The commitment must open to the loaded position
+// ============================================================================
+#[test]
+fn test_cargar_revolver_checks_commitment() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 21;
+    let salt = BytesN::from_array(&env, &[42u8; 32]);
+    let commitment = client.compute_bullet_hash(&salt, &3);
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+
+    // Committed to 3, claiming 1
+    assert_eq!(
+        client.try_cargar_revolver(&session_id, &p1, &commitment, &1, &salt, &None, &false),
+        Err(Ok(Error::CommitmentMismatch))
+    );
+    // Right position, wrong salt
+    let other_salt = BytesN::from_array(&env, &[43u8; 32]);
+    assert_eq!(
+        client.try_cargar_revolver(&session_id, &p1, &commitment, &3, &other_salt, &None, &false),
+        Err(Ok(Error::CommitmentMismatch))
+    );
+    assert_eq!(client.get_game(&session_id).phase, PHASE_WAITING);
+
+    client.cargar_revolver(&session_id, &p1, &commitment, &3, &salt, &None, &false);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, PHASE_PLAYING);
+    // Stored for the reveal, but not served while the game runs
+    assert_eq!(game.bullet_salt, None);
+    env.as_contract(&contract_id, || {
+        let stored = ZkMafiaContract::load_game(&env, session_id).unwrap();
+        assert_eq!(stored.bullet_salt, Some(salt.clone()));
+    });
+}
+
+// ============================================================================
+// Test: The salt stored at load is hidden until revelar_bala opens it
+// ============================================================================
+#[test]
+fn test_salt_hidden_until_revealed() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 22;
+    let proof = Bytes::from_array(&env, &[0xE1; 32]);
+    let salt = BytesN::from_array(&env, &[42u8; 32]);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 2);
+    loop {
+        let game = client.get_game(&session_id);
+        assert_eq!(game.bullet_salt, None);
+        assert!(!game.salt_revealed);
+        assert_eq!(client.try_get_revealed_salt(&session_id), Err(Ok(Error::NotRevealed)));
+        if game.phase == PHASE_FINISHED {
+            break;
+        }
+        let shooter = game.players.get(game.current_turn).unwrap().address;
+        client.disparar(&session_id, &shooter, &proof);
+    }
+
+    // The reveal makes the stored salt public
+    client.revelar_bala(&session_id, &p1, &salt);
+    let game = client.get_game(&session_id);
+    assert!(game.salt_revealed);
+    assert_eq!(game.bullet_salt, Some(salt.clone()));
+    assert_eq!(client.get_revealed_salt(&session_id), salt);
+}
+
+// ============================================================================
+// Test: The winner can still be read once the live game has expired
+// ============================================================================
+#[test]
+fn test_get_winner_from_persistent_record() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 72;
+    let proof = Bytes::from_array(&env, &[0xBB; 32]);
+
+    assert_eq!(client.try_get_winner(&session_id), Err(Ok(Error::GameNotFound)));
+
+    // Still in temporary storage
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    play_to_end(&client, session_id, &proof);
+    assert_eq!(client.get_winner(&session_id), Some(p1.clone()));
+
+    // Live entries gone: the persistent record answers
+    env.as_contract(&contract_id, || {
+        env.storage().temporary().remove(&DataKey::GameStatic(session_id));
+        env.storage().temporary().remove(&DataKey::GameDynamic(session_id));
+    });
+    assert_eq!(client.try_get_game(&session_id), Err(Ok(Error::GameNotFound)));
+    assert_eq!(client.get_winner(&session_id), Some(p1));
+}
+
 // ============================================================================
 // Test: Player survives a shot (static cylinder miss)
 // ============================================================================
@@ -150,9 +430,9 @@ fn test_player_survives_shot() {
     load_revolver(&env, &client, session_id, &p1, 3);
 
     // Player 1 (turn 0) fires chamber 0 → miss (contract determines)
-    let zk_proof = BytesN::from_array(&env, &[0xAA; 32]);
+    let zk_proof = Bytes::from_array(&env, &[0xAA; 32]);
     let result = client.disparar(&session_id, &p1, &zk_proof);
-    assert_eq!(result, false); // survived
+    assert_eq!(result, SHOT_SURVIVED);
 
     let game = client.get_game(&session_id);
     assert_eq!(game.current_turn, 1); // advanced to p2
@@ -173,14 +453,14 @@ fn test_full_game_player_eliminated() {
     // Bullet in chamber 2 → chamber 0=safe, 1=safe, 2=BOOM
     load_revolver(&env, &client, session_id, &p1, 2);
 
-    let proof = BytesN::from_array(&env, &[0xBB; 32]);
+    let proof = Bytes::from_array(&env, &[0xBB; 32]);
 
     // Turn 0: P1 fires chamber 0 → miss
-    assert_eq!(client.disparar(&session_id, &p1, &proof), false);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
     // Turn 1: P2 fires chamber 1 → miss
-    assert_eq!(client.disparar(&session_id, &p2, &proof), false);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_SURVIVED);
     // Turn 2: P3 fires chamber 2 → HIT! (contract determines: chamber 2 == bullet_position 2)
-    assert_eq!(client.disparar(&session_id, &p3, &proof), true);
+    assert_eq!(client.disparar(&session_id, &p3, &proof), SHOT_HIT);
 
     let game = client.get_game(&session_id);
     assert_eq!(game.eliminated.len(), 1);
@@ -206,12 +486,12 @@ fn test_full_game_winner() {
     // Bullet in chamber 1 → chamber 0=safe, 1=BOOM
     load_revolver(&env, &client, session_id, &p1, 1);
 
-    let proof = BytesN::from_array(&env, &[0xCC; 32]);
+    let proof = Bytes::from_array(&env, &[0xCC; 32]);
 
     // Turn 0: P1 fires chamber 0 → miss
-    assert_eq!(client.disparar(&session_id, &p1, &proof), false);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
     // Turn 1: P2 fires chamber 1 → HIT! P2 eliminated → auto-reload
-    assert_eq!(client.disparar(&session_id, &p2, &proof), true);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
 
     let game = client.get_game(&session_id);
     assert_eq!(game.eliminated.len(), 1);
@@ -252,10 +532,10 @@ fn test_two_player_game_completes() {
     // Bullet in chamber 0 → immediate hit on first shot
     load_revolver(&env, &client, session_id, &p1, 0);
 
-    let proof = BytesN::from_array(&env, &[0xDD; 32]);
+    let proof = Bytes::from_array(&env, &[0xDD; 32]);
 
     // Turn 0: P1 fires chamber 0 → HIT! Only 1 alive → game over
-    assert_eq!(client.disparar(&session_id, &p1, &proof), true);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_HIT);
 
     let game = client.get_game(&session_id);
     assert_eq!(game.phase, PHASE_FINISHED);
@@ -275,7 +555,7 @@ fn test_zero_proof_rejected() {
     join_all_players(&env, &client, session_id, &p1, &p2, &p3);
     load_revolver(&env, &client, session_id, &p1, 3);
 
-    let zero_proof = BytesN::from_array(&env, &[0u8; 32]);
+    let zero_proof = Bytes::from_array(&env, &[0u8; 32]);
     let result = client.try_disparar(&session_id, &p1, &zero_proof);
     assert!(result.is_err());
 }
@@ -293,7 +573,7 @@ fn test_wrong_turn_rejected() {
     load_revolver(&env, &client, session_id, &p1, 4);
 
     // P2 tries to fire when it's P1's turn
-    let proof = BytesN::from_array(&env, &[0xDD; 32]);
+    let proof = Bytes::from_array(&env, &[0xDD; 32]);
     let result = client.try_disparar(&session_id, &p2, &proof);
     assert!(result.is_err());
 }
@@ -313,6 +593,55 @@ fn test_who_is_alive() {
     assert_eq!(alive.len(), 3);
 }
 
+// ============================================================================
+// Test: compute_reload_position re-derives an actual reload
+// ============================================================================
+#[test]
+fn test_compute_reload_position() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xB8; 32]);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    client.disparar(&session_id, &p1, &proof);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+
+    let game = client.get_game(&session_id);
+    let derived = client.compute_reload_position(&session_id, &game.shots_fired, &NUM_CHAMBERS);
+    assert_eq!(derived, game.bullet_position);
+    assert_eq!(game.bullet_history.get(1), Some(derived));
+    assert_eq!(
+        client.try_compute_reload_position(&session_id, &2, &0),
+        Err(Ok(Error::InvalidChamber))
+    );
+}
+
+// ============================================================================
+// Test: Single-player liveness check
+// ============================================================================
+#[test]
+fn test_is_alive() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xB1; 32]);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    client.disparar(&session_id, &p1, &proof);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+
+    assert!(client.is_alive(&session_id, &p1));
+    assert!(!client.is_alive(&session_id, &p2));
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_is_alive(&session_id, &stranger),
+        Err(Ok(Error::NotPlayer))
+    );
+}
+
 // ============================================================================
 // Test: compute_bullet_hash is deterministic
 // ============================================================================
@@ -330,3 +659,4225 @@ fn test_compute_bullet_hash() {
     // Deterministic
     assert_eq!(hash_pos0, client.compute_bullet_hash(&salt, &0));
 }
+
+// ============================================================================
+// Test: Seat index stays consistent with the players Vec
+// ============================================================================
+#[test]
+fn test_seat_index_consistent() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 90;
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.seats.len(), game.players.len());
+    for i in 0..game.players.len() {
+        let p = game.players.get(i).unwrap();
+        assert_eq!(game.seats.get(p.address.clone()).unwrap(), i);
+        assert_eq!(client.get_seat(&session_id, &p.address), i);
+    }
+
+    // Duplicate join is caught by the seat index
+    join_two_players(&env, &client, session_id + 1, &p1, &p2);
+    let result = client.try_entrar_a_la_ruleta(&(session_id + 1), &p2, &100, &1);
+    assert_eq!(result, Err(Ok(Error::AlreadyJoined)));
+    assert_eq!(client.get_game(&(session_id + 1)).seats.len(), 2);
+
+    // Non-participants have no seat
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_get_seat(&session_id, &outsider),
+        Err(Ok(Error::NotPlayer))
+    );
+}
+
+// ============================================================================
+// Test: Sudden death shrinks the cylinder and ends games sooner
+// ============================================================================
+#[test]
+fn test_sudden_death_converges_faster() {
+    let (env, normal_id, p1, p2, p3, hub) = setup_env();
+    let admin = Address::generate(&env);
+    let sudden_id = env.register(ZkMafiaContract, (&admin, &hub));
+    let normal = ZkMafiaContractClient::new(&env, &normal_id);
+    let sudden = ZkMafiaContractClient::new(&env, &sudden_id);
+    let proof = Bytes::from_array(&env, &[0xEE; 32]);
+
+    // Same session ids on both contracts → same reload seeds. Any single
+    // round is seed-dependent, so compare the total over many sessions.
+    let mut normal_turns = 0;
+    let mut sudden_turns = 0;
+    for session_id in 91..111u32 {
+        normal.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+        sudden.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+        let mut config = sudden.get_game(&session_id).config;
+        config.sudden_death_after = 1;
+        sudden.configurar_partida(&session_id, &p1, &config);
+        for client in [&normal, &sudden] {
+            client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+            client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+            load_revolver(&env, client, session_id, &p1, 5);
+        }
+
+        normal_turns += play_to_end(&normal, session_id, &proof);
+        sudden_turns += play_to_end(&sudden, session_id, &proof);
+
+        let game = sudden.get_game(&session_id);
+        assert_eq!(game.num_chambers, NUM_CHAMBERS - 1);
+        assert!(game.bullet_position < game.num_chambers);
+        assert_eq!(normal.get_game(&session_id).num_chambers, NUM_CHAMBERS);
+    }
+
+    assert!(sudden_turns < normal_turns);
+}
+
+// ============================================================================
+// Test: Only the host, alone in the lobby, can configure the session
+// ============================================================================
+#[test]
+fn test_configurar_partida_locks_after_join() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 92;
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    config.sudden_death_after = 3;
+    client.configurar_partida(&session_id, &p1, &config);
+    assert_eq!(client.get_game(&session_id).config, config);
+
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    let result = client.try_configurar_partida(&session_id, &p1, &config);
+    assert_eq!(result, Err(Ok(Error::WrongPhase)));
+}
+
+// ============================================================================
+// Test: session_exists
+// ============================================================================
+#[test]
+fn test_session_exists() {
+    let (env, contract_id, p1, _p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 93;
+
+    assert!(!client.session_exists(&session_id));
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    assert!(client.session_exists(&session_id));
+    assert!(!client.session_exists(&(session_id + 1)));
+}
+
+// ============================================================================
+// Test: Join points must be non-negative and bounded
+// ============================================================================
+#[test]
+fn test_join_points_validated() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 75;
+
+    let result = client.try_entrar_a_la_ruleta(&session_id, &p1, &-1, &0);
+    assert_eq!(result, Err(Ok(Error::InvalidPoints)));
+    let result = client.try_entrar_a_la_ruleta(&session_id, &p1, &(MAX_JOIN_POINTS + 1), &0);
+    assert_eq!(result, Err(Ok(Error::InvalidPoints)));
+    // Nothing was created by the failed joins
+    assert_eq!(client.try_get_game(&session_id), Err(Ok(Error::GameNotFound)));
+
+    // Zero and the cap itself are fine
+    client.entrar_a_la_ruleta(&session_id, &p1, &0, &0);
+    client.entrar_a_la_ruleta(&session_id, &p2, &MAX_JOIN_POINTS, &1);
+    let result = client.try_entrar_a_la_ruleta(&session_id, &p3, &i128::MIN, &2);
+    assert_eq!(result, Err(Ok(Error::InvalidPoints)));
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.players.len(), 2);
+    assert_eq!(game.players.get(0).unwrap().points, 0);
+}
+
+// ============================================================================
+// Test: Avatars are unique per session
+// ============================================================================
+#[test]
+fn test_avatar_uniqueness() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 94;
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &7);
+
+    // Same slot is rejected, out-of-range slot is rejected
+    let result = client.try_entrar_a_la_ruleta(&session_id, &p2, &100, &7);
+    assert_eq!(result, Err(Ok(Error::AvatarTaken)));
+    let result = client.try_entrar_a_la_ruleta(&session_id, &p2, &100, &16);
+    assert_eq!(result, Err(Ok(Error::InvalidAvatar)));
+
+    // A distinct slot succeeds
+    assert_eq!(client.entrar_a_la_ruleta(&session_id, &p2, &100, &3), 2);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &15);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.players.get(0).unwrap().avatar, 7);
+    assert_eq!(game.players.get(1).unwrap().avatar, 3);
+    assert_eq!(game.players.get(2).unwrap().avatar, 15);
+}
+
+// ============================================================================
+// Test: Hub pairing is the first two joiners
+// ============================================================================
+#[test]
+fn test_get_hub_pairing() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 95;
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    assert_eq!(
+        client.try_get_hub_pairing(&session_id),
+        Err(Ok(Error::WrongPhase))
+    );
+
+    load_revolver(&env, &client, session_id, &p1, 0);
+    assert_eq!(client.get_hub_pairing(&session_id), (p1.clone(), p2.clone()));
+
+    // Still available after the game finishes
+    let proof = Bytes::from_array(&env, &[0xAB; 32]);
+    play_to_end(&client, session_id, &proof);
+    assert_eq!(client.get_hub_pairing(&session_id), (p1, p2));
+}
+
+// ============================================================================
+// Test: Third player wins → hub pair is ranked by who outlasted whom
+// ============================================================================
+#[test]
+fn test_hub_result_when_third_player_wins() {
+    let (env, contract_id, p1, p2, p3, hub_id) = setup_recording_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let hub = recording_hub::RecordingHubClient::new(&env, &hub_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xAC; 32]);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    // Bullet in chamber 1 → P2 dies first
+    load_revolver(&env, &client, session_id, &p1, 1);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+
+    // Reload for session 96 after 2 shots puts the bullet in chamber 3:
+    // P3, P1, P3 click, then P1 is hit
+    assert_eq!(client.disparar(&session_id, &p3, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p3, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_HIT);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(p3));
+    // P1 outlasted P2, so the hub pair resolves in P1's favour
+    assert_eq!(hub.ended(&session_id), Some(true));
+}
+
+// ============================================================================
+// Test: AFK-kicked player forfeits their join bond to the winner
+// ============================================================================
+#[test]
+fn test_afk_player_forfeits_bond() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 97;
+    let token_id = setup_token(&env, &[&p1, &p2, &p3], 1_000);
+    let token = TokenClient::new(&env, &token_id);
+
+    let mut config = client.get_config();
+    config.bond_token = Some(token_id.clone());
+    config.join_bond = 50;
+    config.turn_timeout_ledgers = 10;
+    client.set_config(&config);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    assert_eq!(token.balance(&contract_id), 150);
+    assert_eq!(client.get_game(&session_id).players.get(1).unwrap().bond, 50);
+
+    load_revolver(&env, &client, session_id, &p1, 3);
+    let proof = Bytes::from_array(&env, &[0xAD; 32]);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+
+    // Too early to kick P2
+    assert_eq!(
+        client.try_expulsar_por_afk(&session_id),
+        Err(Ok(Error::TurnNotExpired))
+    );
+
+    // P2 goes AFK past the deadline → kicked, bond into the pot
+    env.ledger().with_mut(|l| l.sequence_number += 11);
+    assert_eq!(client.expulsar_por_afk(&session_id), p2);
+
+    let game = client.get_game(&session_id);
+    assert!(!game.players.get(1).unwrap().is_alive);
+    assert_eq!(game.pot.get(token_id.clone()), Some(50));
+    assert_eq!(game.current_turn, 2);
+    assert_eq!(game.current_chamber, 1); // P3 faces the chamber P2 skipped
+
+    play_to_end(&client, session_id, &proof);
+    let game = client.get_game(&session_id);
+    let winner = game.winner.unwrap();
+    let loser = if winner == p1 { p3.clone() } else { p1.clone() };
+
+    // Winner: own bond back + P2's bond. Loser: own bond back. P2: forfeited.
+    assert_eq!(token.balance(&winner), 1_050);
+    assert_eq!(token.balance(&loser), 1_000);
+    assert_eq!(token.balance(&p2), 950);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+// ============================================================================
+// Test: Repeated bad proofs get the shooter kicked
+// ============================================================================
+#[test]
+fn test_proof_attempts_exhausted() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 77;
+    let good = Bytes::from_array(&env, &[0xAF; 32]);
+    let bad = Bytes::from_array(&env, &[0u8; 32]);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    config.max_proof_attempts = 2;
+    client.configurar_partida(&session_id, &p1, &config);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+    load_revolver(&env, &client, session_id, &p1, 3);
+
+    // One miss is recorded, not fatal; a good proof then resets the count
+    assert_eq!(client.disparar(&session_id, &p1, &bad), SHOT_PROOF_REJECTED);
+    let failed = ProofFail {
+        session_id,
+        player: p1.clone(),
+        attempts: 1,
+    };
+    let events = env.events().all().filter_by_contract(&contract_id);
+    assert_eq!(events.events().last(), Some(&failed.to_xdr(&env, &contract_id)));
+    let game = client.get_game(&session_id);
+    assert_eq!((game.current_turn, game.current_chamber, game.shots_fired), (0, 0, 0));
+    assert_eq!(client.disparar(&session_id, &p1, &good), SHOT_SURVIVED);
+    assert_eq!(client.get_game(&session_id).invalid_proof_attempts, 0);
+
+    // P2 burns both attempts and is out; P3 faces the same chamber
+    assert_eq!(client.disparar(&session_id, &p2, &bad), SHOT_PROOF_REJECTED);
+    assert_eq!(client.disparar(&session_id, &p2, &bad), SHOT_KICKED);
+    let game = client.get_game(&session_id);
+    assert!(!game.players.get(1).unwrap().is_alive);
+    assert_eq!(game.eliminated, soroban_sdk::vec![&env, p2.clone()]);
+    assert_eq!((game.current_turn, game.current_chamber), (2, 1));
+    assert_eq!(game.invalid_proof_attempts, 0);
+
+    // Without a limit a bad proof is still just rejected
+    join_two_players(&env, &client, 78, &p1, &p2);
+    load_revolver(&env, &client, 78, &p1, 3);
+    assert_eq!(client.try_disparar(&78, &p1, &bad), Err(Ok(Error::InvalidProof)));
+}
+
+// ============================================================================
+// Test: Timed turns announce their deadline
+// ============================================================================
+#[test]
+fn test_turn_start_event_carries_deadline() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 71;
+    let proof = Bytes::from_array(&env, &[0xAE; 32]);
+
+    let mut config = client.get_config();
+    config.turn_timeout_ledgers = 10;
+    client.set_config(&config);
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    load_revolver(&env, &client, session_id, &p1, 3);
+
+    env.ledger().with_mut(|l| l.sequence_number += 4);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    let started = TurnStart {
+        session_id,
+        player: p2.clone(),
+        turn_deadline_ledger: env.ledger().sequence() + 10,
+    };
+    let events = env.events().all().filter_by_contract(&contract_id);
+    assert_eq!(events.events().last(), Some(&started.to_xdr(&env, &contract_id)));
+    assert_eq!(
+        client.get_game(&session_id).turn_deadline_ledger,
+        started.turn_deadline_ledger
+    );
+
+    // Untimed games stay quiet
+    config.turn_timeout_ledgers = 0;
+    client.set_config(&config);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_SURVIVED);
+    assert!(!event_topics(&env, &contract_id).contains(&"turn_start".into()));
+}
+
+// ============================================================================
+// Test: Leaving the lobby refunds the bond and reseats players
+// ============================================================================
+#[test]
+fn test_leave_lobby_refunds_bond() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 98;
+    let token_id = setup_token(&env, &[&p1, &p2, &p3], 1_000);
+    let token = TokenClient::new(&env, &token_id);
+
+    let mut config = client.get_config();
+    config.bond_token = Some(token_id.clone());
+    config.join_bond = 50;
+    client.set_config(&config);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+
+    // Host leaves → P2 becomes host, seats reindexed
+    assert_eq!(client.salir_de_la_ruleta(&session_id, &p1), 2);
+    assert_eq!(token.balance(&p1), 1_000);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.seats.len(), 2);
+    assert_eq!(client.get_seat(&session_id, &p2), 0);
+    assert_eq!(client.get_seat(&session_id, &p3), 1);
+    assert_eq!(
+        client.try_get_seat(&session_id, &p1),
+        Err(Ok(Error::NotPlayer))
+    );
+    assert_eq!(game.hub_player1, p2);
+    assert_eq!(game.hub_player2, p3);
+
+    // Last players leave → lobby is deleted
+    client.salir_de_la_ruleta(&session_id, &p2);
+    assert_eq!(client.salir_de_la_ruleta(&session_id, &p3), 0);
+    assert!(!client.session_exists(&session_id));
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+// ============================================================================
+// Test: Bullet sequence matches the SHA256 reload derivations
+// ============================================================================
+#[test]
+fn test_bullet_sequence_matches_derivation() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xAE; 32]);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 1);
+
+    // Shot 2 eliminates P2 and triggers the only reload
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+    assert_eq!(
+        client.try_get_bullet_sequence(&session_id),
+        Err(Ok(Error::WrongPhase))
+    );
+    play_to_end(&client, session_id, &proof);
+
+    let mut seed = Bytes::new(&env);
+    seed.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
+    seed.append(&Bytes::from_array(&env, &2u32.to_be_bytes()));
+    let reload = (env.crypto().sha256(&seed).to_array()[0] as u32) % NUM_CHAMBERS;
+
+    let sequence = client.get_bullet_sequence(&session_id);
+    assert_eq!(sequence.len(), 2);
+    assert_eq!(sequence.get(0).unwrap(), 1);
+    assert_eq!(sequence.get(1).unwrap(), reload);
+}
+
+// ============================================================================
+// Test: 2-player hub result in both orientations
+// ============================================================================
+#[test]
+fn test_two_player_hub_result_both_orientations() {
+    let (env, contract_id, p1, p2, _p3, hub_id) = setup_recording_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let hub = recording_hub::RecordingHubClient::new(&env, &hub_id);
+    let proof = Bytes::from_array(&env, &[0xAF; 32]);
+
+    // Host hit on the first shot → player 2 wins without shooting
+    join_two_players(&env, &client, 1, &p1, &p2);
+    load_revolver(&env, &client, 1, &p1, 0);
+    assert_eq!(client.disparar(&1, &p1, &proof), SHOT_HIT);
+    assert_eq!(client.get_game(&1).winner, Some(p2.clone()));
+    assert_eq!(hub.ended(&1), Some(false));
+
+    // Host survives, player 2 is hit on their first shot
+    join_two_players(&env, &client, 2, &p1, &p2);
+    load_revolver(&env, &client, 2, &p1, 1);
+    assert_eq!(client.disparar(&2, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&2, &p2, &proof), SHOT_HIT);
+    assert_eq!(client.get_game(&2).winner, Some(p1));
+    assert_eq!(hub.ended(&2), Some(true));
+}
+
+// ============================================================================
+// Test: Winner survives an honor shot and earns the bravery bonus
+// ============================================================================
+#[test]
+fn test_tiro_de_honor_bonus() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xB0; 32]);
+
+    let mut config = client.get_config();
+    config.bravery_bonus = 25;
+    config.honor_window_ledgers = 5;
+    client.set_config(&config);
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    load_revolver(&env, &client, session_id, &p1, 1);
+
+    // Honor shots only exist after the game ends
+    assert_eq!(
+        client.try_tiro_de_honor(&session_id, &p1, &proof),
+        Err(Ok(Error::WrongPhase))
+    );
+
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+    assert_eq!(client.get_game(&session_id).winner, Some(p1.clone()));
+
+    // Loser can't take it
+    assert_eq!(
+        client.try_tiro_de_honor(&session_id, &p2, &proof),
+        Err(Ok(Error::NotWinner))
+    );
+
+    // The spin after two shots loads chamber 3, so the honor shot clicks
+    assert_eq!(client.compute_reload_position(&session_id, &2, &6), 3);
+    assert!(!client.tiro_de_honor(&session_id, &p1, &proof));
+    let game = client.get_game(&session_id);
+    assert!(game.honor_shot_taken);
+    assert_eq!(game.players.get(0).unwrap().points, 125);
+
+    // Only once
+    assert_eq!(
+        client.try_tiro_de_honor(&session_id, &p1, &proof),
+        Err(Ok(Error::HonorUnavailable))
+    );
+}
+
+// ============================================================================
+// Test: A winner hit by the honor shot forfeits part of the escrowed pot
+// ============================================================================
+#[test]
+fn test_tiro_de_honor_forfeits_pot() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 118;
+    let token_id = setup_token(&env, &[&p1, &p2], 1_000);
+    let token = TokenClient::new(&env, &token_id);
+    let proof = Bytes::from_array(&env, &[0xE6; 32]);
+
+    let mut config = client.get_config();
+    config.stake_tokens = soroban_sdk::vec![&env, token_id.clone()];
+    config.bravery_bonus = 25;
+    config.honor_forfeit_bps = 2_500;
+    config.honor_window_ledgers = 5;
+    client.set_config(&config);
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    client.apostar(&session_id, &p1, &token_id, &100);
+    client.apostar(&session_id, &p2, &token_id, &100);
+    load_revolver(&env, &client, session_id, &p1, 0);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_HIT);
+
+    // Even with automatic payout, the pot waits for the honor shot
+    assert_eq!(client.get_game(&session_id).pot.get(token_id.clone()), Some(200));
+    assert_eq!(token.balance(&p2), 900);
+
+    // The spin after one shot loads chamber 0: a hit
+    assert_eq!(client.compute_reload_position(&session_id, &1, &6), 0);
+    assert!(client.tiro_de_honor(&session_id, &p2, &proof));
+    assert_eq!(client.get_game(&session_id).players.get(1).unwrap().points, 75);
+    assert_eq!(client.get_fee_balance(&token_id), 50);
+    assert_eq!(token.balance(&p2), 1_050);
+    assert_eq!(
+        client.try_reclamar_premio(&session_id, &p2),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+// ============================================================================
+// Test: Claiming the escrowed pot passes on the honor shot
+// ============================================================================
+#[test]
+fn test_reclamar_premio_waives_honor_shot() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 119;
+    let token_id = setup_token(&env, &[&p1, &p2], 1_000);
+    let proof = Bytes::from_array(&env, &[0xE7; 32]);
+
+    let mut config = client.get_config();
+    config.stake_tokens = soroban_sdk::vec![&env, token_id.clone()];
+    config.honor_forfeit_bps = 2_500;
+    config.honor_window_ledgers = 5;
+    client.set_config(&config);
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    client.apostar(&session_id, &p1, &token_id, &100);
+    load_revolver(&env, &client, session_id, &p1, 0);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_HIT);
+
+    assert_eq!(
+        client.reclamar_premio(&session_id, &p2),
+        soroban_sdk::vec![&env, (token_id.clone(), 100)]
+    );
+    assert_eq!(
+        client.try_tiro_de_honor(&session_id, &p2, &proof),
+        Err(Ok(Error::HonorUnavailable))
+    );
+}
+
+// ============================================================================
+// Test: Honor shot window closes
+// ============================================================================
+#[test]
+fn test_tiro_de_honor_window_expires() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xB1; 32]);
+
+    let mut config = client.get_config();
+    config.bravery_bonus = 25;
+    config.honor_window_ledgers = 5;
+    client.set_config(&config);
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    load_revolver(&env, &client, session_id, &p1, 0);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_HIT);
+
+    env.ledger().with_mut(|l| l.sequence_number += 6);
+    assert_eq!(
+        client.try_tiro_de_honor(&session_id, &p2, &proof),
+        Err(Ok(Error::HonorUnavailable))
+    );
+}
+
+// ============================================================================
+// Test: Proof system selector
+// ============================================================================
+#[test]
+fn test_proof_system_selector() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let proof = Bytes::from_array(&env, &[0xB2; 32]);
+
+    // Fallback (default): any non-zero proof is accepted
+    join_two_players(&env, &client, 1, &p1, &p2);
+    assert_eq!(client.get_game(&1).config.proof_system, PROOF_SYSTEM_FALLBACK);
+    load_revolver(&env, &client, 1, &p1, 3);
+    assert_eq!(client.disparar(&1, &p1, &proof), SHOT_SURVIVED);
+
+    // Groth16: a bare 32-byte proof is not a valid Groth16 proof
+    client.entrar_a_la_ruleta(&2, &p1, &100, &0);
+    let mut config = client.get_game(&2).config;
+    config.proof_system = PROOF_SYSTEM_GROTH16;
+    client.configurar_partida(&2, &p1, &config);
+    client.entrar_a_la_ruleta(&2, &p2, &100, &1);
+    load_revolver(&env, &client, 2, &p1, 3);
+    assert_eq!(
+        client.try_disparar(&2, &p1, &proof),
+        Err(Ok(Error::InvalidProof))
+    );
+
+    // Unknown systems are refused up front
+    client.entrar_a_la_ruleta(&3, &p1, &100, &0);
+    config.proof_system = 2;
+    assert_eq!(
+        client.try_configurar_partida(&3, &p1, &config),
+        Err(Ok(Error::UnsupportedProofSystem))
+    );
+}
+
+// ============================================================================
+// Test: Groth16 pairing check
+// ============================================================================
+
+/// BN254 generators: G1 = (1, 2); G2 in EIP-197 order (x.c1, x.c0, y.c1, y.c0)
+fn bn254_generators(env: &Env) -> (Bn254G1Affine, Bn254G2Affine) {
+    let mut g1 = [0u8; 64];
+    g1[31] = 1;
+    g1[63] = 2;
+    let g2_hex = concat!(
+        "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+        "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+        "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+        "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+    );
+    let mut g2 = [0u8; 128];
+    for (i, byte) in g2.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&g2_hex[2 * i..2 * i + 2], 16).unwrap();
+    }
+    (
+        Bn254G1Affine::from_array(env, &g1),
+        Bn254G2Affine::from_array(env, &g2),
+    )
+}
+
+/// A verifying key whose G2 points are all the generator, so that
+/// `A = alpha + vk_x + C` satisfies the Groth16 equation by construction
+fn groth16_fixture_key(env: &Env) -> VerifyingKey {
+    let (g1, g2) = bn254_generators(env);
+    let mul = |k: u32| (g1.clone() * Fr::from_u256(U256::from_u32(env, k))).to_bytes();
+    VerifyingKey {
+        alpha: mul(3),
+        beta: g2.to_bytes(),
+        gamma: g2.to_bytes(),
+        delta: g2.to_bytes(),
+        ic: Vec::from_array(env, [mul(5), mul(7), mul(11)]),
+    }
+}
+
+/// Wire-encoded proof for `(commitment, chamber)` under the fixture key
+fn groth16_fixture_proof(env: &Env, commitment: &BytesN<32>, chamber: u32) -> Bytes {
+    let vk = groth16_fixture_key(env);
+    let (g1, g2) = bn254_generators(env);
+    let mut masked = commitment.to_array();
+    masked[0] &= 0x1f;
+    let mut fired = [0u8; 32];
+    fired[28..].copy_from_slice(&chamber.to_be_bytes());
+    let inputs = [masked, fired];
+
+    let mut vk_x = Bn254G1Affine::from_bytes(vk.ic.get(0).unwrap());
+    for (i, input) in inputs.iter().enumerate() {
+        let point = Bn254G1Affine::from_bytes(vk.ic.get(i as u32 + 1).unwrap());
+        vk_x = vk_x + point * Fr::from_bytes(BytesN::from_array(env, input));
+    }
+    let c = g1 * Fr::from_u256(U256::from_u32(env, 13));
+    let a = Bn254G1Affine::from_bytes(vk.alpha.clone()) + vk_x + c.clone();
+
+    let mut wire = Bytes::new(env);
+    wire.append(&a.to_bytes().into());
+    wire.append(&g2.to_bytes().into());
+    wire.append(&c.to_bytes().into());
+    for input in inputs.iter() {
+        wire.extend_from_array(input);
+    }
+    wire
+}
+
+#[test]
+fn test_groth16_pairing_check() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 67;
+
+    // The key must carry one IC point per public input plus the constant
+    let mut short_key = groth16_fixture_key(&env);
+    short_key.ic.pop_back();
+    assert_eq!(
+        client.try_set_verifying_key(&short_key),
+        Err(Ok(Error::InvalidConfig))
+    );
+    client.set_verifying_key(&groth16_fixture_key(&env));
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    config.proof_system = PROOF_SYSTEM_GROTH16;
+    client.configurar_partida(&session_id, &p1, &config);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    let commitment = load_revolver(&env, &client, session_id, &p1, 3);
+
+    // Swapping in a different (valid) C point fails the pairing
+    let good = groth16_fixture_proof(&env, &commitment, 0);
+    let (g1, _g2) = bn254_generators(&env);
+    let forged_c = (g1 * Fr::from_u256(U256::from_u32(&env, 17))).to_bytes();
+    let mut tampered = good.slice(0..192);
+    tampered.append(&forged_c.into());
+    tampered.append(&good.slice(256..));
+    assert_eq!(
+        client.try_disparar(&session_id, &p1, &tampered),
+        Err(Ok(Error::InvalidProof))
+    );
+
+    // The genuine proof for chamber 0 is accepted
+    assert_eq!(client.disparar(&session_id, &p1, &good), SHOT_SURVIVED);
+
+    // Replaying it against chamber 1 is refused; a fresh one passes
+    assert_eq!(
+        client.try_disparar(&session_id, &p2, &good),
+        Err(Ok(Error::InvalidProof))
+    );
+    let next = groth16_fixture_proof(&env, &commitment, 1);
+    assert_eq!(client.disparar(&session_id, &p2, &next), SHOT_SURVIVED);
+}
+
+// ============================================================================
+// Test: Malformed curve points are refused instead of trapping the host
+// ============================================================================
+#[test]
+fn test_groth16_rejects_off_curve_points() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 115;
+
+    // (1, 3) is not on y² = x³ + 3
+    let mut off_curve = [0u8; 64];
+    off_curve[31] = 1;
+    off_curve[63] = 3;
+    let mut bad_key = groth16_fixture_key(&env);
+    bad_key.alpha = BytesN::from_array(&env, &off_curve);
+    assert_eq!(
+        client.try_set_verifying_key(&bad_key),
+        Err(Ok(Error::InvalidConfig))
+    );
+    client.set_verifying_key(&groth16_fixture_key(&env));
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    config.proof_system = PROOF_SYSTEM_GROTH16;
+    client.configurar_partida(&session_id, &p1, &config);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    let commitment = load_revolver(&env, &client, session_id, &p1, 3);
+    let good = groth16_fixture_proof(&env, &commitment, 0);
+
+    // Off-curve A
+    let mut tampered = Bytes::from_array(&env, &off_curve);
+    tampered.append(&good.slice(64..));
+    assert_eq!(
+        client.try_disparar(&session_id, &p1, &tampered),
+        Err(Ok(Error::InvalidProof))
+    );
+
+    // A coordinate of C at the field modulus
+    let modulus = [
+        0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81,
+        0x58, 0x5d, 0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16,
+        0xd8, 0x7c, 0xfd, 0x47,
+    ];
+    let mut tampered = good.slice(0..192);
+    tampered.extend_from_array(&modulus);
+    tampered.append(&good.slice(224..));
+    assert_eq!(
+        client.try_disparar(&session_id, &p1, &tampered),
+        Err(Ok(Error::InvalidProof))
+    );
+
+    // G2 B with a garbled coordinate
+    let mut tampered = good.slice(0..64);
+    tampered.extend_from_array(&[0x01; 128]);
+    tampered.append(&good.slice(192..));
+    assert_eq!(
+        client.try_disparar(&session_id, &p1, &tampered),
+        Err(Ok(Error::InvalidProof))
+    );
+
+    assert_eq!(client.disparar(&session_id, &p1, &good), SHOT_SURVIVED);
+}
+
+// ============================================================================
+// Test: Proof pre-flight
+// ============================================================================
+#[test]
+fn test_would_accept_proof() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 68;
+    let good = Bytes::from_array(&env, &[0xB4; 32]);
+    let zero = Bytes::from_array(&env, &[0u8; 32]);
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    assert_eq!(
+        client.try_would_accept_proof(&session_id, &good),
+        Err(Ok(Error::WrongPhase))
+    );
+
+    load_revolver(&env, &client, session_id, &p1, 3);
+    assert!(!client.would_accept_proof(&session_id, &zero));
+    assert!(client.would_accept_proof(&session_id, &good));
+
+    // Read-only: nothing moved
+    assert_eq!(client.get_game(&session_id).shots_fired, 0);
+}
+
+// ============================================================================
+// Test: Exported test vector matches an actual played round
+// ============================================================================
+#[test]
+fn test_export_test_vector_matches_play() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 99;
+    let proof = Bytes::from_array(&env, &[0xB3; 32]);
+
+    // Nonce binding shifts the loaded chamber away from the committed one
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut session = client.get_session_config(&session_id);
+    session.bind_session_nonce = true;
+    client.configurar_partida(&session_id, &p1, &session);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+    let commitment = load_revolver(&env, &client, session_id, &p1, 4);
+
+    let vector = env.as_contract(&contract_id, || {
+        ZkMafiaContract::export_test_vector(&env, session_id)
+    });
+    assert_eq!(vector.commitment, commitment);
+    assert_eq!(vector.salt, BytesN::from_array(&env, &[42u8; 32]));
+    assert_eq!(vector.bullet_position, 4);
+    assert_eq!(vector.loaded_position, 3);
+    assert_eq!(vector.hits.len(), NUM_CHAMBERS);
+    assert_eq!(vector.hits.get(3), Some(true));
+
+    // Play the committed round; every result matches the vector
+    loop {
+        let g = client.get_game(&session_id);
+        let expected = vector.hits.get(g.current_chamber).unwrap();
+        let shooter = g.players.get(g.current_turn).unwrap().address;
+        let result = client.disparar(&session_id, &shooter, &proof);
+        assert_eq!(result == SHOT_HIT, expected);
+        if expected {
+            break;
+        }
+    }
+}
+
+// ============================================================================
+// Test: A bond that would overflow the pot is refused, not wrapped
+// ============================================================================
+#[test]
+fn test_forfeit_bond_overflow() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 120;
+    let token_id = setup_token(&env, &[&p1, &p2, &p3], 1_000);
+
+    let mut config = client.get_config();
+    config.bond_token = Some(token_id.clone());
+    config.join_bond = 50;
+    config.turn_timeout_ledgers = 10;
+    client.set_config(&config);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 3);
+    env.as_contract(&contract_id, || {
+        let mut game = ZkMafiaContract::load_game(&env, session_id).unwrap();
+        game.pot.set(token_id.clone(), i128::MAX - 10);
+        ZkMafiaContract::save_game(&env, &mut game);
+    });
+
+    env.ledger().with_mut(|l| l.sequence_number += 11);
+    assert_eq!(client.try_expulsar_por_afk(&session_id), Err(Ok(Error::Overflow)));
+    let game = client.get_game(&session_id);
+    assert!(game.players.get(0).unwrap().is_alive);
+    assert_eq!(game.pot.get(token_id), Some(i128::MAX - 10));
+}
+
+// ============================================================================
+// Test: Claim payout mode — winner pulls the pot
+// ============================================================================
+#[test]
+fn test_claim_payout_mode() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 100;
+    let token_id = setup_token(&env, &[&p1, &p2, &p3], 1_000);
+    let token = TokenClient::new(&env, &token_id);
+    let proof = Bytes::from_array(&env, &[0xB4; 32]);
+
+    let mut config = client.get_config();
+    config.bond_token = Some(token_id.clone());
+    config.join_bond = 50;
+    config.turn_timeout_ledgers = 10;
+    client.set_config(&config);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut session = client.get_game(&session_id).config;
+    session.payout_mode = PAYOUT_CLAIM;
+    client.configurar_partida(&session_id, &p1, &session);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+    load_revolver(&env, &client, session_id, &p1, 3);
+
+    // P1 goes AFK → their bond becomes the pot
+    env.ledger().with_mut(|l| l.sequence_number += 11);
+    client.expulsar_por_afk(&session_id);
+    play_to_end(&client, session_id, &proof);
+
+    let game = client.get_game(&session_id);
+    let winner = game.winner.unwrap();
+    assert_eq!(game.pot.get(token_id.clone()), Some(50)); // Held, not pushed
+    assert_eq!(token.balance(&winner), 1_000); // Only the bond came back
+
+    let loser = if winner == p2 { p3.clone() } else { p2.clone() };
+    assert_eq!(
+        client.try_reclamar_premio(&session_id, &loser),
+        Err(Ok(Error::NotWinner))
+    );
+
+    assert_eq!(
+        client.reclamar_premio(&session_id, &winner),
+        soroban_sdk::vec![&env, (token_id.clone(), 50)]
+    );
+    assert_eq!(token.balance(&winner), 1_050);
+    assert_eq!(token.balance(&contract_id), 0);
+    assert_eq!(
+        client.try_reclamar_premio(&session_id, &winner),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+// ============================================================================
+// Test: safe_chambers_remaining counts down across misses
+// ============================================================================
+#[test]
+fn test_safe_chambers_remaining() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 101;
+    let proof = Bytes::from_array(&env, &[0xB5; 32]);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 3);
+
+    assert_eq!(client.safe_chambers_remaining(&session_id), 3);
+    client.disparar(&session_id, &p1, &proof);
+    assert_eq!(client.safe_chambers_remaining(&session_id), 2);
+    client.disparar(&session_id, &p2, &proof);
+    assert_eq!(client.safe_chambers_remaining(&session_id), 1);
+    client.disparar(&session_id, &p3, &proof);
+    assert_eq!(client.safe_chambers_remaining(&session_id), 0);
+
+    // Hidden mode refuses to reveal the distance
+    client.entrar_a_la_ruleta(&(session_id + 1), &p1, &100, &0);
+    let mut config = client.get_game(&(session_id + 1)).config;
+    config.mode = MODE_HIDDEN;
+    client.configurar_partida(&(session_id + 1), &p1, &config);
+    client.entrar_a_la_ruleta(&(session_id + 1), &p2, &100, &1);
+    load_revolver(&env, &client, session_id + 1, &p1, 3);
+    assert_eq!(
+        client.try_safe_chambers_remaining(&(session_id + 1)),
+        Err(Ok(Error::WrongMode))
+    );
+}
+
+// ============================================================================
+// Test: get_game keeps a hidden-mode bullet secret until the game ends
+// ============================================================================
+#[test]
+fn test_get_game_hides_bullet_in_hidden_mode() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 82;
+    let proof = Bytes::from_array(&env, &[0xB7; 32]);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    config.mode = MODE_HIDDEN;
+    client.configurar_partida(&session_id, &p1, &config);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    load_revolver(&env, &client, session_id, &p1, 3);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.bullet_position, BULLET_POSITION_HIDDEN);
+    assert!(game.bullet_history.is_empty());
+    assert_eq!(game.bullet_salt, None);
+
+    play_to_end(&client, session_id, &proof);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, PHASE_FINISHED);
+    assert_eq!(game.bullet_history.get(0), Some(3));
+}
+
+// ============================================================================
+// Test: chambers_remaining counts the cylinder down in any mode
+// ============================================================================
+#[test]
+fn test_chambers_remaining() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 81;
+    let proof = Bytes::from_array(&env, &[0xB6; 32]);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    config.mode = MODE_HIDDEN;
+    client.configurar_partida(&session_id, &p1, &config);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+    assert_eq!(
+        client.try_chambers_remaining(&session_id),
+        Err(Ok(Error::WrongPhase))
+    );
+
+    load_revolver(&env, &client, session_id, &p1, 4);
+    assert_eq!(client.chambers_remaining(&session_id), NUM_CHAMBERS);
+    for (shooter, left) in [(&p1, 5), (&p2, 4), (&p3, 3)] {
+        assert_eq!(client.disparar(&session_id, shooter, &proof), SHOT_SURVIVED);
+        assert_eq!(client.chambers_remaining(&session_id), left);
+    }
+}
+
+// ============================================================================
+// Test: Joins are rate-limited per address per ledger
+// ============================================================================
+#[test]
+fn test_join_rate_limit() {
+    let (env, contract_id, p1, _p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+
+    let mut config = client.get_config();
+    config.max_joins_per_ledger = 2;
+    client.set_config(&config);
+
+    client.entrar_a_la_ruleta(&1, &p1, &100, &0);
+    client.entrar_a_la_ruleta(&2, &p1, &100, &0);
+    assert_eq!(
+        client.try_entrar_a_la_ruleta(&3, &p1, &100, &0),
+        Err(Ok(Error::RateLimited))
+    );
+    assert!(!client.session_exists(&3));
+
+    // Quota resets on the next ledger
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+    client.entrar_a_la_ruleta(&3, &p1, &100, &0);
+    assert!(client.session_exists(&3));
+}
+
+// ============================================================================
+// Test: Concurrent session cap per address
+// ============================================================================
+#[test]
+fn test_max_active_games() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let proof = Bytes::from_array(&env, &[0xB7; 32]);
+
+    let mut config = client.get_config();
+    config.max_active_games = 2;
+    client.set_config(&config);
+
+    join_two_players(&env, &client, 84, &p1, &p2);
+    client.entrar_a_la_ruleta(&85, &p1, &100, &0);
+    assert_eq!(client.get_active_game_count(&p1), 2);
+    assert_eq!(
+        client.try_entrar_a_la_ruleta(&86, &p1, &100, &0),
+        Err(Ok(Error::TooManyActiveGames))
+    );
+
+    // Leaving a lobby frees a slot, and so does finishing a game
+    client.salir_de_la_ruleta(&85, &p1);
+    client.entrar_a_la_ruleta(&86, &p1, &100, &0);
+    load_revolver(&env, &client, 84, &p1, 1);
+    play_to_end(&client, 84, &proof);
+    assert_eq!(client.get_active_game_count(&p1), 1);
+    assert_eq!(client.get_active_game_count(&p2), 0);
+    client.entrar_a_la_ruleta(&87, &p1, &100, &0);
+    assert_eq!(client.get_active_game_count(&p1), 2);
+
+    // Other players are counted separately
+    client.entrar_a_la_ruleta(&87, &p3, &100, &1);
+    assert_eq!(client.get_active_game_count(&p3), 1);
+
+    // A lobby nobody finished stops counting once its storage expires
+    env.as_contract(&contract_id, || {
+        env.storage().temporary().remove(&DataKey::GameStatic(86));
+        env.storage().temporary().remove(&DataKey::GameDynamic(86));
+    });
+    assert_eq!(client.get_active_game_count(&p1), 1);
+    client.entrar_a_la_ruleta(&88, &p1, &100, &0);
+    assert_eq!(client.get_active_game_count(&p1), 2);
+}
+
+// ============================================================================
+// Test: Neutral theme renames events without changing mechanics
+// ============================================================================
+#[test]
+fn test_neutral_theme_events() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 102;
+    let proof = Bytes::from_array(&env, &[0xB6; 32]);
+
+    let mut config = client.get_config();
+    assert_eq!(config.theme, THEME_ROULETTE);
+    config.theme = THEME_NEUTRAL + 1;
+    assert_eq!(client.try_set_config(&config), Err(Ok(Error::InvalidConfig)));
+    config.theme = THEME_NEUTRAL;
+    client.set_config(&config);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    assert_eq!(event_topics(&env, &contract_id), ["phase_changed", "ready"]);
+
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(event_topics(&env, &contract_id), ["safe"]);
+
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+    assert_eq!(event_topics(&env, &contract_id), ["out", "cylinder_reloaded"]);
+
+    // Default theme keeps the roulette names
+    config.theme = THEME_ROULETTE;
+    client.set_config(&config);
+    assert_eq!(client.disparar(&session_id, &p3, &proof), SHOT_SURVIVED);
+    assert_eq!(event_topics(&env, &contract_id), ["click"]);
+}
+
+// ============================================================================
+// Test: last_action_ledger tracks the latest mutation
+// ============================================================================
+#[test]
+fn test_last_action_ledger() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 103;
+    let proof = Bytes::from_array(&env, &[0xB7; 32]);
+
+    env.ledger().with_mut(|l| l.sequence_number = 1_000);
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    load_revolver(&env, &client, session_id, &p1, 3);
+    assert_eq!(client.get_last_action_ledger(&session_id), 1_000);
+
+    env.ledger().with_mut(|l| l.sequence_number = 1_042);
+    client.disparar(&session_id, &p1, &proof);
+    assert_eq!(client.get_last_action_ledger(&session_id), 1_042);
+
+    // Reads don't count as activity
+    env.ledger().with_mut(|l| l.sequence_number = 1_100);
+    client.get_game(&session_id);
+    assert_eq!(client.get_last_action_ledger(&session_id), 1_042);
+}
+
+// ============================================================================
+// Test: Points reported to the hub are clamped
+// ============================================================================
+#[test]
+fn test_hub_points_clamped() {
+    let (env, contract_id, p1, p2, _p3, hub_id) = setup_recording_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let hub = recording_hub::RecordingHubClient::new(&env, &hub_id);
+    let session_id: u32 = 104;
+
+    let mut config = client.get_config();
+    config.max_hub_points = 200;
+    client.set_config(&config);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &10_000, &0);
+    client.entrar_a_la_ruleta(&session_id, &p2, &150, &1);
+    load_revolver(&env, &client, session_id, &p1, 3);
+
+    let (hub_p1, hub_p2, p1_points, p2_points) = hub.started(&session_id).unwrap();
+    assert_eq!((hub_p1, hub_p2), (p1, p2));
+    assert_eq!(p1_points, 200); // clamped
+    assert_eq!(p2_points, 150); // under the cap, untouched
+}
+
+// ============================================================================
+// Test: Winner margin counts the clicks the winner survived
+// ============================================================================
+#[test]
+fn test_winner_margin() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xB8; 32]);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    assert_eq!(
+        client.try_get_winner_margin(&session_id),
+        Err(Ok(Error::WrongPhase))
+    );
+
+    // Same script as the third-player-wins test: P3 clicks twice, wins
+    client.disparar(&session_id, &p1, &proof); // click
+    client.disparar(&session_id, &p2, &proof); // boom → reload (bullet 3)
+    client.disparar(&session_id, &p3, &proof); // click
+    client.disparar(&session_id, &p1, &proof); // click
+    client.disparar(&session_id, &p3, &proof); // click
+    client.disparar(&session_id, &p1, &proof); // boom
+
+    assert_eq!(client.get_game(&session_id).winner, Some(p3));
+    assert_eq!(client.get_winner_margin(&session_id), 2);
+}
+
+// ============================================================================
+// Test: reconciliar_turno repairs a turn pointing at a dead player
+// ============================================================================
+#[test]
+fn test_reconciliar_turno() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 105;
+    let proof = Bytes::from_array(&env, &[0xB9; 32]);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 2);
+    client.disparar(&session_id, &p1, &proof);
+    client.disparar(&session_id, &p2, &proof);
+    assert_eq!(client.disparar(&session_id, &p3, &proof), SHOT_HIT); // P3 dead, turn → P1
+
+    // Consistent turn is left alone
+    assert_eq!(client.reconciliar_turno(&session_id), 0);
+
+    // Simulate state written by older turn logic: turn on dead P3
+    env.as_contract(&contract_id, || {
+        let mut game = ZkMafiaContract::load_game(&env, session_id).unwrap();
+        game.current_turn = 2;
+        ZkMafiaContract::save_game(&env, &mut game);
+    });
+    assert_eq!(
+        client.try_disparar(&session_id, &p3, &proof),
+        Err(Ok(Error::PlayerEliminated))
+    );
+
+    assert_eq!(client.reconciliar_turno(&session_id), 0);
+    assert_eq!(client.get_game(&session_id).current_turn, 0);
+    client.disparar(&session_id, &p1, &proof); // turn → P2
+
+    // An out-of-range turn that wraps onto a living seat stays there:
+    // 4 % 3 is P2, who must not be skipped
+    env.as_contract(&contract_id, || {
+        let mut game = ZkMafiaContract::load_game(&env, session_id).unwrap();
+        game.current_turn = 4;
+        ZkMafiaContract::save_game(&env, &mut game);
+    });
+    assert_eq!(client.reconciliar_turno(&session_id), 1);
+    client.disparar(&session_id, &p2, &proof);
+}
+
+// ============================================================================
+// Test: Winner is withheld until the reveal ledger
+// ============================================================================
+#[test]
+fn test_winner_reveal_delay() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 106;
+    let proof = Bytes::from_array(&env, &[0xBA; 32]);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    // A delay that could overflow the reveal ledger is refused up front
+    config.reveal_delay_ledgers = u32::MAX;
+    assert_eq!(
+        client.try_configurar_partida(&session_id, &p1, &config),
+        Err(Ok(Error::InvalidConfig))
+    );
+    config.reveal_delay_ledgers = 3;
+    client.configurar_partida(&session_id, &p1, &config);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    load_revolver(&env, &client, session_id, &p1, 0);
+    assert_eq!(client.get_winner(&session_id), None);
+
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_HIT);
+
+    // Finished, but the winner is still under wraps
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, PHASE_FINISHED);
+    assert_eq!(game.winner, None);
+    assert_eq!(client.try_get_winner(&session_id), Err(Ok(Error::PendingReveal)));
+
+    env.ledger().with_mut(|l| l.sequence_number += 2);
+    assert_eq!(client.try_get_winner(&session_id), Err(Ok(Error::PendingReveal)));
+
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+    assert_eq!(client.get_winner(&session_id), Some(p2.clone()));
+    assert_eq!(client.get_game(&session_id).winner, Some(p2));
+}
+
+// ============================================================================
+// Test: check_invariants passes on real games and catches corruption
+// ============================================================================
+#[test]
+fn test_check_invariants() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 107;
+    let proof = Bytes::from_array(&env, &[0xBB; 32]);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    client.check_invariants(&session_id);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    client.check_invariants(&session_id);
+
+    // Check after every shot through to the finish
+    loop {
+        let g = client.get_game(&session_id);
+        if g.phase == PHASE_FINISHED {
+            break;
+        }
+        let current = g.players.get(g.current_turn).unwrap();
+        client.disparar(&session_id, &current.address, &proof);
+        client.check_invariants(&session_id);
+    }
+
+    // Hand-corrupt: revive an eliminated player without removing them
+    env.as_contract(&contract_id, || {
+        let mut game = ZkMafiaContract::load_game(&env, session_id).unwrap();
+        let mut dead = game.players.get(1).unwrap();
+        dead.is_alive = true;
+        game.players.set(1, dead);
+        ZkMafiaContract::save_game(&env, &mut game);
+    });
+    assert_eq!(
+        client.try_check_invariants(&session_id),
+        Err(Ok(Error::InconsistentState))
+    );
+}
+
+// ============================================================================
+// Test: Mixed-currency pot — winner receives every staked token
+// ============================================================================
+#[test]
+fn test_multi_token_pot() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let usdc_id = setup_token(&env, &[&p1, &p2], 1_000);
+    let xlm_id = setup_token(&env, &[&p1, &p2], 1_000);
+    let usdc = TokenClient::new(&env, &usdc_id);
+    let xlm = TokenClient::new(&env, &xlm_id);
+    let proof = Bytes::from_array(&env, &[0xC3; 32]);
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+
+    // Only allowlisted tokens can be staked
+    assert_eq!(
+        client.try_apostar(&session_id, &p1, &usdc_id, &100),
+        Err(Ok(Error::TokenNotAllowed))
+    );
+    let mut config = client.get_config();
+    config.stake_tokens = soroban_sdk::vec![&env, usdc_id.clone(), xlm_id.clone()];
+    client.set_config(&config);
+    assert_eq!(
+        client.try_apostar(&session_id, &p1, &usdc_id, &0),
+        Err(Ok(Error::InvalidStake))
+    );
+
+    assert_eq!(client.apostar(&session_id, &p1, &usdc_id, &100), 100);
+    assert_eq!(client.apostar(&session_id, &p2, &xlm_id, &200), 200);
+    assert_eq!(client.apostar(&session_id, &p2, &usdc_id, &50), 50);
+    let pot = client.get_multi_pot(&session_id);
+    assert_eq!(pot.len(), 2);
+    assert!(pot.contains(&(usdc_id.clone(), 150)));
+    assert!(pot.contains(&(xlm_id.clone(), 200)));
+
+    // Session 96 with the bullet in chamber 1: P1 wins
+    load_revolver(&env, &client, session_id, &p1, 1);
+    play_to_end(&client, session_id, &proof);
+    assert_eq!(client.get_winner(&session_id), Some(p1.clone()));
+
+    assert_eq!(usdc.balance(&p1), 1_050);
+    assert_eq!(xlm.balance(&p1), 1_200);
+    assert_eq!(usdc.balance(&p2), 950);
+    assert_eq!(xlm.balance(&p2), 800);
+    assert_eq!(usdc.balance(&contract_id), 0);
+    assert_eq!(xlm.balance(&contract_id), 0);
+    assert!(client.get_multi_pot(&session_id).is_empty());
+}
+
+// ============================================================================
+// Test: Reload event carries the round but hides the position in hidden mode
+// ============================================================================
+#[test]
+fn test_reload_event_hides_position() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let proof = Bytes::from_array(&env, &[0xC4; 32]);
+
+    // Static (practice) mode: session 96's reload lands on chamber 3
+    join_all_players(&env, &client, 96, &p1, &p2, &p3);
+    load_revolver(&env, &client, 96, &p1, 1);
+    client.disparar(&96, &p1, &proof);
+    assert_eq!(client.disparar(&96, &p2, &proof), SHOT_HIT);
+    let reloaded = CylinderReloaded {
+        session_id: 96,
+        round: 1,
+        bullet_position: Some(3),
+    };
+    let events = env.events().all().filter_by_contract(&contract_id);
+    assert_eq!(events.events().last(), Some(&reloaded.to_xdr(&env, &contract_id)));
+    assert_eq!(client.get_game(&96).round, 1);
+
+    // Hidden mode: same round context, no position
+    let session_id: u32 = 1096;
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    config.mode = MODE_HIDDEN;
+    client.configurar_partida(&session_id, &p1, &config);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+    load_revolver(&env, &client, session_id, &p1, 0);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_HIT);
+    let reloaded = CylinderReloaded {
+        session_id,
+        round: 1,
+        bullet_position: None,
+    };
+    let events = env.events().all().filter_by_contract(&contract_id);
+    assert_eq!(events.events().last(), Some(&reloaded.to_xdr(&env, &contract_id)));
+}
+
+// ============================================================================
+// Test: Observer snapshot never carries the bullet in hidden mode
+// ============================================================================
+#[test]
+fn test_subscribe_info_redacts_hidden_bullet() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 103;
+    let proof = Bytes::from_array(&env, &[0xC5; 32]);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    config.mode = MODE_HIDDEN;
+    client.configurar_partida(&session_id, &p1, &config);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+    load_revolver(&env, &client, session_id, &p1, 3);
+    client.disparar(&session_id, &p1, &proof);
+
+    let info = client.subscribe_info(&session_id);
+    assert_eq!(info.phase, PHASE_PLAYING);
+    assert_eq!(info.current_player, Some(p2.clone()));
+    assert_eq!(info.alive.len(), 3);
+    assert_eq!(info.shots_fired, 1);
+    assert_eq!(info.round, 0);
+    assert_eq!(info.bullet_commitment, client.get_game(&session_id).bullet_commitment);
+    assert_eq!(info.bullet_position, None);
+
+    // Static mode shows it for practice
+    join_all_players(&env, &client, session_id + 1, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id + 1, &p1, 3);
+    assert_eq!(client.subscribe_info(&(session_id + 1)).bullet_position, Some(3));
+}
+
+// ============================================================================
+// Test: Majority skip vote passes the current player's turn
+// ============================================================================
+#[test]
+fn test_skip_vote_advances_turn() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 104;
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 3);
+
+    // P1 holds the turn and cannot vote on it
+    assert_eq!(
+        client.try_votar_saltar(&session_id, &p1),
+        Err(Ok(Error::NotPlayer))
+    );
+
+    // No vote opens until P1 has idled through the soft window
+    let idle = client.get_game(&session_id).config.skip_vote_idle_ledgers;
+    env.ledger().with_mut(|l| l.sequence_number += idle - 1);
+    assert_eq!(
+        client.try_votar_saltar(&session_id, &p2),
+        Err(Ok(Error::TurnNotExpired))
+    );
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+
+    // One of two other players is not a majority yet
+    assert!(!client.votar_saltar(&session_id, &p2));
+    assert_eq!(
+        client.try_votar_saltar(&session_id, &p2),
+        Err(Ok(Error::AlreadyVoted))
+    );
+    assert_eq!(client.get_game(&session_id).current_turn, 0);
+
+    assert!(client.votar_saltar(&session_id, &p3));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.current_turn, 1);
+    assert_eq!(game.current_chamber, 0); // P2 faces the chamber P1 skipped
+    assert!(game.players.get(0).unwrap().is_alive); // Skipped, not eliminated
+    assert!(game.skip_votes.is_empty());
+}
+
+// ============================================================================
+// Test: Skip vote can be configured to eliminate instead
+// ============================================================================
+#[test]
+fn test_skip_vote_eliminates() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 105;
+
+    let mut admin_config = client.get_config();
+    admin_config.turn_timeout_ledgers = 10;
+    client.set_config(&admin_config);
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    config.skip_vote_eliminates = true;
+    // The soft window must be open and end before the hard timeout
+    for idle in [0, 10] {
+        config.skip_vote_idle_ledgers = idle;
+        assert_eq!(
+            client.try_configurar_partida(&session_id, &p1, &config),
+            Err(Ok(Error::InvalidConfig))
+        );
+    }
+    config.skip_vote_idle_ledgers = 3;
+    client.configurar_partida(&session_id, &p1, &config);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+    load_revolver(&env, &client, session_id, &p1, 3);
+
+    // P2 and P3 can't vote P1 out the moment P1's turn starts
+    assert_eq!(
+        client.try_votar_saltar(&session_id, &p2),
+        Err(Ok(Error::TurnNotExpired))
+    );
+    env.ledger().with_mut(|l| l.sequence_number += 3);
+    assert!(!client.votar_saltar(&session_id, &p2));
+    assert!(client.votar_saltar(&session_id, &p3));
+    let game = client.get_game(&session_id);
+    assert!(!game.players.get(0).unwrap().is_alive);
+    assert_eq!(game.eliminated, soroban_sdk::vec![&env, p1.clone()]);
+    assert_eq!(game.current_turn, 1);
+    client.check_invariants(&session_id);
+}
+
+// ============================================================================
+// Test: Fairness proof opens the commitment after the reveal
+// ============================================================================
+#[test]
+fn test_fairness_proof() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 106;
+    let proof = Bytes::from_array(&env, &[0xC6; 32]);
+    let salt = BytesN::from_array(&env, &[42u8; 32]);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    let commitment = load_revolver(&env, &client, session_id, &p1, 2);
+
+    // Nothing to share, and no revealing, before the game ends
+    assert_eq!(
+        client.try_revelar_bala(&session_id, &p1, &salt),
+        Err(Ok(Error::WrongPhase))
+    );
+    play_to_end(&client, session_id, &proof);
+    assert_eq!(
+        client.try_get_fairness_proof(&session_id),
+        Err(Ok(Error::WrongPhase))
+    );
+
+    assert_eq!(
+        client.try_revelar_bala(&session_id, &p2, &salt),
+        Err(Ok(Error::NotPlayer))
+    );
+    // A salt that doesn't open the commitment is refused, not recorded
+    let wrong_salt = BytesN::from_array(&env, &[43u8; 32]);
+    assert_eq!(
+        client.try_revelar_bala(&session_id, &p1, &wrong_salt),
+        Err(Ok(Error::CommitmentMismatch))
+    );
+    assert_eq!(client.try_get_revealed_salt(&session_id), Err(Ok(Error::NotRevealed)));
+    assert_eq!(
+        client.try_get_fairness_proof(&session_id),
+        Err(Ok(Error::WrongPhase))
+    );
+    client.revelar_bala(&session_id, &p1, &salt);
+    assert_eq!(
+        client.try_revelar_bala(&session_id, &p1, &salt),
+        Err(Ok(Error::AlreadyRevealed))
+    );
+
+    let fairness = client.get_fairness_proof(&session_id);
+    assert_eq!(fairness.commitment, commitment);
+    assert_eq!(fairness.salt, salt);
+    assert_eq!(fairness.bullet_position, 2);
+    assert!(fairness.sha_matches);
+}
+
+// ============================================================================
+// Test: Shuffled turn order is redrawn from the seed on reload
+// ============================================================================
+#[test]
+fn test_shuffle_each_round() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 110;
+    let proof = Bytes::from_array(&env, &[0xC7; 32]);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    config.shuffle_each_round = true;
+    client.configurar_partida(&session_id, &p1, &config);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+    load_revolver(&env, &client, session_id, &p1, 1);
+
+    // Round 0 runs by seat: P1 clicks, then P2 is hit (P3 would be next)
+    client.disparar(&session_id, &p1, &proof);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+
+    // Session 110's seed draws P1 before P3 for round 1
+    let game = client.get_game(&session_id);
+    assert_eq!(game.turn_order, soroban_sdk::vec![&env, 0, 2]);
+    assert_eq!(game.current_turn, 0);
+
+    // Every alive player is still in the order, and turns follow it
+    for i in 0..game.players.len() {
+        let p = game.players.get(i).unwrap();
+        assert_eq!(game.turn_order.contains(i), p.is_alive);
+    }
+    client.disparar(&session_id, &p1, &proof);
+    assert_eq!(client.get_game(&session_id).current_turn, 2);
+    client.check_invariants(&session_id);
+}
+
+// ============================================================================
+// Test: Static/dynamic storage halves stay consistent across a full game
+// ============================================================================
+#[test]
+fn test_split_storage_consistent() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xC8; 32]);
+
+    let halves = || {
+        env.as_contract(&contract_id, || {
+            let storage = env.storage().temporary();
+            let st: GameStatic = storage.get(&DataKey::GameStatic(session_id)).unwrap();
+            let dy: GameDynamic = storage.get(&DataKey::GameDynamic(session_id)).unwrap();
+            (st, dy)
+        })
+    };
+    let logs = || {
+        env.as_contract(&contract_id, || {
+            let storage = env.storage().temporary();
+            let shots: Vec<ShotRecord> = storage.get(&DataKey::ShotLog(session_id)).unwrap();
+            let out: Vec<Address> = storage.get(&DataKey::EliminatedLog(session_id)).unwrap();
+            (shots, out)
+        })
+    };
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 1);
+
+    // A miss only moves the dynamic half and appends to the shot log
+    let (st_before, dy_before) = halves();
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    let (st_after, dy_after) = halves();
+    assert_eq!(st_after, st_before);
+    assert_ne!(dy_after, dy_before);
+    assert_eq!(logs().0.len(), 1);
+
+    loop {
+        let (st, dy) = halves();
+        let game = client.get_game(&session_id);
+        assert_eq!(game.phase, dy.phase);
+        assert_eq!(game.current_turn, dy.current_turn);
+        assert_eq!(game.current_chamber, dy.current_chamber);
+        assert_eq!(game.shots_fired, dy.shots_fired);
+        assert_eq!(game.bullet_commitment, st.bullet_commitment);
+        let (shots, out) = logs();
+        assert_eq!(game.shot_log, shots);
+        assert_eq!(game.eliminated, out);
+        for i in 0..game.players.len() {
+            let p = game.players.get(i).unwrap();
+            assert_eq!(p.is_alive, st.players.get(i).unwrap().is_alive);
+            assert_eq!(p.shots_survived, dy.shots_survived.get(i).unwrap());
+        }
+        client.check_invariants(&session_id);
+
+        if game.phase == PHASE_FINISHED {
+            break;
+        }
+        let current = game.players.get(game.current_turn).unwrap();
+        client.disparar(&session_id, &current.address, &proof);
+    }
+    assert_eq!(client.get_winner(&session_id), Some(p3.clone()));
+}
+
+// ============================================================================
+// Test: Registration deadline closes the lobby but not the game
+// ============================================================================
+#[test]
+fn test_registration_deadline() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 107;
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    config.registration_deadline_ledger = env.ledger().sequence() + 5;
+    client.configurar_partida(&session_id, &p1, &config);
+
+    env.ledger().with_mut(|l| l.sequence_number += 5);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1); // On the deadline ledger
+
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+    assert_eq!(
+        client.try_entrar_a_la_ruleta(&session_id, &p3, &100, &2),
+        Err(Ok(Error::RegistrationClosed))
+    );
+
+    // The host still starts with whoever made it in
+    load_revolver(&env, &client, session_id, &p1, 3);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, PHASE_PLAYING);
+    assert_eq!(game.players.len(), 2);
+}
+
+// ============================================================================
+// Test: validate_shot reports why a shot would be refused
+// ============================================================================
+#[test]
+fn test_validate_shot() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 108;
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    assert_eq!(
+        client.try_validate_shot(&session_id, &p1),
+        Err(Ok(Error::WrongPhase))
+    );
+
+    load_revolver(&env, &client, session_id, &p1, 3);
+    client.validate_shot(&session_id, &p1);
+    assert_eq!(
+        client.try_validate_shot(&session_id, &p2),
+        Err(Ok(Error::NotYourTurn))
+    );
+    assert_eq!(
+        client.try_validate_shot(&(session_id + 1), &p1),
+        Err(Ok(Error::GameNotFound))
+    );
+    assert_eq!(client.get_game(&session_id).shots_fired, 0); // Nothing moved
+}
+
+// ============================================================================
+// Test: Losers' final points include the consolation credit
+// ============================================================================
+#[test]
+fn test_consolation_points() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xC9; 32]);
+
+    let mut config = client.get_config();
+    config.consolation_points = 10;
+    client.set_config(&config);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    assert_eq!(client.get_game(&session_id).players.get(0).unwrap().final_points, 0);
+    play_to_end(&client, session_id, &proof);
+
+    // Session 96: P3 outlasts P1 and P2
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(p3.clone()));
+    for i in 0..game.players.len() {
+        let p = game.players.get(i).unwrap();
+        let expected = if p.address == p3 { p.points } else { p.points + 10 };
+        assert_eq!(p.final_points, expected);
+    }
+}
+
+// ============================================================================
+// Test: Dev-mode session reset
+// ============================================================================
+#[test]
+fn test_reset_session_dev_mode() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 109;
+    let proof = Bytes::from_array(&env, &[0xCA; 32]);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 3);
+    client.disparar(&session_id, &p1, &proof);
+
+    // Refused outside dev mode
+    assert_eq!(
+        client.try_reset_session(&session_id),
+        Err(Ok(Error::DevModeDisabled))
+    );
+    assert!(client.session_exists(&session_id));
+
+    let mut config = client.get_config();
+    config.dev_mode = true;
+    client.set_config(&config);
+    client.reset_session(&session_id);
+    assert!(!client.session_exists(&session_id));
+    assert_eq!(
+        client.try_get_game(&session_id),
+        Err(Ok(Error::GameNotFound))
+    );
+
+    // The id is free to start over
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    assert_eq!(client.get_game(&session_id).phase, PHASE_WAITING);
+}
+
+// ============================================================================
+// Test: High roller shoots first under points-descending turn order
+// ============================================================================
+#[test]
+fn test_turn_order_points_desc() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 111;
+    let proof = Bytes::from_array(&env, &[0xCB; 32]);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    config.turn_order_mode = TURN_ORDER_POINTS_DESC;
+    client.configurar_partida(&session_id, &p1, &config);
+    client.entrar_a_la_ruleta(&session_id, &p2, &300, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &200, &2);
+    load_revolver(&env, &client, session_id, &p1, 3);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.turn_order, soroban_sdk::vec![&env, 1, 2, 0]);
+    assert_eq!(game.current_turn, 1); // P2 has the most points
+    assert_eq!(game.players.get(0).unwrap().address, p1); // Seats unchanged
+
+    // Turns step through the sequence, not the seats
+    assert_eq!(
+        client.try_disparar(&session_id, &p1, &proof),
+        Err(Ok(Error::NotYourTurn))
+    );
+    client.disparar(&session_id, &p2, &proof);
+    assert_eq!(client.get_game(&session_id).current_turn, 2);
+    client.disparar(&session_id, &p3, &proof);
+    assert_eq!(client.get_game(&session_id).current_turn, 0);
+}
+
+// ============================================================================
+// Test: Shuffled seating keeps every player exactly once, and anyone may
+// end up shooting first
+// ============================================================================
+#[test]
+fn test_shuffle_seating() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let proof = Bytes::from_array(&env, &[0xCD; 32]);
+    let salt = BytesN::from_array(&env, &[42u8; 32]);
+    let commitment = client.compute_bullet_hash(&salt, &3);
+
+    let mut first_shooters = soroban_sdk::Vec::<Address>::new(&env);
+    let mut guest_first = None;
+    for session_id in 74..94u32 {
+        join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+        client.cargar_revolver(&session_id, &p1, &commitment, &3, &salt, &None, &true);
+
+        let game = client.get_game(&session_id);
+        assert_eq!(game.players.len(), 3);
+        for player in [&p1, &p2, &p3] {
+            assert_eq!(game.players.iter().filter(|p| p.address == *player).count(), 1);
+            let seat = game.seats.get(player.clone()).unwrap();
+            assert_eq!(game.players.get(seat).unwrap().address, *player);
+        }
+        assert_eq!(game.current_turn, 0);
+        // The host keeps the role wherever they sit; the hub pair follows
+        // the new first two seats
+        assert_eq!(client.get_host(&session_id), p1);
+        let first = game.players.get(0).unwrap().address;
+        let second = game.players.get(1).unwrap().address;
+        assert_eq!(client.get_hub_pairing(&session_id), (first.clone(), second));
+        if !first_shooters.contains(&first) {
+            first_shooters.push_back(first.clone());
+        }
+        if first != p1 {
+            guest_first = Some(session_id);
+        }
+
+        play_to_end(&client, session_id, &proof);
+        assert_eq!(client.get_game(&session_id).phase, PHASE_FINISHED);
+    }
+    assert!(first_shooters.len() > 1);
+
+    // A host shuffled out of seat 0 can still reveal
+    let session_id = guest_first.unwrap();
+    client.revelar_bala(&session_id, &p1, &salt);
+    assert_eq!(client.get_revealed_salt(&session_id), salt);
+}
+
+// ============================================================================
+// Test: Round counter follows elimination-driven reloads
+// ============================================================================
+#[test]
+fn test_get_round() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xCC; 32]);
+
+    assert_eq!(client.try_get_round(&session_id), Err(Ok(Error::GameNotFound)));
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    assert_eq!(client.get_round(&session_id), 0);
+
+    client.disparar(&session_id, &p1, &proof);
+    assert_eq!(client.get_round(&session_id), 0); // A click doesn't reload
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+    assert_eq!(client.get_round(&session_id), 1);
+
+    // The final elimination ends the game instead of reloading
+    play_to_end(&client, session_id, &proof);
+    assert_eq!(client.get_round(&session_id), 1);
+}
+
+// ============================================================================
+// Test: Round info tracks the cylinder across shots and a reload
+// ============================================================================
+#[test]
+fn test_get_round_info() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xCE; 32]);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 2);
+    client.disparar(&session_id, &p1, &proof);
+    client.disparar(&session_id, &p2, &proof);
+    let info = RoundInfo {
+        round: 0,
+        current_chamber: 2,
+        num_chambers: NUM_CHAMBERS,
+        shots_fired: 2,
+    };
+    assert_eq!(client.get_round_info(&session_id), info);
+
+    // P3 takes the bullet in chamber 2: the cylinder resets for round 1
+    assert_eq!(client.disparar(&session_id, &p3, &proof), SHOT_HIT);
+    let info = RoundInfo {
+        round: 1,
+        current_chamber: 0,
+        num_chambers: NUM_CHAMBERS,
+        shots_fired: 3,
+    };
+    assert_eq!(client.get_round_info(&session_id), info);
+}
+
+// ============================================================================
+// Test: Oversized proofs are rejected before verification
+// ============================================================================
+#[test]
+fn test_proof_too_large() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 112;
+
+    let mut config = client.get_config();
+    config.max_proof_bytes = 64;
+    client.set_config(&config);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 3);
+
+    let huge = Bytes::from_array(&env, &[0xCD; 65]);
+    assert_eq!(
+        client.try_disparar(&session_id, &p1, &huge),
+        Err(Ok(Error::ProofTooLarge))
+    );
+    assert_eq!(client.get_game(&session_id).shots_fired, 0);
+
+    let fits = Bytes::from_array(&env, &[0xCD; 64]);
+    assert_eq!(client.disparar(&session_id, &p1, &fits), SHOT_SURVIVED);
+    assert_eq!(client.get_game(&session_id).shots_fired, 1);
+}
+
+// ============================================================================
+// Test: Pending actions follow the game from lobby to reveal
+// ============================================================================
+#[test]
+fn test_pending_actions() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let token_id = setup_token(&env, &[&p1, &p2], 1_000);
+    let proof = Bytes::from_array(&env, &[0xCE; 32]);
+
+    let mut config = client.get_config();
+    config.stake_tokens = soroban_sdk::vec![&env, token_id.clone()];
+    client.set_config(&config);
+
+    // Lobby: whoever hasn't staked yet is asked to
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    client.apostar(&session_id, &p1, &token_id, &10);
+    assert_eq!(
+        client.get_pending_actions(&session_id),
+        soroban_sdk::vec![&env, (p1.clone(), ACTION_NONE), (p2.clone(), ACTION_STAKE)]
+    );
+
+    // Playing: only the shooter has something to do
+    client.apostar(&session_id, &p2, &token_id, &10);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    assert_eq!(
+        client.get_pending_actions(&session_id),
+        soroban_sdk::vec![&env, (p1.clone(), ACTION_SHOOT), (p2.clone(), ACTION_NONE)]
+    );
+
+    // Finished: the host owes the reveal until they publish the salt
+    play_to_end(&client, session_id, &proof);
+    assert_eq!(
+        client.get_pending_actions(&session_id),
+        soroban_sdk::vec![&env, (p1.clone(), ACTION_REVEAL), (p2.clone(), ACTION_NONE)]
+    );
+    client.revelar_bala(&session_id, &p1, &BytesN::from_array(&env, &[42u8; 32]));
+    assert_eq!(
+        client.get_pending_actions(&session_id),
+        soroban_sdk::vec![&env, (p1.clone(), ACTION_NONE), (p2.clone(), ACTION_NONE)]
+    );
+}
+
+// ============================================================================
+// Test: Continue-mode reloads never land on a fired chamber
+// ============================================================================
+#[test]
+fn test_reload_continue_skips_fired_chambers() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let proof = Bytes::from_array(&env, &[0xCF; 32]);
+
+    for session_id in 120..140u32 {
+        client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+        let mut config = client.get_game(&session_id).config;
+        config.reload_mode = RELOAD_CONTINUE;
+        client.configurar_partida(&session_id, &p1, &config);
+        client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+        client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+        load_revolver(&env, &client, session_id, &p1, 2);
+
+        // Chambers 0-2 are fired; P3 takes the bullet in chamber 2
+        client.disparar(&session_id, &p1, &proof);
+        client.disparar(&session_id, &p2, &proof);
+        assert_eq!(client.disparar(&session_id, &p3, &proof), SHOT_HIT);
+
+        let game = client.get_game(&session_id);
+        assert_eq!(game.round, 1);
+        assert_eq!(game.current_chamber, 3);
+        assert!(game.bullet_position >= 3 && game.bullet_position < NUM_CHAMBERS);
+    }
+}
+
+// ============================================================================
+// Test: PhaseChanged traces the state machine across a full game
+// ============================================================================
+#[test]
+fn test_phase_changed_events() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xD0; 32]);
+
+    let phase_events = || {
+        let changed = |from_phase, to_phase| {
+            PhaseChanged {
+                session_id,
+                from_phase,
+                to_phase,
+                at_ledger: env.ledger().sequence(),
+            }
+            .to_xdr(&env, &contract_id)
+        };
+        let all = env.events().all().filter_by_contract(&contract_id);
+        let mut trace = std::vec::Vec::new();
+        for (from, to) in [(PHASE_WAITING, PHASE_PLAYING), (PHASE_PLAYING, PHASE_FINISHED)] {
+            if all.events().contains(&changed(from, to)) {
+                trace.push((from, to));
+            }
+        }
+        trace
+    };
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    assert!(phase_events().is_empty());
+
+    load_revolver(&env, &client, session_id, &p1, 1);
+    assert_eq!(phase_events(), [(PHASE_WAITING, PHASE_PLAYING)]);
+
+    let mut trace = std::vec::Vec::new();
+    loop {
+        let game = client.get_game(&session_id);
+        if game.phase == PHASE_FINISHED {
+            break;
+        }
+        let current = game.players.get(game.current_turn).unwrap();
+        client.disparar(&session_id, &current.address, &proof);
+        trace.extend(phase_events());
+    }
+    assert_eq!(trace, [(PHASE_PLAYING, PHASE_FINISHED)]);
+}
+
+// ============================================================================
+// Test: Reused commitments are refused in competitive sessions
+// ============================================================================
+#[test]
+fn test_commitment_reuse_rejected() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let salt = BytesN::from_array(&env, &[42u8; 32]);
+    let commitment = client.compute_bullet_hash(&salt, &3);
+
+    let mut config = client.get_config();
+    config.reject_reused_commitments = true;
+    client.set_config(&config);
+
+    let hidden_lobby = |session_id: u32| {
+        client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+        let mut session = client.get_game(&session_id).config;
+        session.mode = MODE_HIDDEN;
+        client.configurar_partida(&session_id, &p1, &session);
+        client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    };
+
+    assert!(client.commitment_is_fresh(&commitment));
+    hidden_lobby(113);
+    client.cargar_revolver(&113, &p1, &commitment, &3, &salt, &None, &false);
+    assert!(!client.commitment_is_fresh(&commitment));
+
+    hidden_lobby(114);
+    assert_eq!(
+        client.try_cargar_revolver(&114, &p1, &commitment, &3, &salt, &None, &false),
+        Err(Ok(Error::CommitmentReused))
+    );
+    let fresh_salt = BytesN::from_array(&env, &[7u8; 32]);
+    let fresh = client.compute_bullet_hash(&fresh_salt, &3);
+    client.cargar_revolver(&114, &p1, &fresh, &3, &fresh_salt, &None, &false);
+
+    // Practice sessions may reuse it
+    join_two_players(&env, &client, 115, &p1, &p2);
+    client.cargar_revolver(&115, &p1, &commitment, &3, &salt, &None, &false);
+}
+
+// ============================================================================
+// Test: Admin force-resolve in refund-all mode returns every stake
+// ============================================================================
+#[test]
+fn test_resolver_manualmente_refund_all() {
+    let (env, contract_id, p1, p2, p3, hub) = setup_recording_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let hub_client = recording_hub::RecordingHubClient::new(&env, &hub);
+    let session_id: u32 = 116;
+    let token_id = setup_token(&env, &[&p1, &p2, &p3], 1_000);
+    let token = TokenClient::new(&env, &token_id);
+
+    let mut config = client.get_config();
+    config.stake_tokens = soroban_sdk::vec![&env, token_id.clone()];
+    client.set_config(&config);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    client.apostar(&session_id, &p1, &token_id, &100);
+    client.apostar(&session_id, &p2, &token_id, &200);
+    client.apostar(&session_id, &p3, &token_id, &300);
+    load_revolver(&env, &client, session_id, &p1, 3);
+    assert_eq!(token.balance(&contract_id), 600);
+
+    assert_eq!(
+        client.try_resolver_manualmente(&session_id, &p1, &3),
+        Err(Ok(Error::InvalidConfig))
+    );
+    client.resolver_manualmente(&session_id, &p1, &RESOLVE_REFUND_ALL);
+
+    for p in [&p1, &p2, &p3] {
+        assert_eq!(token.balance(p), 1_000);
+    }
+    assert_eq!(token.balance(&contract_id), 0);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, PHASE_FINISHED);
+    assert_eq!(game.winner, Some(p1.clone()));
+    assert_eq!(hub_client.ended(&session_id), Some(true));
+}
+
+// ============================================================================
+// Test: Abort reports the highest-points survivor to the hub
+// ============================================================================
+#[test]
+fn test_abortar_partida_points_tiebreak() {
+    let (env, contract_id, p1, p2, p3, hub) = setup_recording_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let hub = recording_hub::RecordingHubClient::new(&env, &hub);
+    let session_id: u32 = 82;
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    client.entrar_a_la_ruleta(&session_id, &p2, &300, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &200, &2);
+    assert_eq!(
+        client.try_abortar_partida(&session_id, &RESOLVE_REFUND_ALL),
+        Err(Ok(Error::WrongPhase))
+    );
+    load_revolver(&env, &client, session_id, &p1, 3);
+
+    assert_eq!(client.abortar_partida(&session_id, &RESOLVE_REFUND_ALL), p2);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, PHASE_FINISHED);
+    assert_eq!(game.winner, Some(p2.clone()));
+    // Hub pair is P1/P2: player 2 won
+    assert_eq!(hub.ended(&session_id), Some(false));
+
+    // Equal points fall to the lower address
+    let session_id = session_id + 1;
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    client.entrar_a_la_ruleta(&session_id, &p2, &500, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &500, &2);
+    load_revolver(&env, &client, session_id, &p1, 3);
+    let expected = if p2 < p3 { p2.clone() } else { p3.clone() };
+    assert_eq!(client.abortar_partida(&session_id, &RESOLVE_REFUND_ALL), expected);
+
+    // With nobody left alive there is no winner to report
+    let session_id = session_id + 1;
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 3);
+    env.as_contract(&contract_id, || {
+        let mut game = ZkMafiaContract::load_game(&env, session_id).unwrap();
+        for seat in 0..game.players.len() {
+            let mut p = game.players.get(seat).unwrap();
+            p.is_alive = false;
+            game.players.set(seat, p);
+        }
+        ZkMafiaContract::save_game(&env, &mut game);
+    });
+    assert_eq!(
+        client.try_abortar_partida(&session_id, &RESOLVE_REFUND_ALL),
+        Err(Ok(Error::GameAlreadyEnded))
+    );
+}
+
+// ============================================================================
+// Test: Admin force-resolve splitting the pot among survivors
+// ============================================================================
+#[test]
+fn test_resolver_manualmente_split_survivors() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let token_id = setup_token(&env, &[&p1, &p2, &p3], 1_000);
+    let token = TokenClient::new(&env, &token_id);
+    let proof = Bytes::from_array(&env, &[0xD1; 32]);
+
+    let mut config = client.get_config();
+    config.stake_tokens = soroban_sdk::vec![&env, token_id.clone()];
+    client.set_config(&config);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    for p in [&p1, &p2, &p3] {
+        client.apostar(&session_id, p, &token_id, &101);
+    }
+    load_revolver(&env, &client, session_id, &p1, 1);
+    client.disparar(&session_id, &p1, &proof);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT); // P2 out
+
+    // Eliminated players can't be declared the winner
+    assert_eq!(
+        client.try_resolver_manualmente(&session_id, &p2, &RESOLVE_SPLIT_SURVIVORS),
+        Err(Ok(Error::PlayerEliminated))
+    );
+    client.resolver_manualmente(&session_id, &p3, &RESOLVE_SPLIT_SURVIVORS);
+
+    // 303 split two ways: 151 each, the odd unit goes to the declared winner
+    assert_eq!(token.balance(&p1), 899 + 151);
+    assert_eq!(token.balance(&p3), 899 + 152);
+    assert_eq!(token.balance(&p2), 899);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+// ============================================================================
+// Test: Splitting among survivors leaves the pot alone when nobody is alive
+// ============================================================================
+#[test]
+fn test_split_pot_among_alive_without_survivors() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 97;
+    let token_id = setup_token(&env, &[&p1, &p2], 1_000);
+
+    let mut config = client.get_config();
+    config.stake_tokens = soroban_sdk::vec![&env, token_id.clone()];
+    client.set_config(&config);
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    client.apostar(&session_id, &p1, &token_id, &100);
+    load_revolver(&env, &client, session_id, &p1, 1);
+
+    env.as_contract(&contract_id, || {
+        let mut game = ZkMafiaContract::load_game(&env, session_id).unwrap();
+        for seat in 0..game.players.len() {
+            let mut p = game.players.get(seat).unwrap();
+            p.is_alive = false;
+            game.players.set(seat, p);
+        }
+        ZkMafiaContract::split_pot_among_alive(&env, &mut game);
+        assert_eq!(game.pot.get(token_id.clone()), Some(100));
+    });
+}
+
+// ============================================================================
+// Test: Handicap widens a strong player's danger zone
+// ============================================================================
+#[test]
+fn test_handicap_danger_zone() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let proof = Bytes::from_array(&env, &[0xD2; 32]);
+
+    // Session 96, two players, bullet 1: P1 wins, so P1 is 1-0
+    join_two_players(&env, &client, 96, &p1, &p2);
+    load_revolver(&env, &client, 96, &p1, 1);
+    play_to_end(&client, 96, &proof);
+    assert_eq!(client.get_winner(&96), Some(p1.clone()));
+    assert_eq!(
+        client.get_player_stats(&p1),
+        PlayerStats { games_played: 1, wins: 1 }
+    );
+    assert_eq!(client.get_player_stats(&p2).wins, 0);
+
+    let mut config = client.get_config();
+    config.handicap = true;
+    config.handicap_table = soroban_sdk::vec![&env, (5_000, 1)];
+    client.set_config(&config);
+
+    // Bullet in chamber 2: P2 (0% wins) is safe in chamber 1...
+    join_all_players(&env, &client, 117, &p2, &p1, &p3);
+    load_revolver(&env, &client, 117, &p2, 2);
+    assert_eq!(client.disparar(&117, &p2, &proof), SHOT_SURVIVED); // Chamber 0
+    // ...but P1 (100% wins) is hit one chamber early
+    assert_eq!(client.disparar(&117, &p1, &proof), SHOT_HIT); // Chamber 1
+    assert!(!client.get_game(&117).players.get(1).unwrap().is_alive);
+
+    // Unranked P3 still needs the exact chamber
+    join_all_players(&env, &client, 118, &p3, &p2, &p1);
+    load_revolver(&env, &client, 118, &p3, 1);
+    assert_eq!(client.disparar(&118, &p3, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&118, &p2, &proof), SHOT_HIT);
+}
+
+// ============================================================================
+// Test: Large win counts and pots don't overflow the rate and share math
+// ============================================================================
+#[test]
+fn test_handicap_and_shares_with_large_values() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let proof = Bytes::from_array(&env, &[0xE7; 32]);
+
+    // wins × 10_000 no longer fits a u32
+    env.as_contract(&contract_id, || {
+        let veteran = PlayerStats { games_played: 1_000_000, wins: 1_000_000 };
+        env.storage().persistent().set(&DataKey::Stats(p2.clone()), &veteran);
+    });
+    let mut config = client.get_config();
+    config.handicap = true;
+    config.handicap_table = soroban_sdk::vec![&env, (5_000, 1)];
+    client.set_config(&config);
+
+    join_all_players(&env, &client, 114, &p1, &p2, &p3);
+    load_revolver(&env, &client, 114, &p1, 2);
+    assert_eq!(client.disparar(&114, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&114, &p2, &proof), SHOT_HIT);
+
+    // Basis-point shares stay exact up to the largest amounts
+    assert_eq!(ZkMafiaContract::bps_of(i128::MAX, 10_000), i128::MAX);
+    assert_eq!(ZkMafiaContract::bps_of(i128::MAX, 5_000), i128::MAX / 2);
+    assert_eq!(ZkMafiaContract::bps_of(19_999, 5_000), 9_999);
+}
+
+// ============================================================================
+// Test: Session config snapshot reflects the host's rules
+// ============================================================================
+#[test]
+fn test_get_session_config() {
+    let (env, contract_id, p1, _p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 119;
+
+    assert_eq!(
+        client.try_get_session_config(&session_id),
+        Err(Ok(Error::GameNotFound))
+    );
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_session_config(&session_id);
+    assert_eq!(config, client.get_game(&session_id).config);
+    config.mode = MODE_HIDDEN;
+    config.payout_mode = PAYOUT_CLAIM;
+    config.sudden_death_after = 4;
+    config.reload_mode = RELOAD_CONTINUE;
+    config.turn_order_mode = TURN_ORDER_POINTS_ASC;
+    client.configurar_partida(&session_id, &p1, &config);
+
+    assert_eq!(client.get_session_config(&session_id), config);
+}
+
+// ============================================================================
+// Test: Delayed elimination finalizes only after the delay
+// ============================================================================
+#[test]
+fn test_elimination_delay() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xD3; 32]);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    config.elimination_delay_ledgers = u32::MAX;
+    assert_eq!(
+        client.try_configurar_partida(&session_id, &p1, &config),
+        Err(Ok(Error::InvalidConfig))
+    );
+    config.elimination_delay_ledgers = 3;
+    client.configurar_partida(&session_id, &p1, &config);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+    load_revolver(&env, &client, session_id, &p1, 1);
+
+    assert_eq!(
+        client.try_confirmar_eliminacion(&session_id),
+        Err(Ok(Error::NoPendingElimination))
+    );
+    client.disparar(&session_id, &p1, &proof);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+
+    // P2 is hit but still standing while the animation plays
+    let game = client.get_game(&session_id);
+    assert_eq!(game.pending_elimination, Some(p2.clone()));
+    assert!(game.players.get(1).unwrap().is_alive);
+    assert_eq!(game.round, 0);
+    assert_eq!(
+        client.try_disparar(&session_id, &p3, &proof),
+        Err(Ok(Error::EliminationPending))
+    );
+    assert_eq!(
+        client.get_pending_actions(&session_id),
+        soroban_sdk::vec![
+            &env,
+            (p1.clone(), ACTION_NONE),
+            (p2.clone(), ACTION_CONFIRM),
+            (p3.clone(), ACTION_NONE)
+        ]
+    );
+    env.ledger().with_mut(|l| l.sequence_number += 2);
+    assert_eq!(
+        client.try_confirmar_eliminacion(&session_id),
+        Err(Ok(Error::EliminationPending))
+    );
+
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+    assert_eq!(client.confirmar_eliminacion(&session_id), p2);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.pending_elimination, None);
+    assert!(!game.players.get(1).unwrap().is_alive);
+    assert_eq!(game.round, 1);
+    assert_eq!(game.current_turn, 2);
+    assert_eq!(game.bullet_position, 3); // Same reload an immediate hit gets
+    client.check_invariants(&session_id);
+}
+
+// ============================================================================
+// Test: Admin switches a live game to continue-mode reloads
+// ============================================================================
+#[test]
+fn test_cambiar_reload_mode() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xD4; 32]);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    assert_eq!(
+        client.try_cambiar_reload_mode(&session_id, &RELOAD_CONTINUE),
+        Err(Ok(Error::WrongPhase))
+    );
+    load_revolver(&env, &client, session_id, &p1, 1);
+    client.disparar(&session_id, &p1, &proof);
+
+    assert_eq!(
+        client.try_cambiar_reload_mode(&session_id, &2),
+        Err(Ok(Error::InvalidConfig))
+    );
+    // Mid-round state is valid under either mode, so it is kept
+    assert_eq!(client.cambiar_reload_mode(&session_id, &RELOAD_CONTINUE), 1);
+    assert_eq!(client.get_session_config(&session_id).reload_mode, RELOAD_CONTINUE);
+
+    // P2's hit at chamber 1 now keeps the cylinder turning
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.current_chamber, 2);
+    assert!(game.bullet_position >= 2);
+    client.check_invariants(&session_id);
+}
+
+// ============================================================================
+// Test: Staked volume is tracked per token, net of refunds
+// ============================================================================
+#[test]
+fn test_token_volume() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let usdc_id = setup_token(&env, &[&p1, &p2], 1_000);
+    let xlm_id = setup_token(&env, &[&p1, &p2], 1_000);
+
+    let mut config = client.get_config();
+    config.stake_tokens = soroban_sdk::vec![&env, usdc_id.clone(), xlm_id.clone()];
+    client.set_config(&config);
+
+    join_two_players(&env, &client, 1, &p1, &p2);
+    client.apostar(&1, &p1, &usdc_id, &100);
+    client.apostar(&1, &p2, &xlm_id, &40);
+    join_two_players(&env, &client, 2, &p1, &p2);
+    client.apostar(&2, &p1, &usdc_id, &25);
+    assert_eq!(client.get_token_volume(&usdc_id), 125);
+    assert_eq!(client.get_token_volume(&xlm_id), 40);
+
+    // Leaving the lobby refunds the stake and takes it off the volume
+    client.salir_de_la_ruleta(&2, &p1);
+    assert_eq!(client.get_token_volume(&usdc_id), 100);
+    assert_eq!(client.get_token_volume(&xlm_id), 40);
+}
+
+// ============================================================================
+// Test: Insurance absorbs one hit, the next one eliminates
+// ============================================================================
+#[test]
+fn test_insurance_absorbs_first_hit() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let token_id = setup_token(&env, &[&p1, &p2], 1_000);
+    let proof = Bytes::from_array(&env, &[0xD1; 32]);
+
+    let mut config = client.get_config();
+    config.stake_tokens = soroban_sdk::vec![&env, token_id.clone()];
+    config.insurance_price = 50;
+    client.set_config(&config);
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    load_revolver(&env, &client, session_id, &p1, 1);
+
+    assert_eq!(
+        client.try_comprar_seguro(&session_id, &p2, &token_id, &49),
+        Err(Ok(Error::InvalidStake))
+    );
+    client.comprar_seguro(&session_id, &p2, &token_id, &50);
+    assert_eq!(
+        client.try_comprar_seguro(&session_id, &p2, &token_id, &50),
+        Err(Ok(Error::InsuranceUnavailable))
+    );
+    assert!(client.get_multi_pot(&session_id).contains(&(token_id.clone(), 50)));
+
+    // P2 takes the bullet but the policy absorbs it
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+    assert_eq!(event_topics(&env, &contract_id), ["absorbed", "cylinder_reloaded"]);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, PHASE_PLAYING);
+    assert!(game.players.get(1).unwrap().is_alive);
+    assert!(!game.players.get(1).unwrap().insured);
+    assert_eq!(game.bullet_position, 3);
+
+    // Uninsured now: the reloaded bullet in chamber 3 finishes P2
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, PHASE_FINISHED);
+    assert_eq!(game.winner, Some(p1.clone()));
+}
+
+// ============================================================================
+// Test: Preloaded bullet sequence is revealed leaf by leaf
+// ============================================================================
+#[test]
+fn test_bullet_sequence_reveals() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 70;
+    let token_id = setup_token(&env, &[&p2, &p3], 1_000);
+    let proof = Bytes::from_array(&env, &[0xD7; 32]);
+
+    // Three leaves, the odd one paired with itself
+    let hash_pair = |a: &BytesN<32>, b: &BytesN<32>| -> BytesN<32> {
+        let mut preimage = Bytes::from_array(&env, &a.to_array());
+        preimage.extend_from_array(&b.to_array());
+        env.crypto().sha256(&preimage).into()
+    };
+    let values = [0u32, 1, 4];
+    let salts: std::vec::Vec<BytesN<32>> =
+        (1..=3u8).map(|i| BytesN::from_array(&env, &[i; 32])).collect();
+    let leaves: std::vec::Vec<BytesN<32>> =
+        (0..3).map(|i| client.compute_bullet_hash(&salts[i], &values[i])).collect();
+    let n01 = hash_pair(&leaves[0], &leaves[1]);
+    let n22 = hash_pair(&leaves[2], &leaves[2]);
+    let root = hash_pair(&n01, &n22);
+    let proofs = [
+        soroban_sdk::vec![&env, leaves[1].clone(), n22.clone()],
+        soroban_sdk::vec![&env, leaves[0].clone(), n22.clone()],
+        soroban_sdk::vec![&env, leaves[2].clone(), n01.clone()],
+    ];
+
+    let mut config = client.get_config();
+    config.stake_tokens = soroban_sdk::vec![&env, token_id.clone()];
+    config.insurance_price = 50;
+    client.set_config(&config);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut session = client.get_game(&session_id).config;
+    session.bullet_sequence_commitment = Some(root);
+    client.configurar_partida(&session_id, &p1, &session);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    client.comprar_seguro(&session_id, &p2, &token_id, &50);
+    client.comprar_seguro(&session_id, &p3, &token_id, &50);
+
+    // P2's policy absorbs the host's bullet; the reload waits on the host
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+    assert!(client.get_game(&session_id).awaiting_reload);
+    assert_eq!(
+        client.try_disparar(&session_id, &p3, &proof),
+        Err(Ok(Error::AwaitingReload))
+    );
+
+    // Leaf 1 can't stand in for reload 0, nor can a wrong value
+    assert_eq!(
+        client.try_recargar(&session_id, &p1, &values[1], &salts[1], &proofs[1]),
+        Err(Ok(Error::InvalidReloadProof))
+    );
+    assert_eq!(
+        client.try_recargar(&session_id, &p1, &3, &salts[0], &proofs[0]),
+        Err(Ok(Error::InvalidReloadProof))
+    );
+    assert_eq!(client.recargar(&session_id, &p1, &values[0], &salts[0], &proofs[0]), 0);
+
+    // P3's policy absorbs chamber 0; reload 1 puts the bullet in chamber 1
+    assert_eq!(client.disparar(&session_id, &p3, &proof), SHOT_HIT);
+    assert_eq!(client.recargar(&session_id, &p1, &values[1], &salts[1], &proofs[1]), 1);
+
+    // P2 is uninsured now and falls; reload 2 puts it in chamber 4
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+    assert_eq!(client.recargar(&session_id, &p1, &values[2], &salts[2], &proofs[2]), 4);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.bullet_history, soroban_sdk::vec![&env, 1, 0, 1, 4]);
+    assert_eq!(
+        client.try_recargar(&session_id, &p1, &values[2], &salts[2], &proofs[2]),
+        Err(Ok(Error::WrongPhase))
+    );
+
+    play_to_end(&client, session_id, &proof);
+    assert!(client.auditar_partida(&session_id, &BytesN::from_array(&env, &[42u8; 32])));
+}
+
+// ============================================================================
+// Test: A revealed reload loads the round's full bullet count
+// ============================================================================
+#[test]
+fn test_recargar_loads_bullets_per_round() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 71;
+    let proof = Bytes::from_array(&env, &[0xD8; 32]);
+
+    // A single leaf is its own root
+    let salt = BytesN::from_array(&env, &[9u8; 32]);
+    let leaf = client.compute_bullet_hash(&salt, &2);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut session = client.get_game(&session_id).config;
+    session.bullet_sequence_commitment = Some(leaf);
+    session.bullets_per_round = 3;
+    client.configurar_partida(&session_id, &p1, &session);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+    load_revolver(&env, &client, session_id, &p1, 0);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_HIT);
+
+    // The revealed value still places the first bullet drawn
+    let bullet = client.recargar(&session_id, &p1, &2, &salt, &soroban_sdk::vec![&env]);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.seed_shots_fired, 1);
+    play_to_end(&client, session_id, &proof);
+    let chambers = client.get_fatal_chambers(&session_id, &1);
+    assert_eq!(chambers.len(), 3);
+    assert!(chambers.contains(2));
+    assert_eq!(chambers.get(0), Some(bullet));
+}
+
+// ============================================================================
+// Test: Phase timeline records when the game started and finished
+// ============================================================================
+#[test]
+fn test_phase_timeline() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xD2; 32]);
+
+    env.ledger().set_sequence_number(100);
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    assert_eq!(
+        client.get_phase_timeline(&session_id),
+        soroban_sdk::vec![&env, (PHASE_WAITING, 100)]
+    );
+
+    env.ledger().set_sequence_number(110);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    env.ledger().set_sequence_number(125);
+    play_to_end(&client, session_id, &proof);
+
+    assert_eq!(
+        client.get_phase_timeline(&session_id),
+        soroban_sdk::vec![
+            &env,
+            (PHASE_WAITING, 100),
+            (PHASE_PLAYING, 110),
+            (PHASE_FINISHED, 125)
+        ]
+    );
+}
+
+// ============================================================================
+// Test: Action counts attribute each state change to its caller
+// ============================================================================
+#[test]
+fn test_action_counts() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xD3; 32]);
+
+    // P1: join, load, shoot. P2: join, shoot (and take the bullet)
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    client.disparar(&session_id, &p1, &proof);
+    client.disparar(&session_id, &p2, &proof);
+
+    let counts = client.get_action_counts(&session_id);
+    assert_eq!(counts.len(), 2);
+    assert!(counts.contains(&(p1.clone(), 3)));
+    assert!(counts.contains(&(p2.clone(), 2)));
+    assert_eq!(client.get_game(&session_id).last_actor, p2);
+}
+
+// ============================================================================
+// Test: Audit replays an honest game and catches a tampered shot log
+// ============================================================================
+#[test]
+fn test_auditar_partida() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let salt = BytesN::from_array(&env, &[42u8; 32]);
+    let proof = Bytes::from_array(&env, &[0xD4; 32]);
+
+    // P2 is hit in chamber 1, the reload derives chamber 3, P3 wins
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    play_to_end(&client, session_id, &proof);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.bullet_history.len(), 2);
+    assert!(game.shot_log.len() > 2);
+
+    assert!(client.auditar_partida(&session_id, &salt));
+    assert!(!client.auditar_partida(&session_id, &BytesN::from_array(&env, &[7u8; 32])));
+
+    // Rewrite P1's opening miss as a hit
+    env.as_contract(&contract_id, || {
+        let mut game = ZkMafiaContract::load_game(&env, session_id).unwrap();
+        let mut shot = game.shot_log.get(0).unwrap();
+        shot.hit = true;
+        game.shot_log.set(0, shot);
+        ZkMafiaContract::save_game(&env, &mut game);
+    });
+    assert!(!client.auditar_partida(&session_id, &salt));
+}
+
+// ============================================================================
+// Test: Each joiner after the host pays the host's entry fee
+// ============================================================================
+#[test]
+fn test_host_fee_paid_to_host() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 7;
+    let token_id = setup_token(&env, &[&p1, &p2, &p3], 1_000);
+    let token = TokenClient::new(&env, &token_id);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_session_config(&session_id);
+    config.host_fee = 25;
+    assert_eq!(
+        client.try_configurar_partida(&session_id, &p1, &config),
+        Err(Ok(Error::InvalidConfig))
+    );
+    config.host_fee_token = Some(token_id.clone());
+    client.configurar_partida(&session_id, &p1, &config);
+
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    assert_eq!(token.balance(&p1), 1_025);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+    assert_eq!(token.balance(&p1), 1_050);
+    assert_eq!(token.balance(&p2), 975);
+    assert_eq!(token.balance(&p3), 975);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+// ============================================================================
+// Test: Termination check accepts a normal game, rejects an empty cylinder
+// ============================================================================
+#[test]
+fn test_config_is_terminating() {
+    let (env, contract_id, _p1, _p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+
+    assert!(client.config_is_terminating(&NUM_CHAMBERS, &1, &RELOAD_RESET, &3));
+    assert!(client.config_is_terminating(&NUM_CHAMBERS, &1, &RELOAD_CONTINUE, &2));
+    assert!(!client.config_is_terminating(&NUM_CHAMBERS, &0, &RELOAD_RESET, &3));
+    assert!(!client.config_is_terminating(&NUM_CHAMBERS, &1, &RELOAD_RESET, &1));
+}
+
+// ============================================================================
+// Test: A session can report to its own hub instead of the global one
+// ============================================================================
+#[test]
+fn test_hub_override() {
+    let (env, contract_id, p1, p2, _p3, hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let salt = BytesN::from_array(&env, &[42u8; 32]);
+    let commitment = client.compute_bullet_hash(&salt, &1);
+    let proof = Bytes::from_array(&env, &[0xD5; 32]);
+
+    let tournament_hub = env.register(recording_hub::RecordingHub, ());
+    let tournament = recording_hub::RecordingHubClient::new(&env, &tournament_hub);
+    assert_eq!(client.try_add_hub(&p2), Err(Ok(Error::InvalidConfig)));
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    let tournament_override = Some(tournament_hub.clone());
+    assert_eq!(
+        client.try_cargar_revolver(
+            &session_id,
+            &p1,
+            &commitment,
+            &1,
+            &salt,
+            &tournament_override,
+            &false
+        ),
+        Err(Ok(Error::UnknownHub))
+    );
+    client.add_hub(&tournament_hub);
+    assert_eq!(client.get_approved_hubs(), soroban_sdk::vec![&env, hub, tournament_hub.clone()]);
+    client.cargar_revolver(&session_id, &p1, &commitment, &1, &salt, &tournament_override, &false);
+    assert_eq!(
+        tournament.started(&session_id),
+        Some((p1.clone(), p2.clone(), 100, 100))
+    );
+
+    // Withdrawing approval doesn't strand a session already loaded
+    client.remove_hub(&tournament_hub);
+    assert_eq!(client.try_remove_hub(&tournament_hub), Err(Ok(Error::UnknownHub)));
+    play_to_end(&client, session_id, &proof);
+    assert_eq!(tournament.ended(&session_id), Some(true));
+}
+
+// ============================================================================
+// Test: Sessions are routed to whichever approved hub they chose
+// ============================================================================
+#[test]
+fn test_sessions_routed_to_approved_hubs() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let salt = BytesN::from_array(&env, &[42u8; 32]);
+    let commitment = client.compute_bullet_hash(&salt, &1);
+    let proof = Bytes::from_array(&env, &[0xD6; 32]);
+
+    let hub_a = env.register(recording_hub::RecordingHub, ());
+    let hub_b = env.register(recording_hub::RecordingHub, ());
+    client.add_hub(&hub_a);
+    client.add_hub(&hub_b);
+    client.add_hub(&hub_a);
+    assert_eq!(client.get_approved_hubs().len(), 3);
+
+    for (session_id, chosen) in [(73u32, &hub_a), (74, &hub_b)] {
+        join_two_players(&env, &client, session_id, &p1, &p2);
+        let hub_override = Some(chosen.clone());
+        client.cargar_revolver(&session_id, &p1, &commitment, &1, &salt, &hub_override, &false);
+        play_to_end(&client, session_id, &proof);
+    }
+
+    let a = recording_hub::RecordingHubClient::new(&env, &hub_a);
+    let b = recording_hub::RecordingHubClient::new(&env, &hub_b);
+    assert_eq!(a.ended(&73), Some(true));
+    assert_eq!(a.started(&74), None);
+    assert_eq!(b.ended(&74), Some(true));
+    assert_eq!(b.started(&73), None);
+}
+
+// ============================================================================
+// Test: Stakes that would push the pot past the cap are rejected
+// ============================================================================
+#[test]
+fn test_pot_cap() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 7;
+    let token_id = setup_token(&env, &[&p1, &p2, &p3], 1_000);
+
+    let mut config = client.get_config();
+    assert_eq!(config.max_pot, i128::MAX);
+    config.stake_tokens = soroban_sdk::vec![&env, token_id.clone()];
+    config.max_pot = 250;
+    client.set_config(&config);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    client.apostar(&session_id, &p1, &token_id, &100);
+    client.apostar(&session_id, &p2, &token_id, &100);
+
+    // 200 held: another 100 would make 300
+    assert_eq!(
+        client.try_apostar(&session_id, &p3, &token_id, &100),
+        Err(Ok(Error::PotCapExceeded))
+    );
+    assert_eq!(client.apostar(&session_id, &p3, &token_id, &50), 50);
+    assert!(client.get_multi_pot(&session_id).contains(&(token_id.clone(), 250)));
+}
+
+// ============================================================================
+// Test: Wins list collects every session a player won
+// ============================================================================
+#[test]
+fn test_player_wins() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let proof = Bytes::from_array(&env, &[0xD6; 32]);
+
+    // Two players, bullet 1: P2 takes it in chamber 1 both times
+    for session_id in [96u32, 97] {
+        join_two_players(&env, &client, session_id, &p1, &p2);
+        load_revolver(&env, &client, session_id, &p1, 1);
+        play_to_end(&client, session_id, &proof);
+    }
+
+    assert_eq!(client.get_player_wins(&p1), soroban_sdk::vec![&env, 96, 97]);
+    assert!(client.get_player_wins(&p2).is_empty());
+}
+
+// ============================================================================
+// Test: Escalation adds a bullet per reload and shortens games
+// ============================================================================
+#[test]
+fn test_bullet_escalation() {
+    // Same sessions with and without escalation; returns total turns
+    let play_all = |escalate: bool| -> u32 {
+        let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+        let client = ZkMafiaContractClient::new(&env, &contract_id);
+        let proof = Bytes::from_array(&env, &[0xD7; 32]);
+        let mut turns = 0;
+        for session_id in 100..120u32 {
+            client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+            let mut config = client.get_session_config(&session_id);
+            config.bullet_escalation = escalate;
+            client.configurar_partida(&session_id, &p1, &config);
+            client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+            client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+            load_revolver(&env, &client, session_id, &p1, 5);
+            turns += play_to_end(&client, session_id, &proof);
+
+            let game = client.get_game(&session_id);
+            let expected = if escalate { 1 + game.round } else { 1 };
+            assert_eq!(game.num_bullets, expected);
+            assert!(client.auditar_partida(&session_id, &BytesN::from_array(&env, &[42u8; 32])));
+        }
+        turns
+    };
+
+    assert!(play_all(true) < play_all(false));
+}
+
+// ============================================================================
+// Test: Current seed re-derives the reloaded bullet
+// ============================================================================
+#[test]
+fn test_get_current_seed() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xD8; 32]);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    assert_eq!(client.try_get_current_seed(&session_id), Err(Ok(Error::WrongPhase)));
+
+    // P2's hit on the second shot reloads the cylinder
+    client.disparar(&session_id, &p1, &proof);
+    client.disparar(&session_id, &p2, &proof);
+    client.disparar(&session_id, &p3, &proof);
+
+    let seed = client.get_current_seed(&session_id);
+    let mut preimage = Bytes::from_array(&env, &session_id.to_be_bytes());
+    preimage.append(&Bytes::from_array(&env, &2u32.to_be_bytes()));
+    let derived: BytesN<32> = env.crypto().sha256(&preimage).into();
+    assert_eq!(seed, derived);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.bullet_position, seed.to_array()[0] as u32 % game.num_chambers);
+
+    // Hidden sessions keep the seed to themselves
+    client.entrar_a_la_ruleta(&7, &p1, &100, &0);
+    let mut config = client.get_session_config(&7);
+    config.mode = MODE_HIDDEN;
+    client.configurar_partida(&7, &p1, &config);
+    client.entrar_a_la_ruleta(&7, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&7, &p3, &100, &2);
+    load_revolver(&env, &client, 7, &p1, 1);
+    client.disparar(&7, &p1, &proof);
+    client.disparar(&7, &p2, &proof);
+    assert_eq!(client.get_round(&7), 1);
+    assert_eq!(client.try_get_current_seed(&7), Err(Ok(Error::WrongPhase)));
+
+    // While a hit awaits confirmation, the seed still backs the live bullet
+    client.entrar_a_la_ruleta(&8, &p1, &100, &0);
+    let mut config = client.get_session_config(&8);
+    config.elimination_delay_ledgers = 2;
+    client.configurar_partida(&8, &p1, &config);
+    client.entrar_a_la_ruleta(&8, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&8, &p3, &100, &2);
+    load_revolver(&env, &client, 8, &p1, 0);
+    client.disparar(&8, &p1, &proof);
+    env.ledger().with_mut(|l| l.sequence_number += 2);
+    client.confirmar_eliminacion(&8);
+    let seed = client.get_current_seed(&8);
+    loop {
+        let game = client.get_game(&8);
+        if game.pending_elimination.is_some() {
+            break;
+        }
+        let shooter = game.players.get(game.current_turn).unwrap().address;
+        client.disparar(&8, &shooter, &proof);
+    }
+    assert_eq!(client.get_current_seed(&8), seed);
+    let game = client.get_game(&8);
+    assert_eq!(game.bullet_position, seed.to_array()[0] as u32 % game.num_chambers);
+}
+
+// ============================================================================
+// Test: Prize ladder pays by finishing order
+// ============================================================================
+#[test]
+fn test_prize_ladder() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let token_id = setup_token(&env, &[&p1, &p2, &p3], 1_000);
+    let token = TokenClient::new(&env, &token_id);
+    let proof = Bytes::from_array(&env, &[0xD9; 32]);
+
+    let mut config = client.get_config();
+    config.stake_tokens = soroban_sdk::vec![&env, token_id.clone()];
+    client.set_config(&config);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut session = client.get_session_config(&session_id);
+    session.prize_ladder = soroban_sdk::vec![&env, 6_000, 3_000, 500];
+    assert_eq!(
+        client.try_configurar_partida(&session_id, &p1, &session),
+        Err(Ok(Error::InvalidConfig))
+    );
+    session.prize_ladder = soroban_sdk::vec![&env, 6_000, 3_000, 1_000];
+    client.configurar_partida(&session_id, &p1, &session);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+    for p in [&p1, &p2, &p3] {
+        client.apostar(&session_id, p, &token_id, &100);
+    }
+
+    // P2 goes out first, then P1; P3 wins
+    load_revolver(&env, &client, session_id, &p1, 1);
+    play_to_end(&client, session_id, &proof);
+    assert_eq!(
+        client.get_game(&session_id).eliminated,
+        soroban_sdk::vec![&env, p2.clone(), p1.clone()]
+    );
+
+    assert_eq!(token.balance(&p3), 900 + 180);
+    assert_eq!(token.balance(&p1), 900 + 90);
+    assert_eq!(token.balance(&p2), 900 + 30);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+// ============================================================================
+// Test: parse_game round-trips a serialized game and rejects other shapes
+// ============================================================================
+#[test]
+fn test_parse_game() {
+    use soroban_sdk::xdr::ToXdr;
+
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 7;
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    load_revolver(&env, &client, session_id, &p1, 3);
+    client.disparar(&session_id, &p1, &Bytes::from_array(&env, &[0xDA; 32]));
+
+    let game = client.get_game(&session_id);
+    let blob = game.clone().to_xdr(&env);
+    assert_eq!(client.parse_game(&blob), game);
+
+    let stats = client.get_player_stats(&p1).to_xdr(&env);
+    assert_eq!(client.try_parse_game(&stats), Err(Ok(Error::UnknownSchema)));
+
+    // A game saved before `insured` existed on players
+    let game_val: Val = game.into_val(&env);
+    let mut old_game = Map::<Symbol, Val>::try_from_val(&env, &game_val).unwrap();
+    let players_key = Symbol::new(&env, "players");
+    let players = Vec::<Val>::try_from_val(&env, &old_game.get(players_key.clone()).unwrap());
+    let mut old_player =
+        Map::<Symbol, Val>::try_from_val(&env, &players.unwrap().get(0).unwrap()).unwrap();
+    old_player.remove(Symbol::new(&env, "insured"));
+    old_game.set(players_key, soroban_sdk::vec![&env, old_player.to_val()].to_val());
+    let old_blob = old_game.to_xdr(&env);
+    assert_eq!(client.try_parse_game(&old_blob), Err(Ok(Error::UnknownSchema)));
+}
+
+// ============================================================================
+// Test: A player who skips the ante is eliminated when the round ends
+// ============================================================================
+#[test]
+fn test_ante_defaulter_eliminated() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let token_id = setup_token(&env, &[&p1, &p2, &p3], 1_000);
+    let token = TokenClient::new(&env, &token_id);
+    let proof = Bytes::from_array(&env, &[0xDB; 32]);
+
+    let mut config = client.get_config();
+    config.stake_tokens = soroban_sdk::vec![&env, token_id.clone()];
+    config.bond_token = Some(token_id.clone());
+    config.join_bond = 50;
+    client.set_config(&config);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut session = client.get_session_config(&session_id);
+    session.ante_per_round = 10;
+    session.ante_token = Some(token_id.clone());
+    client.configurar_partida(&session_id, &p1, &session);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+    for p in [&p1, &p2, &p3] {
+        client.apostar(&session_id, p, &token_id, &100);
+    }
+    load_revolver(&env, &client, session_id, &p1, 1);
+
+    // P3 never pays; P2 is hit and the round ends
+    assert_eq!(client.pagar_ante(&session_id, &p1, &10), 10);
+    assert_eq!(client.pagar_ante(&session_id, &p2, &10), 10);
+    client.disparar(&session_id, &p1, &proof);
+    client.disparar(&session_id, &p2, &proof);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, PHASE_FINISHED);
+    assert!(!game.players.get(2).unwrap().is_alive);
+    assert_eq!(game.eliminated, soroban_sdk::vec![&env, p2.clone(), p3.clone()]);
+    assert_eq!(game.winner, Some(p1.clone()));
+
+    // P3's stake went to the winner with everything else, but their bond
+    // came back like any eliminated player's
+    assert_eq!(token.balance(&p1), 1_000 - 110 + 320);
+    assert_eq!(token.balance(&p3), 900);
+    assert_eq!(client.get_fee_balance(&token_id), 0);
+}
+
+// ============================================================================
+// Test: Liabilities match what the contract actually holds
+// ============================================================================
+#[test]
+fn test_total_liabilities() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let usdc_id = setup_token(&env, &[&p1, &p2, &p3], 1_000);
+    let xlm_id = setup_token(&env, &[&p1, &p2, &p3], 1_000);
+    let usdc = TokenClient::new(&env, &usdc_id);
+    let xlm = TokenClient::new(&env, &xlm_id);
+    let proof = Bytes::from_array(&env, &[0xDC; 32]);
+
+    let mut config = client.get_config();
+    config.bond_token = Some(usdc_id.clone());
+    config.join_bond = 50;
+    config.stake_tokens = soroban_sdk::vec![&env, usdc_id.clone(), xlm_id.clone()];
+    config.insurance_price = 5;
+    client.set_config(&config);
+
+    // Session 7 stays in play; session 8 finishes and pays out
+    join_all_players(&env, &client, 7, &p1, &p2, &p3);
+    client.apostar(&7, &p1, &usdc_id, &30);
+    client.apostar(&7, &p2, &xlm_id, &40);
+    load_revolver(&env, &client, 7, &p1, 3);
+    client.comprar_seguro(&7, &p3, &xlm_id, &5);
+
+    join_two_players(&env, &client, 8, &p1, &p2);
+    client.apostar(&8, &p1, &xlm_id, &20);
+    load_revolver(&env, &client, 8, &p1, 1);
+    client.disparar(&8, &p1, &proof);
+
+    let check = |expected_usdc: i128, expected_xlm: i128| {
+        let owed = client.total_liabilities();
+        assert_eq!(owed.get(usdc_id.clone()).unwrap_or(0), usdc.balance(&contract_id));
+        assert_eq!(owed.get(xlm_id.clone()).unwrap_or(0), xlm.balance(&contract_id));
+        assert_eq!(usdc.balance(&contract_id), expected_usdc);
+        assert_eq!(xlm.balance(&contract_id), expected_xlm);
+    };
+    // Bonds 5 × 50 and stake 30 in USDC; stakes 40 + 20 and premium 5 in XLM
+    check(280, 65);
+
+    client.disparar(&8, &p2, &proof);
+    check(180, 45);
+}
+
+// ============================================================================
+// Test: Stray tokens can be recovered, escrow can't
+// ============================================================================
+#[test]
+fn test_recuperar_token_surplus_only() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let token_id = setup_token(&env, &[&p1, &p2, &p3], 1_000);
+    let token = TokenClient::new(&env, &token_id);
+    let treasury = Address::generate(&env);
+
+    let mut config = client.get_config();
+    config.stake_tokens = soroban_sdk::vec![&env, token_id.clone()];
+    client.set_config(&config);
+
+    join_two_players(&env, &client, 79, &p1, &p2);
+    client.apostar(&79, &p1, &token_id, &100);
+    load_revolver(&env, &client, 79, &p1, 3);
+
+    // P3 sends 30 by mistake: only those can leave
+    token.transfer(&p3, &contract_id, &30);
+    assert_eq!(
+        client.try_recuperar_token(&token_id, &treasury, &31),
+        Err(Ok(Error::ExceedsRecoverable))
+    );
+    assert_eq!(
+        client.try_recuperar_token(&token_id, &treasury, &0),
+        Err(Ok(Error::InvalidStake))
+    );
+    client.recuperar_token(&token_id, &treasury, &30);
+    assert_eq!(token.balance(&treasury), 30);
+    assert_eq!(token.balance(&contract_id), 100);
+    assert_eq!(
+        client.try_recuperar_token(&token_id, &treasury, &1),
+        Err(Ok(Error::ExceedsRecoverable))
+    );
+}
+
+// ============================================================================
+// Test: Buy-back refunds part of an eliminated player's stake
+// ============================================================================
+#[test]
+fn test_loser_refund_bps() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 80;
+    let token_id = setup_token(&env, &[&p1, &p2], 1_000);
+    let token = TokenClient::new(&env, &token_id);
+    let proof = Bytes::from_array(&env, &[0xE0; 32]);
+
+    let mut config = client.get_config();
+    config.stake_tokens = soroban_sdk::vec![&env, token_id.clone()];
+    client.set_config(&config);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut session = client.get_game(&session_id).config;
+    session.loser_refund_bps = 10_000;
+    assert_eq!(
+        client.try_configurar_partida(&session_id, &p1, &session),
+        Err(Ok(Error::InvalidConfig))
+    );
+    session.loser_refund_bps = 2_500;
+    client.configurar_partida(&session_id, &p1, &session);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.apostar(&session_id, &p1, &token_id, &100);
+    client.apostar(&session_id, &p2, &token_id, &100);
+    load_revolver(&env, &client, session_id, &p1, 1);
+
+    // P2 falls: a quarter of their 100 comes straight back
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+    assert_eq!(token.balance(&p2), 925);
+    assert_eq!(token.balance(&p1), 1_075);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+// ============================================================================
+// Test: A tactical timeout extends the deadline once
+// ============================================================================
+#[test]
+fn test_tactical_timeout() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 7;
+
+    let mut config = client.get_config();
+    config.turn_timeout_ledgers = 10;
+    client.set_config(&config);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut session = client.get_session_config(&session_id);
+    session.timeouts_per_player = 1;
+    session.timeout_extension_ledgers = 20;
+    client.configurar_partida(&session_id, &p1, &session);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+
+    env.ledger().set_sequence_number(100);
+    load_revolver(&env, &client, session_id, &p1, 3);
+    assert_eq!(client.get_game(&session_id).turn_deadline_ledger, 110);
+
+    // Only the shooter may ask, and only once
+    assert_eq!(client.try_pedir_tiempo(&session_id, &p2), Err(Ok(Error::NotYourTurn)));
+    assert_eq!(client.pedir_tiempo(&session_id, &p1), 0);
+    assert_eq!(client.get_game(&session_id).turn_deadline_ledger, 130);
+    assert_eq!(client.try_pedir_tiempo(&session_id, &p1), Err(Ok(Error::NoTimeoutsLeft)));
+
+    env.ledger().set_sequence_number(120);
+    assert_eq!(client.try_expulsar_por_afk(&session_id), Err(Ok(Error::TurnNotExpired)));
+}
+
+// ============================================================================
+// Test: Nonce binding makes a reused commitment load different cylinders
+// ============================================================================
+#[test]
+fn test_session_nonce_binding() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let salt = BytesN::from_array(&env, &[42u8; 32]);
+    let proof = Bytes::from_array(&env, &[0xDD; 32]);
+
+    let mut histories = std::vec::Vec::new();
+    for session_id in [7u32, 8] {
+        client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+        let mut session = client.get_session_config(&session_id);
+        session.bind_session_nonce = true;
+        client.configurar_partida(&session_id, &p1, &session);
+        client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+        client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+        // Same salt and position, so the same commitment
+        load_revolver(&env, &client, session_id, &p1, 2);
+        play_to_end(&client, session_id, &proof);
+
+        assert!(client.auditar_partida(&session_id, &salt));
+        client.revelar_bala(&session_id, &p1, &salt);
+        let fairness = client.get_fairness_proof(&session_id);
+        assert!(fairness.sha_matches);
+        assert_eq!(fairness.bullet_position, 2);
+        histories.push(client.get_game(&session_id).bullet_history);
+    }
+
+    assert_ne!(histories[0].get(0), histories[1].get(0));
+    assert_ne!(histories[0], histories[1]);
+}
+
+// ============================================================================
+// Test: Cylinder state mid-round, with the bullet hidden in competitive mode
+// ============================================================================
+#[test]
+fn test_get_cylinder_state() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let proof = Bytes::from_array(&env, &[0xDE; 32]);
+
+    join_all_players(&env, &client, 7, &p1, &p2, &p3);
+    load_revolver(&env, &client, 7, &p1, 3);
+    client.disparar(&7, &p1, &proof);
+    client.disparar(&7, &p2, &proof);
+    assert_eq!(
+        client.get_cylinder_state(&7),
+        CylinderState {
+            num_chambers: NUM_CHAMBERS,
+            current_chamber: 2,
+            fired_chambers: soroban_sdk::vec![&env, 0, 1],
+            bullet_visible: Some(3),
+        }
+    );
+
+    client.entrar_a_la_ruleta(&8, &p1, &100, &0);
+    let mut session = client.get_session_config(&8);
+    session.mode = MODE_HIDDEN;
+    client.configurar_partida(&8, &p1, &session);
+    client.entrar_a_la_ruleta(&8, &p2, &100, &1);
+    load_revolver(&env, &client, 8, &p1, 3);
+    client.disparar(&8, &p1, &proof);
+    let hidden = client.get_cylinder_state(&8);
+    assert_eq!(hidden.fired_chambers, soroban_sdk::vec![&env, 0]);
+    assert_eq!(hidden.bullet_visible, None);
+
+    assert_eq!(client.try_get_cylinder_state(&9), Err(Ok(Error::GameNotFound)));
+}
+
+// ============================================================================
+// Test: 2v2 ends when a whole team is out, and the winners split the pot
+// ============================================================================
+#[test]
+fn test_team_mode_two_vs_two() {
+    let (env, contract_id, p1, p2, p3, hub_id) = setup_recording_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let hub = recording_hub::RecordingHubClient::new(&env, &hub_id);
+    let session_id: u32 = 96;
+    let p4 = Address::generate(&env);
+    let token_id = setup_token(&env, &[&p1, &p2, &p3, &p4], 1_000);
+    let token = TokenClient::new(&env, &token_id);
+    let proof = Bytes::from_array(&env, &[0xDF; 32]);
+
+    let mut config = client.get_config();
+    config.stake_tokens = soroban_sdk::vec![&env, token_id.clone()];
+    client.set_config(&config);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut session = client.get_session_config(&session_id);
+    session.team_mode = true;
+    client.configurar_partida(&session_id, &p1, &session);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+    client.entrar_a_la_ruleta(&session_id, &p4, &100, &3);
+    let everyone = [&p1, &p2, &p3, &p4];
+    for p in everyone {
+        client.apostar(&session_id, p, &token_id, &100);
+    }
+
+    // Joiners alternate: P1 and P3 against P2 and P4
+    let game = client.get_game(&session_id);
+    let teams: std::vec::Vec<u32> = game.players.iter().map(|p| p.team).collect();
+    assert_eq!(teams, [0, 1, 0, 1]);
+
+    // P2 falls, but P4 keeps their team in it
+    load_revolver(&env, &client, session_id, &p1, 1);
+    client.disparar(&session_id, &p1, &proof);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+    assert_eq!(client.get_game(&session_id).phase, PHASE_PLAYING);
+
+    play_to_end(&client, session_id, &proof);
+    let game = client.get_game(&session_id);
+    let winner = game.winner.clone().unwrap();
+    let winning_team = game.players.iter().find(|p| p.address == winner).unwrap().team;
+    for p in game.players.iter() {
+        if p.team == winning_team {
+            assert_eq!(token.balance(&p.address), 900 + 200);
+        } else {
+            assert!(!p.is_alive);
+            assert_eq!(token.balance(&p.address), 900);
+        }
+    }
+    assert_eq!(token.balance(&contract_id), 0);
+
+    // The hub hears whether P1's team won, whoever landed the last shot
+    let (hub_p1, _, _, _) = hub.started(&session_id).unwrap();
+    let p1_team = game.players.iter().find(|p| p.address == hub_p1).unwrap().team;
+    assert_eq!(hub.ended(&session_id), Some(p1_team == winning_team));
+}
+
+// ============================================================================
+// Test: The Game Hub can veto a session before it starts
+// ============================================================================
+#[test]
+fn test_hub_can_veto_start() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let game_hub = env.register(veto_hub::VetoHub, ());
+    let contract_id = env.register(ZkMafiaContract, (&admin, &game_hub));
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let p1 = Address::generate(&env);
+    let p2 = Address::generate(&env);
+    let session_id: u32 = 97;
+    let salt = BytesN::from_array(&env, &[42u8; 32]);
+    let commitment = client.compute_bullet_hash(&salt, &1);
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    assert_eq!(
+        client.try_cargar_revolver(&session_id, &p1, &commitment, &1, &salt, &None, &false),
+        Err(Ok(Error::HubRejected))
+    );
+    assert_eq!(client.get_game(&session_id).phase, PHASE_WAITING);
+}
+
+// ============================================================================
+// Test: The bullet can only be revealed once the audit window has passed
+// ============================================================================
+#[test]
+fn test_bullet_reveal_waits_for_audit_window() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 98;
+    let proof = Bytes::from_array(&env, &[0xD7; 32]);
+    let salt = BytesN::from_array(&env, &[42u8; 32]);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    config.reveal_after_ledgers = 5;
+    client.configurar_partida(&session_id, &p1, &config);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    load_revolver(&env, &client, session_id, &p1, 0);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_HIT);
+    assert_eq!(client.get_game(&session_id).phase, PHASE_FINISHED);
+
+    assert_eq!(
+        client.try_revelar_bala(&session_id, &p1, &salt),
+        Err(Ok(Error::RevealTooEarly))
+    );
+    env.ledger().with_mut(|l| l.sequence_number += 4);
+    assert_eq!(
+        client.try_revelar_bala(&session_id, &p1, &salt),
+        Err(Ok(Error::RevealTooEarly))
+    );
+    assert_eq!(client.get_game(&session_id).bullet_salt, None);
+
+    env.ledger().with_mut(|l| l.sequence_number += 1);
+    client.revelar_bala(&session_id, &p1, &salt);
+    assert_eq!(client.get_game(&session_id).bullet_salt, Some(salt));
+}
+
+// ============================================================================
+// Test: get_host follows the first seat when the host leaves
+// ============================================================================
+#[test]
+fn test_get_host() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 99;
+
+    assert_eq!(client.try_get_host(&session_id), Err(Ok(Error::GameNotFound)));
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    assert_eq!(client.get_host(&session_id), p1);
+
+    client.salir_de_la_ruleta(&session_id, &p1);
+    assert_eq!(client.get_host(&session_id), p2);
+    load_revolver(&env, &client, session_id, &p2, 1);
+    assert_eq!(client.get_host(&session_id), p2);
+}
+
+// ============================================================================
+// Test: The hub hears about every eliminated player, not just the pair
+// ============================================================================
+#[test]
+fn test_hub_receives_all_eliminations() {
+    let (env, contract_id, p1, p2, p3, hub_id) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xD8; 32]);
+
+    // Mirrors the mock hub's event
+    #[contractevent]
+    struct EliminationsReported {
+        session_id: u32,
+        eliminated: soroban_sdk::Vec<Address>,
+    }
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    // Same script as the third-player hub test: P2 is hit, then P1
+    load_revolver(&env, &client, session_id, &p1, 1);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+    assert_eq!(client.disparar(&session_id, &p3, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p3, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_HIT);
+
+    let reported = EliminationsReported {
+        session_id,
+        eliminated: soroban_sdk::vec![&env, p2, p1],
+    };
+    let hub_events = env.events().all().filter_by_contract(&hub_id);
+    assert_eq!(hub_events.events().last(), Some(&reported.to_xdr(&env, &hub_id)));
+}
+
+// ============================================================================
+// Test: Session 0 is reserved and rejected by the game entry points
+// ============================================================================
+#[test]
+fn test_session_zero_rejected() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let salt = BytesN::from_array(&env, &[42u8; 32]);
+    let commitment = client.compute_bullet_hash(&salt, &1);
+    let proof = Bytes::from_array(&env, &[0xD9; 32]);
+
+    assert_eq!(
+        client.try_entrar_a_la_ruleta(&0, &p1, &100, &0),
+        Err(Ok(Error::InvalidSession))
+    );
+    assert_eq!(
+        client.try_cargar_revolver(&0, &p1, &commitment, &1, &salt, &None, &false),
+        Err(Ok(Error::InvalidSession))
+    );
+    assert_eq!(
+        client.try_disparar(&0, &p1, &proof),
+        Err(Ok(Error::InvalidSession))
+    );
+    assert!(!client.session_exists(&0));
+
+    // Any other id still works
+    join_two_players(&env, &client, 1, &p1, &p2);
+    load_revolver(&env, &client, 1, &p1, 1);
+    assert_eq!(client.disparar(&1, &p1, &proof), SHOT_SURVIVED);
+}
+
+// ============================================================================
+// Test: get_shots_fired counts every shot, the fatal one included
+// ============================================================================
+#[test]
+fn test_get_shots_fired() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 100;
+    let proof = Bytes::from_array(&env, &[0xDA; 32]);
+
+    assert_eq!(client.try_get_shots_fired(&session_id), Err(Ok(Error::GameNotFound)));
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    assert_eq!(client.get_shots_fired(&session_id), 0);
+
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.get_shots_fired(&session_id), 1);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+    assert_eq!(client.get_shots_fired(&session_id), 2);
+    assert_eq!(client.get_game(&session_id).shots_fired, 2);
+}
+
+// ============================================================================
+// Test: Commitment tracking is bounded and forgets the oldest entry
+// ============================================================================
+#[test]
+fn test_commitment_tracking_evicts_oldest() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let salt_for = |i: u32| BytesN::from_array(&env, &[(i as u8).wrapping_add(100); 32]);
+
+    let mut config = client.get_config();
+    config.reject_reused_commitments = true;
+    client.set_config(&config);
+
+    let hidden_lobby = |session_id: u32| {
+        client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+        let mut session = client.get_game(&session_id).config;
+        session.mode = MODE_HIDDEN;
+        client.configurar_partida(&session_id, &p1, &session);
+        client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    };
+
+    // The first commitment is refused while it is tracked...
+    let first = client.compute_bullet_hash(&salt_for(0), &1);
+    hidden_lobby(1);
+    client.cargar_revolver(&1, &p1, &first, &1, &salt_for(0), &None, &false);
+    hidden_lobby(2);
+    assert_eq!(
+        client.try_cargar_revolver(&2, &p1, &first, &1, &salt_for(0), &None, &false),
+        Err(Ok(Error::CommitmentReused))
+    );
+
+    // ...and forgotten once MAX_TRACKED_COMMITMENTS newer ones are loaded
+    for i in 1..=64u32 {
+        let session_id = 1000 + i;
+        join_two_players(&env, &client, session_id, &p1, &p2);
+        let commitment = client.compute_bullet_hash(&salt_for(i), &1);
+        client.cargar_revolver(&session_id, &p1, &commitment, &1, &salt_for(i), &None, &false);
+    }
+    assert!(client.commitment_is_fresh(&first));
+    assert!(!client.commitment_is_fresh(&client.compute_bullet_hash(&salt_for(1), &1)));
+    client.cargar_revolver(&2, &p1, &first, &1, &salt_for(0), &None, &false);
+}
+
+// ============================================================================
+// Test: Each join publishes the new player's seat
+// ============================================================================
+#[test]
+fn test_player_joined_event() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 101;
+
+    for (i, player) in [&p1, &p2, &p3].into_iter().enumerate() {
+        let before = if client.session_exists(&session_id) {
+            client.get_game(&session_id).players.len()
+        } else {
+            0
+        };
+        client.entrar_a_la_ruleta(&session_id, player, &100, &(i as u32));
+        let joined = PlayerJoined {
+            session_id,
+            player: player.clone(),
+            seat_index: before,
+            total_players: before + 1,
+        };
+        let events = env.events().all().filter_by_contract(&contract_id);
+        assert_eq!(events.events().last(), Some(&joined.to_xdr(&env, &contract_id)));
+        assert_eq!(client.get_seat(&session_id, player), before);
+    }
+}
+
+// ============================================================================
+// Test: The protocol fee accrues per token and the admin can withdraw it
+// ============================================================================
+#[test]
+fn test_protocol_fee_balance_and_withdrawal() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let token_id = setup_token(&env, &[&p1, &p2], 1_000);
+    let token = TokenClient::new(&env, &token_id);
+    let treasury = Address::generate(&env);
+    let session_id: u32 = 102;
+    let proof = Bytes::from_array(&env, &[0xDB; 32]);
+
+    let mut config = client.get_config();
+    config.stake_tokens = soroban_sdk::vec![&env, token_id.clone()];
+    config.protocol_fee_bps = 10_000;
+    assert_eq!(client.try_set_config(&config), Err(Ok(Error::InvalidConfig)));
+    config.protocol_fee_bps = 250;
+    client.set_config(&config);
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    client.apostar(&session_id, &p1, &token_id, &200);
+    client.apostar(&session_id, &p2, &token_id, &200);
+    assert_eq!(client.get_fee_balance(&token_id), 0);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+
+    // 2.5% of the 400 pot stays behind as fees
+    assert_eq!(client.get_fee_balance(&token_id), 10);
+    assert_eq!(token.balance(&p1), 800 + 390);
+    assert_eq!(token.balance(&contract_id), 10);
+    assert_eq!(
+        client.try_recuperar_token(&token_id, &treasury, &1),
+        Err(Ok(Error::ExceedsRecoverable))
+    );
+
+    assert_eq!(client.withdraw_fees(&token_id, &treasury), 10);
+    assert_eq!(token.balance(&treasury), 10);
+    assert_eq!(client.get_fee_balance(&token_id), 0);
+    assert_eq!(
+        client.try_withdraw_fees(&token_id, &treasury),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+// ============================================================================
+// Test: Loading never hands the first turn to a dead seat
+// ============================================================================
+#[test]
+fn test_load_skips_dead_first_seat() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let proof = Bytes::from_array(&env, &[0xDC; 32]);
+    let kill_host = |session_id: u32| {
+        env.as_contract(&contract_id, || {
+            let mut game = ZkMafiaContract::load_game(&env, session_id).unwrap();
+            let mut host = game.players.get(0).unwrap();
+            host.is_alive = false;
+            game.players.set(0, host);
+            ZkMafiaContract::save_game(&env, &mut game);
+        });
+    };
+
+    join_all_players(&env, &client, 103, &p1, &p2, &p3);
+    kill_host(103);
+    load_revolver(&env, &client, 103, &p1, 4);
+    assert_eq!(client.get_game(&103).current_turn, 1);
+    assert_eq!(
+        client.try_disparar(&103, &p1, &proof),
+        Err(Ok(Error::NotYourTurn))
+    );
+    assert_eq!(client.disparar(&103, &p2, &proof), SHOT_SURVIVED);
+
+    // With only one living player there is no game to start
+    join_two_players(&env, &client, 104, &p1, &p2);
+    kill_host(104);
+    let salt = BytesN::from_array(&env, &[42u8; 32]);
+    let commitment = client.compute_bullet_hash(&salt, &1);
+    assert_eq!(
+        client.try_cargar_revolver(&104, &p1, &commitment, &1, &salt, &None, &false),
+        Err(Ok(Error::NotEnoughPlayers))
+    );
+}
+
+// ============================================================================
+// Test: bullets_per_round loads that many distinct chambers on reload
+// ============================================================================
+#[test]
+fn test_bullets_per_round() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xDD; 32]);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    config.bullets_per_round = NUM_CHAMBERS;
+    assert_eq!(
+        client.try_configurar_partida(&session_id, &p1, &config),
+        Err(Ok(Error::InvalidConfig))
+    );
+    config.bullets_per_round = 2;
+    client.configurar_partida(&session_id, &p1, &config);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+
+    // The host's cylinder still holds one bullet: P1 clicks, P2 is hit
+    load_revolver(&env, &client, session_id, &p1, 1);
+    assert_eq!(client.get_game(&session_id).num_bullets, 1);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.num_bullets, 2);
+    let loaded = env.as_contract(&contract_id, || {
+        let arr = ZkMafiaContract::reload_hash(&env, session_id, game.shots_fired);
+        ZkMafiaContract::reload_bullets(&arr, 0, game.num_chambers, 2)
+    });
+    assert_eq!(loaded.count_ones(), 2);
+    assert!(loaded < 1 << game.num_chambers);
+    assert_eq!(game.bullet_position, loaded.trailing_zeros());
+    assert_eq!(game.fatal_chambers.get(1), Some(loaded));
+
+    // Round 1 is still live; round 0 reports the host's single bullet
+    assert_eq!(
+        client.try_get_fatal_chambers(&session_id, &1),
+        Err(Ok(Error::WrongPhase))
+    );
+    assert_eq!(client.get_fatal_chambers(&session_id, &0), soroban_sdk::vec![&env, 1]);
+
+    play_to_end(&client, session_id, &proof);
+    let fatal = client.get_fatal_chambers(&session_id, &1);
+    assert_eq!(fatal.len(), 2);
+    assert_ne!(fatal.get(0), fatal.get(1));
+    for chamber in fatal.iter() {
+        assert!(loaded & (1 << chamber) != 0);
+    }
+}
+
+// ============================================================================
+// Test: get_version reports the compiled-in release number
+// ============================================================================
+#[test]
+fn test_get_version() {
+    let (env, contract_id, _p1, _p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_version(), CONTRACT_VERSION);
+    assert_eq!(client.get_version(), 1);
+}
+
+// ============================================================================
+// Test: Open lobbies are listed until they fill, start or empty
+// ============================================================================
+#[test]
+fn test_open_lobbies() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_open_lobbies(), soroban_sdk::vec![&env]);
+    client.entrar_a_la_ruleta(&105, &p1, &100, &0);
+    client.entrar_a_la_ruleta(&106, &p2, &100, &1);
+    assert_eq!(client.get_open_lobbies(), soroban_sdk::vec![&env, 105, 106]);
+
+    // Starting removes it
+    client.entrar_a_la_ruleta(&105, &p3, &100, &2);
+    load_revolver(&env, &client, 105, &p1, 1);
+    assert_eq!(client.get_open_lobbies(), soroban_sdk::vec![&env, 106]);
+
+    // Filling removes it, a free seat lists it again, emptying removes it
+    client.entrar_a_la_ruleta(&106, &p1, &100, &0);
+    client.entrar_a_la_ruleta(&106, &p3, &100, &2);
+    assert_eq!(client.get_open_lobbies(), soroban_sdk::vec![&env]);
+    client.salir_de_la_ruleta(&106, &p3);
+    assert_eq!(client.get_open_lobbies(), soroban_sdk::vec![&env, 106]);
+    client.salir_de_la_ruleta(&106, &p1);
+    client.salir_de_la_ruleta(&106, &p2);
+    assert_eq!(client.get_open_lobbies(), soroban_sdk::vec![&env]);
+}
+
+// ============================================================================
+// Test: A lone survivor or team in play can't be made to shoot
+// ============================================================================
+#[test]
+fn test_disparar_refuses_lone_side() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 107;
+    let proof = Bytes::from_array(&env, &[0xDE; 32]);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 0);
+    let set_alive = |alive: [bool; 3], team_mode: bool| {
+        env.as_contract(&contract_id, || {
+            let mut game = ZkMafiaContract::load_game(&env, session_id).unwrap();
+            game.config.team_mode = team_mode;
+            for seat in 0..3 {
+                let mut p = game.players.get(seat).unwrap();
+                p.is_alive = alive[seat as usize];
+                p.team = seat % 2;
+                game.players.set(seat, p);
+            }
+            ZkMafiaContract::save_game(&env, &mut game);
+        });
+    };
+
+    // The bullet is in P1's chamber, but no shot is fired
+    set_alive([true, false, false], false);
+    assert_eq!(
+        client.try_disparar(&session_id, &p1, &proof),
+        Err(Ok(Error::GameAlreadyEnded))
+    );
+
+    // P1 and P3 share a team, so in team mode they are one side
+    set_alive([true, false, true], true);
+    assert_eq!(
+        client.try_disparar(&session_id, &p1, &proof),
+        Err(Ok(Error::GameAlreadyEnded))
+    );
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, PHASE_PLAYING);
+    assert_eq!(game.shots_fired, 0);
+}
+
+// ============================================================================
+// Test: The revealed salt is only served after revelar_bala
+// ============================================================================
+#[test]
+fn test_get_revealed_salt() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 108;
+    let proof = Bytes::from_array(&env, &[0xDF; 32]);
+    let salt = BytesN::from_array(&env, &[42u8; 32]);
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    assert_eq!(client.try_get_revealed_salt(&session_id), Err(Ok(Error::NotRevealed)));
+    play_to_end(&client, session_id, &proof);
+    assert_eq!(client.try_get_revealed_salt(&session_id), Err(Ok(Error::NotRevealed)));
+
+    client.revelar_bala(&session_id, &p1, &salt);
+    assert_eq!(client.get_revealed_salt(&session_id), salt);
+}
+
+// ============================================================================
+// Test: A counter at its limit errors cleanly instead of wrapping
+// ============================================================================
+#[test]
+fn test_shot_counter_overflow() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 109;
+    let proof = Bytes::from_array(&env, &[0xE0; 32]);
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    load_revolver(&env, &client, session_id, &p1, 3);
+    env.as_contract(&contract_id, || {
+        let mut game = ZkMafiaContract::load_game(&env, session_id).unwrap();
+        game.shots_fired = u32::MAX;
+        ZkMafiaContract::save_game(&env, &mut game);
+    });
+
+    assert_eq!(
+        client.try_disparar(&session_id, &p1, &proof),
+        Err(Ok(Error::Overflow))
+    );
+    let game = client.get_game(&session_id);
+    assert_eq!(game.shots_fired, u32::MAX);
+    assert_eq!(game.current_chamber, 0);
+
+    // The round counter is checked too when a hit reloads the cylinder
+    join_all_players(&env, &client, session_id + 1, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id + 1, &p1, 0);
+    env.as_contract(&contract_id, || {
+        let mut game = ZkMafiaContract::load_game(&env, session_id + 1).unwrap();
+        game.round = u32::MAX;
+        ZkMafiaContract::save_game(&env, &mut game);
+    });
+    assert_eq!(
+        client.try_disparar(&(session_id + 1), &p1, &proof),
+        Err(Ok(Error::Overflow))
+    );
+    assert_eq!(client.get_game(&(session_id + 1)).round, u32::MAX);
+}
+
+// ============================================================================
+// Test: The active-session index is paged and sheds expired sessions
+// ============================================================================
+#[test]
+fn test_session_index_pages_and_prunes() {
+    let (env, contract_id, p1, _p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let pages = || {
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .get::<_, u32>(&DataKey::SessionPages)
+                .unwrap_or(0)
+        })
+    };
+    let indexed = |session_id: u32| {
+        env.as_contract(&contract_id, || {
+            env.storage().persistent().has(&DataKey::SessionPageOf(session_id))
+        })
+    };
+
+    for session_id in 1..=SESSION_PAGE_SIZE {
+        client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    }
+    assert_eq!(pages(), 1);
+
+    // Session 1's lobby expires; the next session takes its slot
+    env.as_contract(&contract_id, || {
+        env.storage().temporary().remove(&DataKey::GameStatic(1));
+        env.storage().temporary().remove(&DataKey::GameDynamic(1));
+    });
+    client.entrar_a_la_ruleta(&(SESSION_PAGE_SIZE + 1), &p1, &100, &0);
+    assert_eq!(pages(), 1);
+    assert!(!indexed(1));
+
+    // A full page of live sessions starts a new page
+    client.entrar_a_la_ruleta(&(SESSION_PAGE_SIZE + 2), &p1, &100, &0);
+    assert_eq!(pages(), 2);
+    assert!(indexed(SESSION_PAGE_SIZE + 2));
+
+    // Leaving unindexes the session
+    client.salir_de_la_ruleta(&2, &p1);
+    assert!(!indexed(2));
+}
+
+// ============================================================================
+// Test: A host who stalls the reload can be bypassed after the deadline
+// ============================================================================
+#[test]
+fn test_stalled_reload_falls_back_to_derivation() {
+    let (env, contract_id, p1, p2, p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 96;
+    let proof = Bytes::from_array(&env, &[0xE2; 32]);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut session = client.get_game(&session_id).config;
+    session.bullet_sequence_commitment = Some(BytesN::from_array(&env, &[9u8; 32]));
+    client.configurar_partida(&session_id, &p1, &session);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    client.entrar_a_la_ruleta(&session_id, &p3, &100, &2);
+    load_revolver(&env, &client, session_id, &p1, 1);
+
+    // P2 is hit and the host never reveals the next leaf
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+    let game = client.get_game(&session_id);
+    assert!(game.awaiting_reload);
+    assert_eq!(
+        client.get_pending_actions(&session_id),
+        soroban_sdk::vec![
+            &env,
+            (p1.clone(), ACTION_RELOAD),
+            (p2.clone(), ACTION_NONE),
+            (p3.clone(), ACTION_NONE)
+        ]
+    );
+    assert_eq!(
+        client.try_recargar_por_defecto(&session_id, &p3),
+        Err(Ok(Error::TurnNotExpired))
+    );
+
+    env.ledger().with_mut(|l| l.sequence_number = game.turn_deadline_ledger + 1);
+    assert_eq!(
+        client.try_recargar_por_defecto(&session_id, &p2),
+        Err(Ok(Error::PlayerEliminated))
+    );
+    let position = client.recargar_por_defecto(&session_id, &p3);
+
+    // Same bullet the on-chain derivation loads after two shots
+    let game = client.get_game(&session_id);
+    assert!(!game.awaiting_reload);
+    assert_eq!(game.bullet_position, position);
+    assert_eq!(position, client.compute_reload_position(&session_id, &2, &NUM_CHAMBERS));
+    let shooter = game.players.get(game.current_turn).unwrap().address;
+    client.disparar(&session_id, &shooter, &proof);
+}
+
+// ============================================================================
+// Test: In a 2-player game the opponent alone can't vote the shooter out
+// ============================================================================
+#[test]
+fn test_skip_vote_needs_two_votes_to_eliminate() {
+    let (env, contract_id, p1, p2, _p3, _hub) = setup_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let session_id: u32 = 110;
+    let proof = Bytes::from_array(&env, &[0xE3; 32]);
+
+    client.entrar_a_la_ruleta(&session_id, &p1, &100, &0);
+    let mut config = client.get_game(&session_id).config;
+    config.skip_vote_eliminates = true;
+    client.configurar_partida(&session_id, &p1, &config);
+    client.entrar_a_la_ruleta(&session_id, &p2, &100, &1);
+    load_revolver(&env, &client, session_id, &p1, 3);
+
+    // Once P1 idles the vote opens, untimed game or not, but one vote
+    // is not enough to eliminate
+    env.ledger().with_mut(|l| l.sequence_number += config.skip_vote_idle_ledgers);
+    assert!(!client.votar_saltar(&session_id, &p2));
+    let game = client.get_game(&session_id);
+    assert!(game.players.get(0).unwrap().is_alive);
+    assert_eq!(game.current_turn, 0);
+    assert_eq!(game.skip_votes.len(), 1);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_SURVIVED);
+}
+
+// ============================================================================
+// Test: A hub player still standing outlasts one already eliminated
+// ============================================================================
+#[test]
+fn test_hub_result_when_admin_picks_third_player() {
+    let (env, contract_id, p1, p2, p3, hub_id) = setup_recording_env();
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let hub = recording_hub::RecordingHubClient::new(&env, &hub_id);
+    let session_id: u32 = 111;
+    let proof = Bytes::from_array(&env, &[0xE4; 32]);
+
+    join_all_players(&env, &client, session_id, &p1, &p2, &p3);
+    load_revolver(&env, &client, session_id, &p1, 0);
+    assert_eq!(client.disparar(&session_id, &p1, &proof), SHOT_HIT);
+
+    // P2 is never eliminated, so P1 lost the head-to-head
+    client.resolver_manualmente(&session_id, &p3, &RESOLVE_WINNER_TAKES_ALL);
+    assert_eq!(client.get_game(&session_id).winner, Some(p3));
+    assert_eq!(hub.ended(&session_id), Some(false));
+}
+
+// ============================================================================
+// Test: A hub without the optional entry points still runs games
+// ============================================================================
+#[test]
+fn test_standard_hub_without_can_start() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let game_hub = env.register(standard_hub::StandardHub, ());
+    let contract_id = env.register(ZkMafiaContract, (&admin, &game_hub));
+    let client = ZkMafiaContractClient::new(&env, &contract_id);
+    let p1 = Address::generate(&env);
+    let p2 = Address::generate(&env);
+    let session_id: u32 = 112;
+
+    join_two_players(&env, &client, session_id, &p1, &p2);
+    load_revolver(&env, &client, session_id, &p1, 1);
+    assert_eq!(client.get_game(&session_id).phase, PHASE_PLAYING);
+
+    // Nor does the missing `report_eliminations` trap the final shot
+    let proof = Bytes::from_array(&env, &[0xE5; 32]);
+    client.disparar(&session_id, &p1, &proof);
+    assert_eq!(client.disparar(&session_id, &p2, &proof), SHOT_HIT);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, PHASE_FINISHED);
+    assert_eq!(game.winner, Some(p1));
+}
@@ -6,6 +6,9 @@ import {
   PHASE_WAITING,
   PHASE_PLAYING,
   PHASE_FINISHED,
+  SHOT_HIT,
+  SHOT_PROOF_REJECTED,
+  SHOT_KICKED,
 } from './zkMafiaService';
 import { texts, PHASE_LABELS, getHumorousError } from './gameTexts';
 import { useWallet } from '@/hooks/useWallet';
@@ -292,7 +295,7 @@ export function ZkMafiaGame({ userAddress, onStandingsRefresh, onGameComplete }:
         if (game.players.length < 2) {
           const bot2 = createBotSigner(2);
           setLoaderMsg('El Brayan se sienta a la mesa...');
-          await service.entrarALaRuleta(sessionId, bot2.address, 0n, bot2.signer);
+          await service.entrarALaRuleta(sessionId, bot2.address, 0n, game.players.length, bot2.signer);
           await new Promise(r => setTimeout(r, 1500));
         }
         // Reload game to see bot2
@@ -303,7 +306,7 @@ export function ZkMafiaGame({ userAddress, onStandingsRefresh, onGameComplete }:
         if (botCount === 2 && g && g.players.length < 3) {
           const bot3 = createBotSigner(3);
           setLoaderMsg('El Kevin tambien quiere jugar...');
-          await service.entrarALaRuleta(sessionId, bot3.address, 0n, bot3.signer);
+          await service.entrarALaRuleta(sessionId, bot3.address, 0n, g.players.length, bot3.signer);
           await new Promise(r => setTimeout(r, 1500));
         }
         // Reload game to see all bots
@@ -320,7 +323,7 @@ export function ZkMafiaGame({ userAddress, onStandingsRefresh, onGameComplete }:
           setBulletSalt(salt);
           setBulletPosition(pos);
           const signer = getContractSigner();
-          await service.cargarRevolver(sessionId, userAddress, Buffer.from(commitment), pos, signer);
+          await service.cargarRevolver(sessionId, userAddress, Buffer.from(commitment), pos, Buffer.from(salt), signer);
           await new Promise(r => setTimeout(r, 500));
           g = await service.getGame(sessionId);
           setGame(g);
@@ -393,7 +396,7 @@ export function ZkMafiaGame({ userAddress, onStandingsRefresh, onGameComplete }:
   const handleEntrar = () => {
     runAction(texts.actionEntrar, async () => {
       const signer = getContractSigner();
-      await service.entrarALaRuleta(sessionId, userAddress, 0n, signer);
+      await service.entrarALaRuleta(sessionId, userAddress, 0n, game?.players.length ?? 0, signer);
       setHasJoined(true);
     });
   };
@@ -405,7 +408,7 @@ export function ZkMafiaGame({ userAddress, onStandingsRefresh, onGameComplete }:
       const commitment = await service.computeBulletCommitment(salt, pos);
       setBulletSalt(salt); setBulletPosition(pos);
       const signer = getContractSigner();
-      await service.cargarRevolver(sessionId, userAddress, Buffer.from(commitment), pos, signer);
+      await service.cargarRevolver(sessionId, userAddress, Buffer.from(commitment), pos, Buffer.from(salt), signer);
     });
   };
 
@@ -413,14 +416,13 @@ export function ZkMafiaGame({ userAddress, onStandingsRefresh, onGameComplete }:
     if (!game) return;
     runAction(texts.actionDisparar, async () => {
       setIsSpinning(true);
-      // Read bullet_position from on-chain state (static cylinder)
-      const currentChamber = game!.current_chamber;
-      const bp = game!.bullet_position;
-      const isHit = currentChamber === bp;
       const zkProof = service.generateZkProof();
-      await playTensionSequence(isHit);
       const signer = getContractSigner();
-      await service.disparar(sessionId, userAddress, Buffer.from(zkProof), signer);
+      // The contract decides the shot; a kick eliminates the shooter too
+      const shot = await service.disparar(sessionId, userAddress, Buffer.from(zkProof), signer);
+      if (shot === SHOT_PROOF_REJECTED) throw new Error('Proof rejected');
+      const isHit = shot === SHOT_HIT || shot === SHOT_KICKED;
+      await playTensionSequence(isHit);
       setIsSpinning(false);
       setLastShotResult(isHit ? 'hit' : 'miss');
     });
@@ -433,16 +435,14 @@ export function ZkMafiaGame({ userAddress, onStandingsRefresh, onGameComplete }:
     if (!turnPlayer) return;
     try {
       setIsSpinning(true);
-      // Read bullet_position from on-chain state (static cylinder)
-      const currentChamber = game.current_chamber;
-      const bp = game.bullet_position;
-      const isHit = currentChamber === bp;
       const zkProof = service.generateZkProof();
-      await playTensionSequence(isHit);
       // Use the bot's own signer — determine which bot player number this is
       const botPlayerNum = turnIdx === 1 ? 2 : 3;
       const bot = createBotSigner(botPlayerNum as 2 | 3);
-      await service.disparar(sessionId, bot.address, Buffer.from(zkProof), bot.signer);
+      const shot = await service.disparar(sessionId, bot.address, Buffer.from(zkProof), bot.signer);
+      if (shot === SHOT_PROOF_REJECTED) throw new Error('Proof rejected');
+      const isHit = shot === SHOT_HIT || shot === SHOT_KICKED;
+      await playTensionSequence(isHit);
       setIsSpinning(false); setLastShotResult(isHit ? 'hit' : 'miss'); await loadGame();
     } catch (e: any) { setIsSpinning(false); setError(getHumorousError(e?.message || String(e))); }
   };
@@ -49,35 +49,520 @@ export const Errors = {
   9: {message:"InvalidProof"},
   10: {message:"InvalidChamber"},
   11: {message:"NotEnoughPlayers"},
-  12: {message:"AlreadyStarted"}
+  12: {message:"AlreadyStarted"},
+  13: {message:"InvalidAvatar"},
+  14: {message:"TurnNotExpired"},
+  15: {message:"InvalidConfig"},
+  16: {message:"NotWinner"},
+  17: {message:"HonorUnavailable"},
+  18: {message:"NothingToClaim"},
+  19: {message:"WrongMode"},
+  20: {message:"PendingReveal"},
+  21: {message:"InconsistentState"},
+  22: {message:"TokenNotAllowed"},
+  23: {message:"InvalidStake"},
+  24: {message:"AlreadyRevealed"},
+  25: {message:"DevModeDisabled"},
+  26: {message:"EliminationPending"},
+  27: {message:"NoPendingElimination"},
+  28: {message:"InsuranceUnavailable"},
+  29: {message:"UnknownSchema"},
+  30: {message:"NoTimeoutsLeft"},
+  31: {message:"CommitmentMismatch"},
+  32: {message:"InvalidReloadProof"},
+  33: {message:"AwaitingReload"},
+  34: {message:"UnknownHub"},
+  35: {message:"InvalidPoints"},
+  36: {message:"ExceedsRecoverable"},
+  37: {message:"TooManyActiveGames"},
+  38: {message:"HubRejected"},
+  39: {message:"RevealTooEarly"},
+  40: {message:"AvatarTaken"},
+  41: {message:"UnsupportedProofSystem"},
+  42: {message:"RateLimited"},
+  43: {message:"AlreadyVoted"},
+  44: {message:"RegistrationClosed"},
+  45: {message:"ProofTooLarge"},
+  46: {message:"CommitmentReused"},
+  47: {message:"InvalidSession"},
+  48: {message:"NotRevealed"},
+  49: {message:"Overflow"},
+  51: {message:"PotCapExceeded"}
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+
+/**
+ * Contract-wide policy, set by the admin
+ */
+export interface Config {
+  /**
+ * Token join bonds are posted in (`None` = no bonds)
+ */
+bond_token: Option<string>;
+  /**
+ * Points a winner gains by surviving `tiro_de_honor` (and loses if hit)
+ */
+bravery_bonus: i128;
+  /**
+ * Participation credit added to each loser's `final_points`
+ */
+consolation_points: i128;
+  /**
+ * Enables dev-only tools such as `reset_session`; keep off in production
+ */
+dev_mode: boolean;
+  /**
+ * Widen strong players' danger zone per `handicap_table`
+ */
+handicap: boolean;
+  /**
+ * `(min_win_rate_bps, extra_chambers)` rows: a shooter whose win rate
+ * (in basis points) reaches a row is also hit by the bullet that many
+ * chambers early. The largest matching row applies.
+ */
+handicap_table: Array<readonly [u32, u32]>;
+  /**
+ * Share of the pot, in basis points, a winner hit by `tiro_de_honor`
+ * forfeits to protocol fees (at most 10000)
+ */
+honor_forfeit_bps: u32;
+  /**
+ * Ledgers after the finish during which the winner may take the
+ * honor shot, with the pot held meanwhile (0 = honor shots disabled)
+ */
+honor_window_ledgers: u32;
+  /**
+ * Minimum premium for `comprar_seguro` (0 = insurance not offered)
+ */
+insurance_price: i128;
+  /**
+ * Refundable bond each joiner posts; forfeited to the pot on an AFK kick
+ */
+join_bond: i128;
+  /**
+ * Unfinished sessions one address may be seated in at once (0 =
+ * unlimited)
+ */
+max_active_games: u32;
+  /**
+ * Ceiling on the points each player is registered with at the hub
+ */
+max_hub_points: i128;
+  /**
+ * Joins one address may make per ledger, across all sessions (0 = unlimited)
+ */
+max_joins_per_ledger: u32;
+  /**
+ * Most a session's pot may hold in any one token from stakes and
+ * premiums (`i128::MAX` = unlimited)
+ */
+max_pot: i128;
+  /**
+ * Longest proof `disparar` / `tiro_de_honor` accept, in bytes (0 = unlimited)
+ */
+max_proof_bytes: u32;
+  /**
+ * Protocol fee in basis points, skimmed off each pot before it is
+ * paid out and held for `withdraw_fees` (below 10000)
+ */
+protocol_fee_bps: u32;
+  /**
+ * Track recently loaded commitments and refuse reuse in hidden-mode
+ * sessions
+ */
+reject_reused_commitments: boolean;
+  /**
+ * Tokens players may stake into the pot with `apostar`
+ */
+stake_tokens: Array<string>;
+  /**
+ * Event naming theme (`THEME_*`)
+ */
+theme: u32;
+  /**
+ * Ledgers the current player has to shoot (0 = no turn timeout)
+ */
+turn_timeout_ledgers: u32;
+}
+
+export type DataKey = {tag: "GameStatic", values: readonly [u32]} | {tag: "GameDynamic", values: readonly [u32]} | {tag: "ShotLog", values: readonly [u32]} | {tag: "EliminatedLog", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void} | {tag: "Config", values: void} | {tag: "JoinCount", values: readonly [string, u32]} | {tag: "UsedCommitment", values: readonly [Buffer]} | {tag: "RecentCommitments", values: void} | {tag: "Stats", values: readonly [string]} | {tag: "TokenVolume", values: readonly [string]} | {tag: "FeeBalance", values: readonly [string]} | {tag: "Wins", values: readonly [string]} | {tag: "SessionPage", values: readonly [u32]} | {tag: "SessionPages", values: void} | {tag: "SessionPageOf", values: readonly [u32]} | {tag: "OpenLobbies", values: void} | {tag: "VerifyingKey", values: void} | {tag: "FinishedGame", values: readonly [u32]} | {tag: "ApprovedHubs", values: void} | {tag: "ActiveGames", values: readonly [string]};
 
 
 export interface Jugador {
   address: string;
+  ante_paid: i128;
+  avatar: u32;
+  bond: i128;
+  final_points: i128;
+  insured: boolean;
   is_alive: boolean;
   points: i128;
+  shots_survived: u32;
+  stakes: Map<string, i128>;
+  team: u32;
+  timeouts_left: u32;
+}
+
+
+/**
+ * Where the current round stands, without the bullet
+ */
+export interface RoundInfo {
+  current_chamber: u32;
+  num_chambers: u32;
+  round: u32;
+  shots_fired: u32;
+}
+
+
+
+
+/**
+ * Rarely-changing half of a stored session: roster, rules, commitment
+ * and settlement. Calls that only move the turn along leave it alone.
+ */
+export interface GameStatic {
+  bond_token: Option<string>;
+  bullet_commitment: Buffer;
+  bullet_history: Array<u32>;
+  bullet_position: u32;
+  bullet_salt: Option<Buffer>;
+  config: SessionConfig;
+  fatal_chambers: Array<u64>;
+  finished_ledger: u32;
+  honor_shot_taken: boolean;
+  host: string;
+  hub_override: Option<string>;
+  hub_player1: string;
+  hub_player2: string;
+  num_bullets: u32;
+  num_chambers: u32;
+  phase_timestamps: Map<u32, u32>;
+  players: Array<Jugador>;
+  pot: Map<string, i128>;
+  reveal_winner_at_ledger: u32;
+  round: u32;
+  salt_revealed: boolean;
+  seats: Map<string, u32>;
+  seed_shots_fired: u32;
+  session_id: u32;
+  turn_order: Array<u32>;
+  winner: Option<string>;
+}
+
+
+/**
+ * One `disparar` call, as the contract judged it
+ */
+export interface ShotRecord {
+  chamber: u32;
+  hit: boolean;
+  reach: u32;
+  shooter: string;
+}
+
+
+/**
+ * Per-shot half of a stored session, the only part a miss rewrites
+ * besides appending to the shot log
+ */
+export interface GameDynamic {
+  actions_count: Map<string, u32>;
+  awaiting_reload: boolean;
+  current_chamber: u32;
+  current_turn: u32;
+  invalid_proof_attempts: u32;
+  last_action_ledger: u32;
+  last_actor: string;
+  pending_elimination: Option<string>;
+  pending_until_ledger: u32;
+  phase: u32;
+  shots_fired: u32;
+  shots_survived: Array<u32>;
+  skip_votes: Array<string>;
+  turn_deadline_ledger: u32;
+}
+
+
+/**
+ * A player's record across finished sessions
+ */
+export interface PlayerStats {
+  games_played: u32;
+  wins: u32;
+}
+
+
+/**
+ * A game's result, kept in persistent storage so it outlives the
+ * temporary game entries
+ */
+export interface FinishedGame {
+  finished_ledger: u32;
+  reveal_winner_at_ledger: u32;
+  winner: string;
+}
+
+
+/**
+ * A Groth16 proof over BN254. Points use the host's uncompressed
+ * Ethereum-style encoding; `public_inputs` are big-endian scalars.
+ */
+export interface Groth16Proof {
+  a: Buffer;
+  b: Buffer;
+  c: Buffer;
+  public_inputs: Array<Buffer>;
+}
+
+
+/**
+ * Redacted session snapshot that is safe to show publicly
+ */
+export interface ObserverInfo {
+  alive: Array<string>;
+  bullet_commitment: Buffer;
+  bullet_position: Option<u32>;
+  current_player: Option<string>;
+  phase: u32;
+  round: u32;
+  shots_fired: u32;
+}
+
+
+/**
+ * Groth16 verifying key for the shot circuit, set by the admin
+ */
+export interface VerifyingKey {
+  alpha: Buffer;
+  beta: Buffer;
+  delta: Buffer;
+  gamma: Buffer;
+  ic: Array<Buffer>;
+}
+
+
+
+
+/**
+ * What a cylinder widget needs to draw the current round
+ */
+export interface CylinderState {
+  bullet_visible: Option<u32>;
+  current_chamber: u32;
+  fired_chambers: Array<u32>;
+  num_chambers: u32;
+}
+
+
+/**
+ * Shareable opening of a finished game's bullet commitment. Anyone can
+ * re-check it: `SHA256(salt || bullet_position) == commitment`.
+ */
+export interface FairnessProof {
+  bullet_position: u32;
+  commitment: Buffer;
+  salt: Buffer;
+  sha_matches: boolean;
 }
 
 
 export interface PartidaRuleta {
+  actions_count: Map<string, u32>;
+  awaiting_reload: boolean;
+  bond_token: Option<string>;
   bullet_commitment: Buffer;
+  bullet_history: Array<u32>;
   bullet_position: u32;
+  bullet_salt: Option<Buffer>;
+  config: SessionConfig;
   current_chamber: u32;
   current_turn: u32;
   eliminated: Array<string>;
+  fatal_chambers: Array<u64>;
+  finished_ledger: u32;
+  honor_shot_taken: boolean;
+  host: string;
+  hub_override: Option<string>;
   hub_player1: string;
   hub_player2: string;
+  invalid_proof_attempts: u32;
+  last_action_ledger: u32;
+  last_actor: string;
+  num_bullets: u32;
+  num_chambers: u32;
+  pending_elimination: Option<string>;
+  pending_until_ledger: u32;
   phase: u32;
+  phase_timestamps: Map<u32, u32>;
   players: Array<Jugador>;
+  pot: Map<string, i128>;
+  reveal_winner_at_ledger: u32;
+  round: u32;
+  salt_revealed: boolean;
+  seats: Map<string, u32>;
+  seed_shots_fired: u32;
   session_id: u32;
+  shot_log: Array<ShotRecord>;
   shots_fired: u32;
+  skip_votes: Array<string>;
+  turn_deadline_ledger: u32;
+  turn_order: Array<u32>;
   winner: Option<string>;
 }
 
+
+/**
+ * Per-session rules, chosen by the host before anyone else joins
+ */
+export interface SessionConfig {
+  /**
+ * Each alive player must pay this much per round with `pagar_ante`,
+ * or be eliminated when the round ends (0 = no ante)
+ */
+ante_per_round: i128;
+  /**
+ * Token the ante is paid in (required when the ante is set)
+ */
+ante_token: Option<string>;
+  /**
+ * Static mode: shift the host's committed first bullet by a
+ * per-session offset, so a commitment reused across sessions still
+ * loads different cylinders
+ */
+bind_session_nonce: boolean;
+  /**
+ * Load one more bullet on every reload, up to `num_chambers - 1`
+ */
+bullet_escalation: boolean;
+  /**
+ * Merkle root of the host's future reload positions. When set, each
+ * reload waits for the host to reveal the next leaf with `recargar`
+ * instead of deriving it on-chain.
+ */
+bullet_sequence_commitment: Option<Buffer>;
+  /**
+ * Distinct fatal chambers each auto-reload draws, below
+ * `NUM_CHAMBERS` (0 = one bullet, or the escalation count)
+ */
+bullets_per_round: u32;
+  /**
+ * Ledgers a hit player stays pending before the elimination is final,
+ * for death animations (0 = immediate)
+ */
+elimination_delay_ledgers: u32;
+  /**
+ * Entry fee each later joiner pays the host directly, outside the
+ * pot (0 = free)
+ */
+host_fee: i128;
+  /**
+ * Token `host_fee` is paid in (required when the fee is set)
+ */
+host_fee_token: Option<string>;
+  /**
+ * Buy-back: basis points of their stakes an eliminated player gets
+ * back on the spot, out of the pot (below 10000, so the winner
+ * always keeps something)
+ */
+loser_refund_bps: u32;
+  /**
+ * Failed proofs the shooter may submit in one turn before being
+ * kicked (0 = a bad proof is simply rejected)
+ */
+max_proof_attempts: u32;
+  /**
+ * Whether the bullet position may be shown (`MODE_*`)
+ */
+mode: u32;
+  /**
+ * How the pot reaches the winner (`PAYOUT_*`)
+ */
+payout_mode: u32;
+  /**
+ * Pot shares in basis points by finishing position (winner first,
+ * then the last eliminated, ...), summing to 10000. Empty = winner
+ * takes all.
+ */
+prize_ladder: Array<u32>;
+  /**
+ * Which verifier `disparar` runs (`PROOF_SYSTEM_*`)
+ */
+proof_system: u32;
+  /**
+ * Last ledger new players may join on (0 = open until full or loaded)
+ */
+registration_deadline_ledger: u32;
+  /**
+ * How the cylinder reloads after an elimination (`RELOAD_*`)
+ */
+reload_mode: u32;
+  /**
+ * Ledgers after the finish before `revelar_bala` is accepted, so
+ * auditors get a window where the commitment stands alone
+ */
+reveal_after_ledgers: u32;
+  /**
+ * Ledgers after the final shot before queries reveal the winner
+ */
+reveal_delay_ledgers: u32;
+  /**
+ * Re-draw the alive players' turn order from the seed on each reload
+ */
+shuffle_each_round: boolean;
+  /**
+ * Whether a passed skip vote eliminates the current player (`false`
+ * just passes their turn)
+ */
+skip_vote_eliminates: boolean;
+  /**
+ * Ledgers the game must sit idle (since `last_action_ledger`) before
+ * a skip vote may open. At least 1, and below the hard turn timeout
+ * when turns are timed.
+ */
+skip_vote_idle_ledgers: u32;
+  /**
+ * Once `shots_fired` exceeds this, each reload removes one chamber
+ * from the cylinder (0 = disabled)
+ */
+sudden_death_after: u32;
+  /**
+ * Two teams (joiners fill the smaller one, up to 2v2); the game ends
+ * when only one team has living members and that team splits the pot
+ */
+team_mode: boolean;
+  /**
+ * Ledgers one timeout adds to the turn deadline
+ */
+timeout_extension_ledgers: u32;
+  /**
+ * Tactical timeouts (`pedir_tiempo`) each player gets per game, up
+ * to `MAX_TACTICAL_TIMEOUTS`
+ */
+timeouts_per_player: u32;
+  /**
+ * Initial turn order (`TURN_ORDER_*`)
+ */
+turn_order_mode: u32;
+}
+
+
 export interface Client {
+  /**
+   * Construct and simulate a add_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Approve another hub for sessions to report to. It must be a
+   * deployed contract.
+   */
+  add_hub: ({hub}: {hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a apostar transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stake `amount` of an admin-approved token into the pot while the
+   * lobby is open. Lobbies may mix currencies; the winner receives each
+   * token's balance separately. Returns the player's total in `token_addr`.
+   */
+  apostar: ({session_id, player, token_addr, amount}: {session_id: u32, player: string, token_addr: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
   /**
    * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
@@ -103,47 +588,522 @@ export interface Client {
    * After elimination, if 2+ players remain, the cylinder auto-reloads
    * with a new deterministic bullet position for the next round.
    * 
+   * An insured shooter who is hit survives instead: the policy is
+   * consumed and the cylinder reloads.
+   * 
+   * With `max_proof_attempts` set, a failed proof doesn't error (an
+   * error would roll the count back): it counts against the shooter
+   * (`SHOT_PROOF_REJECTED`, turn unchanged), and the last allowed
+   * failure kicks them like an AFK player (`SHOT_KICKED`).
+   * 
+   * A game found in play with one side left, or none, is over: the
+   * shot is refused with `GameAlreadyEnded`.
+   * 
    * # Returns
-   * `true` if the player was hit (eliminated), `false` if survived.
+   * `SHOT_HIT` if the player was hit, `SHOT_SURVIVED` if they survived,
+   * or one of the no-shot outcomes above (`SHOT_*`).
    */
-  disparar: ({session_id, player, zk_proof}: {session_id: u32, player: string, zk_proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+  disparar: ({session_id, player, zk_proof}: {session_id: u32, player: string, zk_proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get full game state
+   * Get full game state. During a reveal delay the game already
+   * reads as finished but `winner` is withheld, and `bullet_salt` is
+   * withheld until `revelar_bala`. Hidden-mode games report
+   * `BULLET_POSITION_HIDDEN` and empty `bullet_history` and
+   * `fatal_chambers` until they finish.
    */
   get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<PartidaRuleta>>>
 
+  /**
+   * Construct and simulate a get_host transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The session's host, who loads the revolver. Passes to the first
+   * seat when the host leaves the lobby.
+   */
+  get_host: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a get_seat transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a player's seat (index into `players`) via the seat index
+   */
+  get_seat: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a is_alive transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether one player is still alive, without fetching the list
+   */
+  is_alive: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a recargar transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * With a `bullet_sequence_commitment`, every reload waits here. The
+   * host opens reload `round - 1`'s leaf, `compute_bullet_hash(salt,
+   * value)`, with a Merkle proof against the root. The cylinder is
+   * loaded like any other reload, with the round's bullet count: the
+   * first bullet lands at `current_chamber + value % chambers_left`, so
+   * leaves committed up front fit whatever the cylinder looks like by
+   * then, and any further bullets are drawn from the leaf's remaining
+   * bytes. Returns the new bullet position.
+   */
+  recargar: ({session_id, host, value, salt, proof}: {session_id: u32, host: string, value: u32, salt: Buffer, proof: Array<Buffer>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
 
+  /**
+   * Construct and simulate a get_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reloads the session has gone through (0 = still on the host's cylinder)
+   */
+  get_round: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
   /**
    * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
+  /**
+   * Construct and simulate a get_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_config: (options?: MethodOptions) => Promise<AssembledTransaction<Config>>
+
+  /**
+   * Construct and simulate a get_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the winner of a finished game, once its reveal ledger is
+   * reached (`None` while it is still being played). Falls back to the
+   * persistent `FinishedGame` once the live entries have expired. The
+   * delay only paces front-ends: the hub is told at the final shot and
+   * the `winner` event is published then.
+   */
+  get_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
+
+  /**
+   * Construct and simulate a pagar_ante transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * In an ante session, each alive player pays at least
+   * `ante_per_round` per round into the pot (adding to their stake).
+   * Anyone short when the round ends is eliminated and their stake
+   * stays in the pot. Returns what they've paid this round.
+   */
+  pagar_ante: ({session_id, player, amount}: {session_id: u32, player: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a parse_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Decode an XDR-serialized `PartidaRuleta` (e.g. a saved `get_game`
+   * result) for indexers and debugging scripts. Well-formed XDR of any
+   * other shape, including a game from an older schema, returns
+   * `UnknownSchema`; bytes that aren't XDR at all still trap in the host.
+   */
+  parse_game: ({data}: {data: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<PartidaRuleta>>>
+
+  /**
+   * Construct and simulate a remove_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw a hub's approval. Sessions already loaded keep reporting
+   * to it.
+   */
+  remove_hub: ({hub}: {hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_config: ({config}: {config: Config}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Release number of the WASM currently live (`CONTRACT_VERSION`)
+   */
+  get_version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a pedir_tiempo transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The current shooter spends one of their timeouts to push the turn
+   * deadline back by `timeout_extension_ledgers`. Only meaningful when
+   * turns are timed. Returns the timeouts they have left.
+   */
+  pedir_tiempo: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a revelar_bala transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Once the game is finished the host publishes the salt behind the
+   * commitment, so anyone can check the initial bullet wasn't moved.
+   * The salt was stored at load; this marks it revealed, after which
+   * queries serve it. A salt that doesn't open the commitment is
+   * refused. Refused until `reveal_after_ledgers` have passed since
+   * the finish.
+   */
+  revelar_bala: ({session_id, host, salt}: {session_id: u32, host: string, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a votar_saltar transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Once the game has sat idle for `skip_vote_idle_ledgers` (a soft
+   * threshold, short of the hard timeout), the other alive players may
+   * vote to skip whoever holds the turn, timed game or not. Before
+   * that the first vote is refused (`TurnNotExpired`), so a fresh turn
+   * can't be voted out; once a vote is open, later votes count until
+   * the turn moves. A majority of the others skips the current player.
+   * Eliminating instead, per `skip_vote_eliminates`, takes at least two
+   * votes that are also a strict majority of all living players, so a
+   * lone opponent can't vote the shooter out. Votes reset when a vote
+   * passes. The current player cannot vote (`NotPlayer`). Returns
+   * whether the vote passed.
+   */
+  votar_saltar: ({session_id, voter}: {session_id: u32, voter: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+
   /**
    * Construct and simulate a who_is_alive transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get alive players
    */
   who_is_alive: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<string>>>>
 
+  /**
+   * Construct and simulate a get_multi_pot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Per-token pot balances, for mixed-currency lobbies
+   */
+  get_multi_pot: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<readonly [string, i128]>>>>
+
+  /**
+   * Construct and simulate a reset_session transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Dev-only: delete a session's storage so the same session id can
+   * be recreated fresh. Escrowed bonds and stakes are not refunded.
+   * Fails with `DevModeDisabled` unless `dev_mode` is on.
+   */
+  reset_session: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a tiro_de_honor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Within the honor window after the game ends, the winner may fire
+   * one more chamber. The cylinder is loaded the way the next round's
+   * reload would load it, and the proof must cover the chamber fired.
+   * Surviving adds `bravery_bonus` to the winner's recorded points;
+   * getting hit takes it away and forfeits `honor_forfeit_bps` of the
+   * pot to protocol fees. While the window is open the pot stays in
+   * escrow, so a hit always has it at stake; in `PAYOUT_AUTO` sessions
+   * the rest is paid out once the shot lands.
+   * 
+   * # Returns
+   * `true` if the winner was hit, `false` if they survived.
+   */
+  tiro_de_honor: ({session_id, winner, zk_proof}: {session_id: u32, winner: string, zk_proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a validate_shot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Dry-run `disparar`'s checks for `player` without a proof or any
+   * state change: `Ok` if a shot would be accepted, else the exact
+   * error, so a UI can disable the fire button with the reason.
+   */
+  validate_shot: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a withdraw_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Transfer the protocol fees collected in `token` to `to` and zero
+   * the balance. Returns the amount sent.
+   */
+  withdraw_fees: ({token, to}: {token: string, to: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
+
+  /**
+   * Construct and simulate a comprar_seguro transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * During play, an alive player pays a premium (at least
+   * `insurance_price`, in an approved stake token) into the pot. Their
+   * next hit is absorbed instead of eliminating them. One policy at a
+   * time.
+   */
+  comprar_seguro: ({session_id, player, token_addr, amount}: {session_id: u32, player: string, token_addr: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_round_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Round, chamber and shot counters in one read. Safe to show in any
+   * mode: the bullet position is not included.
+   */
+  get_round_info: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<RoundInfo>>>
+
+  /**
+   * Construct and simulate a session_exists transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether a session has been created (without the `GameNotFound` round-trip)
+   */
+  session_exists: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a subscribe_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Snapshot for streaming overlays, polled each tick. Carries only
+   * what is safe to display: the bullet position is withheld in
+   * hidden mode, and the winner is left to `get_winner`.
+   */
+  subscribe_info: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<ObserverInfo>>>
+
+  /**
+   * Construct and simulate a abortar_partida transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Admin abort: like `resolver_manualmente`, but the contract picks
+   * the winner the hub is told about — the survivor with the most
+   * points, ties going to the lowest address. Returns that winner.
+   */
+  abortar_partida: ({session_id, resolution_mode}: {session_id: u32, resolution_mode: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a auditar_partida transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Full fairness audit. Given the host's salt, checks the commitment
+   * opens to the initial bullet, re-derives every reload position
+   * (sudden death included) and replays the shot log, confirming each
+   * recorded hit or miss is what the cylinder dictated. Returns `false`
+   * on the first inconsistency.
+   * 
+   * A reload may match either reload mode, since the admin can switch
+   * modes mid-game with `cambiar_reload_mode`.
+   */
+  auditar_partida: ({session_id, salt}: {session_id: u32, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+
   /**
    * Construct and simulate a cargar_revolver transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * The first player (host) sets the bullet position and starts the game.
-   * Requires at least 2 players (max 3).
-   * Also registers the session with the Game Hub.
+   * Requires at least 2 players (max 3). `salt` must open
+   * `bullet_commitment` to `bullet_position`. It is stored so it can
+   * later be revealed, and queries hide it until `revelar_bala`.
+   * Also registers the session with the Game Hub — `hub_override`, if
+   * given, replaces the global hub for this session (e.g. a
+   * tournament's own hub) and must be one of the approved hubs. With
+   * `shuffle_seating` every seat is drawn at random before play begins,
+   * so anyone may shoot first, and the hub pair follows the new first
+   * two seats.
+   */
+  cargar_revolver: ({session_id, player, bullet_commitment, bullet_position, salt, hub_override, shuffle_seating}: {session_id: u32, player: string, bullet_commitment: Buffer, bullet_position: u32, salt: Buffer, hub_override: Option<string>, shuffle_seating: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_fee_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Protocol fees collected in `token` and not yet withdrawn
+   */
+  get_fee_balance: ({token}: {token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_hub_pairing transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the two players registered with the Game Hub for this session.
+   * Only meaningful once the revolver is loaded.
+   */
+  get_hub_pairing: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<readonly [string, string]>>>
+
+  /**
+   * Construct and simulate a get_player_wins transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Session ids the player has won, oldest first (the latest
+   * `MAX_WINS_HISTORY` only)
+   */
+  get_player_wins: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Array<u32>>>
+
+  /**
+   * Construct and simulate a get_shots_fired transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Shots fired so far, fatal ones included. Reads only the dynamic
+   * half of the game, so it is cheaper than `get_game`.
+   */
+  get_shots_fired: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a reclamar_premio transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * In `PAYOUT_CLAIM` sessions the pot stays in escrow after the game
+   * ends; the winner pulls it here. Safer for contract-account winners
+   * that might reject a pushed transfer. A pot held for the honor
+   * window is pulled here too, and claiming it passes on the honor
+   * shot. Returns each token and the amount paid in it.
+   */
+  reclamar_premio: ({session_id, winner}: {session_id: u32, winner: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<readonly [string, i128]>>>>
+
+  /**
+   * Construct and simulate a recuperar_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Send tokens that reached the contract outside any game to `to`.
+   * Only the surplus over `total_liabilities` and the fee balance can
+   * leave, so active pots, held bonds and fees stay covered.
+   */
+  recuperar_token: ({token, to, amount}: {token: string, to: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a check_invariants transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check a session's state invariants (for fuzzing and debugging):
+   * seat index matches `players`, eliminated players are exactly the
+   * dead ones, the turn points at a living player and the chamber and
+   * bullet are in range while playing, and a winner exists iff the
+   * game is finished. Returns `InconsistentState` on any violation.
    */
-  cargar_revolver: ({session_id, player, bullet_commitment, bullet_position}: {session_id: u32, player: string, bullet_commitment: Buffer, bullet_position: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  check_invariants: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a expulsar_por_afk transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Anyone may call this once the current turn's deadline has passed.
+   * The AFK player is eliminated and their bond goes to the pot; the
+   * next player faces the same chamber. Returns the kicked address.
+   */
+  expulsar_por_afk: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a get_current_seed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The seed the current round's bullet was derived from:
+   * `SHA256(session_id_be || shots_fired_be)`, with `shots_fired` as of
+   * the hit that triggered the reload that loaded the live bullet (a
+   * hit still awaiting confirmation, or one that ended the game, hasn't
+   * reloaded). In reset mode with one bullet,
+   * `bullet_position == seed[0] % num_chambers`. Static mode only, and
+   * only after a reload (the host picks round 0's bullet).
+   */
+  get_current_seed: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Buffer>>>
+
+  /**
+   * Construct and simulate a get_open_lobbies transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Waiting sessions with a free seat, oldest first, for lobby
+   * browsers. Ids whose lobby expired, started or closed registration
+   * are pruned as they are found.
+   */
+  get_open_lobbies: (options?: MethodOptions) => Promise<AssembledTransaction<Array<u32>>>
+
+  /**
+   * Construct and simulate a get_player_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * A player's games played and wins across finished sessions
+   */
+  get_player_stats: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<PlayerStats>>
+
+  /**
+   * Construct and simulate a get_token_volume transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Net amount staked in `token` across all sessions (refunds excluded)
+   */
+  get_token_volume: ({token}: {token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_action_counts transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * State-changing calls each player has made in the session, for
+   * organizers reconciling sponsored fees. Accounting only.
+   */
+  get_action_counts: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<readonly [string, u32]>>>>
+
+  /**
+   * Construct and simulate a get_approved_hubs transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hubs `cargar_revolver` may route a session to
+   */
+  get_approved_hubs: (options?: MethodOptions) => Promise<AssembledTransaction<Array<string>>>
+
+  /**
+   * Construct and simulate a get_revealed_salt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The salt the host published with `revelar_bala`, for auditors
+   */
+  get_revealed_salt: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Buffer>>>
+
+  /**
+   * Construct and simulate a get_winner_margin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * How many shots the winner survived, for the results screen
+   */
+  get_winner_margin: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a reconciliar_turno transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Migration-safety tool: if an in-flight game's `current_turn` no
+   * longer points at a living player (e.g. stored under older turn
+   * logic), move it to the next alive seat. Returns the resulting turn.
+   */
+  reconciliar_turno: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a set_verifying_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Install the Groth16 verifying key used by `PROOF_SYSTEM_GROTH16`
+   * sessions. It must carry one IC point per public input plus one, all
+   * of its points on the curve.
+   */
+  set_verifying_key: ({vk}: {vk: VerifyingKey}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a total_liabilities transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Everything the contract owes, per token, across the active-session
+   * index: pots (stakes, antes, insurance premiums and forfeited bonds)
+   * plus bonds still held for players. Should never exceed the
+   * contract's balance of each token.
+   */
+  total_liabilities: (options?: MethodOptions) => Promise<AssembledTransaction<Result<Map<string, i128>>>>
+
+  /**
+   * Construct and simulate a chambers_remaining transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pulls left in the current cylinder before it has to be reloaded,
+   * bullet or not. Reveals nothing about the bullet, so any mode.
+   */
+  chambers_remaining: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a configurar_partida transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The host (first player) chooses the session rules. Only allowed
+   * while the host is alone in the lobby, so nobody joins under one
+   * set of rules and plays under another.
+   */
+  configurar_partida: ({session_id, host, config}: {session_id: u32, host: string, config: SessionConfig}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a entrar_a_la_ruleta transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Register a player into the session. Supports 2-3 players.
    * The host must call `cargar_revolver` once enough players have joined.
+   * `avatar` picks a visual slot that no other player in the session holds.
+   * `points` must be in `0..=MAX_JOIN_POINTS`.
+   */
+  entrar_a_la_ruleta: ({session_id, player, points, avatar}: {session_id: u32, player: string, points: i128, avatar: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a get_cylinder_state transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The cylinder as drawn by a widget. Chambers before
+   * `current_chamber` have been fired, including those a continue-mode
+   * reload skipped past; the bullet is shown in static mode only.
+   */
+  get_cylinder_state: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<CylinderState>>>
+
+  /**
+   * Construct and simulate a get_fairness_proof transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The revealed commitment opening, packaged for sharing. Only
+   * available after `revelar_bala`.
+   */
+  get_fairness_proof: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<FairnessProof>>>
+
+  /**
+   * Construct and simulate a get_fatal_chambers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Every chamber loaded in `round` (0 is the host's load, then one per
+   * reload), in ascending order. Available once the round is over;
+   * hidden-mode games disclose nothing until they finish.
+   */
+  get_fatal_chambers: ({session_id, round}: {session_id: u32, round: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<u32>>>>
+
+  /**
+   * Construct and simulate a get_phase_timeline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * `(phase, ledger)` for every phase the session has entered, in phase
+   * order — e.g. how long the lobby waited and how long play took
+   */
+  get_phase_timeline: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<readonly [u32, u32]>>>>
+
+  /**
+   * Construct and simulate a get_session_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The rules a lobby was configured with, without the rest of the state
+   */
+  get_session_config: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<SessionConfig>>>
+
+  /**
+   * Construct and simulate a salir_de_la_ruleta transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Leave a lobby that hasn't started yet. The player's bond is
+   * refunded. If the host leaves, the next player becomes host; an
+   * emptied lobby is deleted. Returns the remaining player count.
    */
-  entrar_a_la_ruleta: ({session_id, player, points}: {session_id: u32, player: string, points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+  salir_de_la_ruleta: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a would_accept_proof transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pre-flight for `disparar`: would this proof pass the proof gate for
+   * the chamber about to be fired? A malformed, oversized or mismatched
+   * proof is `false`; games not in play are `WrongPhase`.
+   */
+  would_accept_proof: ({session_id, zk_proof}: {session_id: u32, zk_proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<boolean>>>
+
+  /**
+   * Construct and simulate a cambiar_reload_mode transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Live-repair tool: switch an in-progress game's `reload_mode`. The
+   * change applies from the next reload; if the current round's
+   * chamber or bullet is out of step (the bullet already passed, or
+   * either beyond the cylinder), the round restarts at chamber 0.
+   * Returns the resulting `current_chamber`.
+   */
+  cambiar_reload_mode: ({session_id, new_mode}: {session_id: u32, new_mode: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a commitment_is_fresh transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether no session has loaded `commitment` yet. Only commitments
+   * loaded while `reject_reused_commitments` is on are tracked, and
+   * only the latest `MAX_TRACKED_COMMITMENTS` of them.
+   */
+  commitment_is_fresh: ({commitment}: {commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
 
   /**
    * Construct and simulate a compute_bullet_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -152,6 +1112,89 @@ export interface Client {
    */
   compute_bullet_hash: ({salt, position}: {salt: Buffer, position: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
 
+  /**
+   * Construct and simulate a get_bullet_sequence transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Every bullet position the session used, in order: the host's
+   * initial position followed by each auto-reload's SHA256-derived
+   * position. Only available once the game is finished.
+   */
+  get_bullet_sequence: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<u32>>>>
+
+  /**
+   * Construct and simulate a get_pending_actions transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * What each seated player must do next, in seat order, so a UI can
+   * show what is holding the game up (`ACTION_*`). While the host owes
+   * a reload or a hit is pending confirmation, nobody is asked to shoot.
+   */
+  get_pending_actions: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<readonly [string, u32]>>>>
+
+  /**
+   * Construct and simulate a recargar_por_defecto transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * If the host hasn't called `recargar` by the reload deadline, any
+   * living player may reload instead. The bullet is derived on-chain
+   * exactly as in a session without a preloaded sequence. Returns the
+   * new bullet position.
+   */
+  recargar_por_defecto: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a resolver_manualmente transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Admin dispute tool: end an in-progress game with a declared
+   * winner (reported to the hub as usual) and settle the pot per
+   * `resolution_mode` (`RESOLVE_*`). Whatever the refund or split
+   * leaves, such as forfeited bonds or rounding dust, follows the
+   * session's payout mode to the winner.
+   */
+  resolver_manualmente: ({session_id, winner, resolution_mode}: {session_id: u32, winner: string, resolution_mode: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a config_is_terminating transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether a game with these parameters must end with a winner, for
+   * hosts checking their settings before loading. True when there is
+   * at least one bullet that fits the cylinder, the reload mode is
+   * known (both modes always reload a bullet into a chamber still to
+   * be fired) and at least two players are seated. Every hit then
+   * removes a player within `num_chambers` pulls.
+   */
+  config_is_terminating: ({num_chambers, num_bullets, reload_mode, num_players}: {num_chambers: u32, num_bullets: u32, reload_mode: u32, num_players: u32}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a confirmar_eliminacion transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Anyone may call this once a pending hit's delay has passed. The
+   * hit player is eliminated and the game reloads or ends exactly as
+   * an immediate hit would have. Returns the eliminated address.
+   */
+  confirmar_eliminacion: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a get_active_game_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Unfinished sessions the player is seated in, as checked against
+   * `max_active_games` on join
+   */
+  get_active_game_count: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_last_action_ledger transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Ledger of the session's most recent state change, for staleness
+   * monitoring
+   */
+  get_last_action_ledger: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a compute_reload_position transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The position a reset-mode, one-bullet reload picks:
+   * `SHA256(session_id_be || shots_fired_be)[0] % num_chambers`, with
+   * `shots_fired` as of the hit. Lets clients re-derive a reload.
+   */
+  compute_reload_position: ({session_id, shots_fired, num_chambers}: {session_id: u32, shots_fired: u32, num_chambers: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a safe_chambers_remaining transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pulls left before the bullet in the current round ("N clicks to
+   * boom"). Static mode only, since it reveals the bullet's distance.
+   */
+  safe_chambers_remaining: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
@@ -172,36 +1215,190 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAADAAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAKV3JvbmdQaGFzZQAAAAAAAwAAAAAAAAALTm90WW91clR1cm4AAAAABAAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAUAAAAAAAAACUxvYmJ5RnVsbAAAAAAAAAYAAAAAAAAADUFscmVhZHlKb2luZWQAAAAAAAAHAAAAAAAAABBQbGF5ZXJFbGltaW5hdGVkAAAACAAAAAAAAAAMSW52YWxpZFByb29mAAAACQAAAAAAAAAOSW52YWxpZENoYW1iZXIAAAAAAAoAAAAAAAAAEE5vdEVub3VnaFBsYXllcnMAAAALAAAAAAAAAA5BbHJlYWR5U3RhcnRlZAAAAAAADA==",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAQAAAAAAAAAAAAAAB0p1Z2Fkb3IAAAAAAwAAAAAAAAAHYWRkcmVzcwAAAAATAAAAAAAAAAhpc19hbGl2ZQAAAAEAAAAAAAAABnBvaW50cwAAAAAACw==",
-        "AAAAAQAAAAAAAAAAAAAADVBhcnRpZGFSdWxldGEAAAAAAAAMAAAAAAAAABFidWxsZXRfY29tbWl0bWVudAAAAAAAA+4AAAAgAAAAAAAAAA9idWxsZXRfcG9zaXRpb24AAAAABAAAAAAAAAAPY3VycmVudF9jaGFtYmVyAAAAAAQAAAAAAAAADGN1cnJlbnRfdHVybgAAAAQAAAAAAAAACmVsaW1pbmF0ZWQAAAAAA+oAAAATAAAAAAAAAAtodWJfcGxheWVyMQAAAAATAAAAAAAAAAtodWJfcGxheWVyMgAAAAATAAAAAAAAAAVwaGFzZQAAAAAAAAQAAAAAAAAAB3BsYXllcnMAAAAD6gAAB9AAAAAHSnVnYWRvcgAAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAC3Nob3RzX2ZpcmVkAAAAAAQAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAMgAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAKV3JvbmdQaGFzZQAAAAAAAwAAAAAAAAALTm90WW91clR1cm4AAAAABAAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAUAAAAAAAAACUxvYmJ5RnVsbAAAAAAAAAYAAAAAAAAADUFscmVhZHlKb2luZWQAAAAAAAAHAAAAAAAAABBQbGF5ZXJFbGltaW5hdGVkAAAACAAAAAAAAAAMSW52YWxpZFByb29mAAAACQAAAAAAAAAOSW52YWxpZENoYW1iZXIAAAAAAAoAAAAAAAAAEE5vdEVub3VnaFBsYXllcnMAAAALAAAAAAAAAA5BbHJlYWR5U3RhcnRlZAAAAAAADAAAAAAAAAANSW52YWxpZEF2YXRhcgAAAAAAAA0AAAAAAAAADlR1cm5Ob3RFeHBpcmVkAAAAAAAOAAAAAAAAAA1JbnZhbGlkQ29uZmlnAAAAAAAADwAAAAAAAAAJTm90V2lubmVyAAAAAAAAEAAAAAAAAAAQSG9ub3JVbmF2YWlsYWJsZQAAABEAAAAAAAAADk5vdGhpbmdUb0NsYWltAAAAAAASAAAAAAAAAAlXcm9uZ01vZGUAAAAAAAATAAAAAAAAAA1QZW5kaW5nUmV2ZWFsAAAAAAAAFAAAAAAAAAARSW5jb25zaXN0ZW50U3RhdGUAAAAAAAAVAAAAAAAAAA9Ub2tlbk5vdEFsbG93ZWQAAAAAFgAAAAAAAAAMSW52YWxpZFN0YWtlAAAAFwAAAAAAAAAPQWxyZWFkeVJldmVhbGVkAAAAABgAAAAAAAAAD0Rldk1vZGVEaXNhYmxlZAAAAAAZAAAAAAAAABJFbGltaW5hdGlvblBlbmRpbmcAAAAAABoAAAAAAAAAFE5vUGVuZGluZ0VsaW1pbmF0aW9uAAAAGwAAAAAAAAAUSW5zdXJhbmNlVW5hdmFpbGFibGUAAAAcAAAAAAAAAA1Vbmtub3duU2NoZW1hAAAAAAAAHQAAAAAAAAAOTm9UaW1lb3V0c0xlZnQAAAAAAB4AAAAAAAAAEkNvbW1pdG1lbnRNaXNtYXRjaAAAAAAAHwAAAAAAAAASSW52YWxpZFJlbG9hZFByb29mAAAAAAAgAAAAAAAAAA5Bd2FpdGluZ1JlbG9hZAAAAAAAIQAAAAAAAAAKVW5rbm93bkh1YgAAAAAAIgAAAAAAAAANSW52YWxpZFBvaW50cwAAAAAAACMAAAAAAAAAEkV4Y2VlZHNSZWNvdmVyYWJsZQAAAAAAJAAAAAAAAAASVG9vTWFueUFjdGl2ZUdhbWVzAAAAAAAlAAAAAAAAAAtIdWJSZWplY3RlZAAAAAAmAAAAAAAAAA5SZXZlYWxUb29FYXJseQAAAAAAJwAAAAAAAAALQXZhdGFyVGFrZW4AAAAAKAAAAAAAAAAWVW5zdXBwb3J0ZWRQcm9vZlN5c3RlbQAAAAAAKQAAAAAAAAALUmF0ZUxpbWl0ZWQAAAAAKgAAAAAAAAAMQWxyZWFkeVZvdGVkAAAAKwAAAAAAAAASUmVnaXN0cmF0aW9uQ2xvc2VkAAAAAAAsAAAAAAAAAA1Qcm9vZlRvb0xhcmdlAAAAAAAALQAAAAAAAAAQQ29tbWl0bWVudFJldXNlZAAAAC4AAAAAAAAADkludmFsaWRTZXNzaW9uAAAAAAAvAAAAAAAAAAtOb3RSZXZlYWxlZAAAAAAwAAAAAAAAAAhPdmVyZmxvdwAAADEAAAAAAAAADlBvdENhcEV4Y2VlZGVkAAAAAAAz",
+        "AAAAAQAAACZDb250cmFjdC13aWRlIHBvbGljeSwgc2V0IGJ5IHRoZSBhZG1pbgAAAAAAAAAAAAZDb25maWcAAAAAABQAAAAyVG9rZW4gam9pbiBib25kcyBhcmUgcG9zdGVkIGluIChgTm9uZWAgPSBubyBib25kcykAAAAAAApib25kX3Rva2VuAAAAAAPoAAAAEwAAAEVQb2ludHMgYSB3aW5uZXIgZ2FpbnMgYnkgc3Vydml2aW5nIGB0aXJvX2RlX2hvbm9yYCAoYW5kIGxvc2VzIGlmIGhpdCkAAAAAAAANYnJhdmVyeV9ib251cwAAAAAAAAsAAAA5UGFydGljaXBhdGlvbiBjcmVkaXQgYWRkZWQgdG8gZWFjaCBsb3NlcidzIGBmaW5hbF9wb2ludHNgAAAAAAAAEmNvbnNvbGF0aW9uX3BvaW50cwAAAAAACwAAAEZFbmFibGVzIGRldi1vbmx5IHRvb2xzIHN1Y2ggYXMgYHJlc2V0X3Nlc3Npb25gOyBrZWVwIG9mZiBpbiBwcm9kdWN0aW9uAAAAAAAIZGV2X21vZGUAAAABAAAANldpZGVuIHN0cm9uZyBwbGF5ZXJzJyBkYW5nZXIgem9uZSBwZXIgYGhhbmRpY2FwX3RhYmxlYAAAAAAACGhhbmRpY2FwAAAAAQAAALlgKG1pbl93aW5fcmF0ZV9icHMsIGV4dHJhX2NoYW1iZXJzKWAgcm93czogYSBzaG9vdGVyIHdob3NlIHdpbiByYXRlCihpbiBiYXNpcyBwb2ludHMpIHJlYWNoZXMgYSByb3cgaXMgYWxzbyBoaXQgYnkgdGhlIGJ1bGxldCB0aGF0IG1hbnkKY2hhbWJlcnMgZWFybHkuIFRoZSBsYXJnZXN0IG1hdGNoaW5nIHJvdyBhcHBsaWVzLgAAAAAAAA5oYW5kaWNhcF90YWJsZQAAAAAD6gAAA+0AAAACAAAABAAAAAQAAABsU2hhcmUgb2YgdGhlIHBvdCwgaW4gYmFzaXMgcG9pbnRzLCBhIHdpbm5lciBoaXQgYnkgYHRpcm9fZGVfaG9ub3JgCmZvcmZlaXRzIHRvIHByb3RvY29sIGZlZXMgKGF0IG1vc3QgMTAwMDApAAAAEWhvbm9yX2ZvcmZlaXRfYnBzAAAAAAAABAAAAIBMZWRnZXJzIGFmdGVyIHRoZSBmaW5pc2ggZHVyaW5nIHdoaWNoIHRoZSB3aW5uZXIgbWF5IHRha2UgdGhlCmhvbm9yIHNob3QsIHdpdGggdGhlIHBvdCBoZWxkIG1lYW53aGlsZSAoMCA9IGhvbm9yIHNob3RzIGRpc2FibGVkKQAAABRob25vcl93aW5kb3dfbGVkZ2VycwAAAAQAAABATWluaW11bSBwcmVtaXVtIGZvciBgY29tcHJhcl9zZWd1cm9gICgwID0gaW5zdXJhbmNlIG5vdCBvZmZlcmVkKQAAAA9pbnN1cmFuY2VfcHJpY2UAAAAACwAAAEZSZWZ1bmRhYmxlIGJvbmQgZWFjaCBqb2luZXIgcG9zdHM7IGZvcmZlaXRlZCB0byB0aGUgcG90IG9uIGFuIEFGSyBraWNrAAAAAAAJam9pbl9ib25kAAAAAAAACwAAAEhVbmZpbmlzaGVkIHNlc3Npb25zIG9uZSBhZGRyZXNzIG1heSBiZSBzZWF0ZWQgaW4gYXQgb25jZSAoMCA9CnVubGltaXRlZCkAAAAQbWF4X2FjdGl2ZV9nYW1lcwAAAAQAAAA/Q2VpbGluZyBvbiB0aGUgcG9pbnRzIGVhY2ggcGxheWVyIGlzIHJlZ2lzdGVyZWQgd2l0aCBhdCB0aGUgaHViAAAAAA5tYXhfaHViX3BvaW50cwAAAAAACwAAAEpKb2lucyBvbmUgYWRkcmVzcyBtYXkgbWFrZSBwZXIgbGVkZ2VyLCBhY3Jvc3MgYWxsIHNlc3Npb25zICgwID0gdW5saW1pdGVkKQAAAAAAFG1heF9qb2luc19wZXJfbGVkZ2VyAAAABAAAAGFNb3N0IGEgc2Vzc2lvbidzIHBvdCBtYXkgaG9sZCBpbiBhbnkgb25lIHRva2VuIGZyb20gc3Rha2VzIGFuZApwcmVtaXVtcyAoYGkxMjg6Ok1BWGAgPSB1bmxpbWl0ZWQpAAAAAAAAB21heF9wb3QAAAAACwAAAEtMb25nZXN0IHByb29mIGBkaXNwYXJhcmAgLyBgdGlyb19kZV9ob25vcmAgYWNjZXB0LCBpbiBieXRlcyAoMCA9IHVubGltaXRlZCkAAAAAD21heF9wcm9vZl9ieXRlcwAAAAAEAAAAc1Byb3RvY29sIGZlZSBpbiBiYXNpcyBwb2ludHMsIHNraW1tZWQgb2ZmIGVhY2ggcG90IGJlZm9yZSBpdCBpcwpwYWlkIG91dCBhbmQgaGVsZCBmb3IgYHdpdGhkcmF3X2ZlZXNgIChiZWxvdyAxMDAwMCkAAAAAEHByb3RvY29sX2ZlZV9icHMAAAAEAAAASlRyYWNrIHJlY2VudGx5IGxvYWRlZCBjb21taXRtZW50cyBhbmQgcmVmdXNlIHJldXNlIGluIGhpZGRlbi1tb2RlCnNlc3Npb25zAAAAAAAZcmVqZWN0X3JldXNlZF9jb21taXRtZW50cwAAAAAAAAEAAAA0VG9rZW5zIHBsYXllcnMgbWF5IHN0YWtlIGludG8gdGhlIHBvdCB3aXRoIGBhcG9zdGFyYAAAAAxzdGFrZV90b2tlbnMAAAPqAAAAEwAAAB5FdmVudCBuYW1pbmcgdGhlbWUgKGBUSEVNRV8qYCkAAAAAAAV0aGVtZQAAAAAAAAQAAAA9TGVkZ2VycyB0aGUgY3VycmVudCBwbGF5ZXIgaGFzIHRvIHNob290ICgwID0gbm8gdHVybiB0aW1lb3V0KQAAAAAAABR0dXJuX3RpbWVvdXRfbGVkZ2VycwAAAAQ=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAFgAAAAEAAAAAAAAACkdhbWVTdGF0aWMAAAAAAAEAAAAEAAAAAQAAAAAAAAALR2FtZUR5bmFtaWMAAAAAAQAAAAQAAAABAAAAAAAAAAdTaG90TG9nAAAAAAEAAAAEAAAAAQAAAAAAAAANRWxpbWluYXRlZExvZwAAAAAAAAEAAAAEAAAAAAAAAAAAAAAOR2FtZUh1YkFkZHJlc3MAAAAAAAAAAAAAAAAABUFkbWluAAAAAAAAAAAAAAAAAAAGQ29uZmlnAAAAAAABAAAAAAAAAAlKb2luQ291bnQAAAAAAAACAAAAEwAAAAQAAAABAAAAAAAAAA5Vc2VkQ29tbWl0bWVudAAAAAAAAQAAA+4AAAAgAAAAAAAAAAAAAAARUmVjZW50Q29tbWl0bWVudHMAAAAAAAABAAAAAAAAAAVTdGF0cwAAAAAAAAEAAAATAAAAAQAAAAAAAAALVG9rZW5Wb2x1bWUAAAAAAQAAABMAAAABAAAAAAAAAApGZWVCYWxhbmNlAAAAAAABAAAAEwAAAAEAAAAAAAAABFdpbnMAAAABAAAAEwAAAAEAAAAAAAAAC1Nlc3Npb25QYWdlAAAAAAEAAAAEAAAAAAAAAAAAAAAMU2Vzc2lvblBhZ2VzAAAAAQAAAAAAAAANU2Vzc2lvblBhZ2VPZgAAAAAAAAEAAAAEAAAAAAAAAAAAAAALT3BlbkxvYmJpZXMAAAAAAAAAAAAAAAAMVmVyaWZ5aW5nS2V5AAAAAQAAAAAAAAAMRmluaXNoZWRHYW1lAAAAAQAAAAQAAAAAAAAAAAAAAAxBcHByb3ZlZEh1YnMAAAABAAAAAAAAAAtBY3RpdmVHYW1lcwAAAAABAAAAEw==",
+        "AAAAAQAAAAAAAAAAAAAAB0p1Z2Fkb3IAAAAADAAAAAAAAAAHYWRkcmVzcwAAAAATAAAAAAAAAAlhbnRlX3BhaWQAAAAAAAALAAAAAAAAAAZhdmF0YXIAAAAAAAQAAAAAAAAABGJvbmQAAAALAAAAAAAAAAxmaW5hbF9wb2ludHMAAAALAAAAAAAAAAdpbnN1cmVkAAAAAAEAAAAAAAAACGlzX2FsaXZlAAAAAQAAAAAAAAAGcG9pbnRzAAAAAAALAAAAAAAAAA5zaG90c19zdXJ2aXZlZAAAAAAABAAAAAAAAAAGc3Rha2VzAAAAAAPsAAAAEwAAAAsAAAAAAAAABHRlYW0AAAAEAAAAAAAAAA10aW1lb3V0c19sZWZ0AAAAAAAABA==",
+        "AAAAAQAAADJXaGVyZSB0aGUgY3VycmVudCByb3VuZCBzdGFuZHMsIHdpdGhvdXQgdGhlIGJ1bGxldAAAAAAAAAAAAAlSb3VuZEluZm8AAAAAAAAEAAAAAAAAAA9jdXJyZW50X2NoYW1iZXIAAAAABAAAAAAAAAAMbnVtX2NoYW1iZXJzAAAABAAAAAAAAAAFcm91bmQAAAAAAAAEAAAAAAAAAAtzaG90c19maXJlZAAAAAAE",
+        "AAAABQAAAENUaGUgc2hvb3RlcidzIHByb29mIGZhaWxlZDsgYXQgYG1heF9wcm9vZl9hdHRlbXB0c2AgdGhleSBhcmUga2lja2VkAAAAAAAAAAAJUHJvb2ZGYWlsAAAAAAAAAQAAAApwcm9vZl9mYWlsAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAAAAAAIYXR0ZW1wdHMAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAHNBIHRpbWVkIHR1cm4gYmVnYW46IGBwbGF5ZXJgIG11c3Qgc2hvb3QgYnkgYHR1cm5fZGVhZGxpbmVfbGVkZ2VyYCBvcgpjYW4gYmUga2lja2VkLCBzbyBjbGllbnRzIGNhbiBzaG93IGEgY291bnRkb3duAAAAAAAAAAAJVHVyblN0YXJ0AAAAAAAAAQAAAAp0dXJuX3N0YXJ0AAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAAAAAAUdHVybl9kZWFkbGluZV9sZWRnZXIAAAAEAAAAAAAAAAI=",
+        "AAAAAQAAAIdSYXJlbHktY2hhbmdpbmcgaGFsZiBvZiBhIHN0b3JlZCBzZXNzaW9uOiByb3N0ZXIsIHJ1bGVzLCBjb21taXRtZW50CmFuZCBzZXR0bGVtZW50LiBDYWxscyB0aGF0IG9ubHkgbW92ZSB0aGUgdHVybiBhbG9uZyBsZWF2ZSBpdCBhbG9uZS4AAAAAAAAAAApHYW1lU3RhdGljAAAAAAAaAAAAAAAAAApib25kX3Rva2VuAAAAAAPoAAAAEwAAAAAAAAARYnVsbGV0X2NvbW1pdG1lbnQAAAAAAAPuAAAAIAAAAAAAAAAOYnVsbGV0X2hpc3RvcnkAAAAAA+oAAAAEAAAAAAAAAA9idWxsZXRfcG9zaXRpb24AAAAABAAAAAAAAAALYnVsbGV0X3NhbHQAAAAD6AAAA+4AAAAgAAAAAAAAAAZjb25maWcAAAAAB9AAAAANU2Vzc2lvbkNvbmZpZwAAAAAAAAAAAAAOZmF0YWxfY2hhbWJlcnMAAAAAA+oAAAAGAAAAAAAAAA9maW5pc2hlZF9sZWRnZXIAAAAABAAAAAAAAAAQaG9ub3Jfc2hvdF90YWtlbgAAAAEAAAAAAAAABGhvc3QAAAATAAAAAAAAAAxodWJfb3ZlcnJpZGUAAAPoAAAAEwAAAAAAAAALaHViX3BsYXllcjEAAAAAEwAAAAAAAAALaHViX3BsYXllcjIAAAAAEwAAAAAAAAALbnVtX2J1bGxldHMAAAAABAAAAAAAAAAMbnVtX2NoYW1iZXJzAAAABAAAAAAAAAAQcGhhc2VfdGltZXN0YW1wcwAAA+wAAAAEAAAABAAAAAAAAAAHcGxheWVycwAAAAPqAAAH0AAAAAdKdWdhZG9yAAAAAAAAAAADcG90AAAAA+wAAAATAAAACwAAAAAAAAAXcmV2ZWFsX3dpbm5lcl9hdF9sZWRnZXIAAAAABAAAAAAAAAAFcm91bmQAAAAAAAAEAAAAAAAAAA1zYWx0X3JldmVhbGVkAAAAAAAAAQAAAAAAAAAFc2VhdHMAAAAAAAPsAAAAEwAAAAQAAAAAAAAAEHNlZWRfc2hvdHNfZmlyZWQAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAp0dXJuX29yZGVyAAAAAAPqAAAABAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
+        "AAAAAQAAAC5PbmUgYGRpc3BhcmFyYCBjYWxsLCBhcyB0aGUgY29udHJhY3QganVkZ2VkIGl0AAAAAAAAAAAAClNob3RSZWNvcmQAAAAAAAQAAAAAAAAAB2NoYW1iZXIAAAAABAAAAAAAAAADaGl0AAAAAAEAAAAAAAAABXJlYWNoAAAAAAAABAAAAAAAAAAHc2hvb3RlcgAAAAAT",
+        "AAAAAQAAAGJQZXItc2hvdCBoYWxmIG9mIGEgc3RvcmVkIHNlc3Npb24sIHRoZSBvbmx5IHBhcnQgYSBtaXNzIHJld3JpdGVzCmJlc2lkZXMgYXBwZW5kaW5nIHRvIHRoZSBzaG90IGxvZwAAAAAAAAAAAAtHYW1lRHluYW1pYwAAAAAOAAAAAAAAAA1hY3Rpb25zX2NvdW50AAAAAAAD7AAAABMAAAAEAAAAAAAAAA9hd2FpdGluZ19yZWxvYWQAAAAAAQAAAAAAAAAPY3VycmVudF9jaGFtYmVyAAAAAAQAAAAAAAAADGN1cnJlbnRfdHVybgAAAAQAAAAAAAAAFmludmFsaWRfcHJvb2ZfYXR0ZW1wdHMAAAAAAAQAAAAAAAAAEmxhc3RfYWN0aW9uX2xlZGdlcgAAAAAABAAAAAAAAAAKbGFzdF9hY3RvcgAAAAAAEwAAAAAAAAATcGVuZGluZ19lbGltaW5hdGlvbgAAAAPoAAAAEwAAAAAAAAAUcGVuZGluZ191bnRpbF9sZWRnZXIAAAAEAAAAAAAAAAVwaGFzZQAAAAAAAAQAAAAAAAAAC3Nob3RzX2ZpcmVkAAAAAAQAAAAAAAAADnNob3RzX3N1cnZpdmVkAAAAAAPqAAAABAAAAAAAAAAKc2tpcF92b3RlcwAAAAAD6gAAABMAAAAAAAAAFHR1cm5fZGVhZGxpbmVfbGVkZ2VyAAAABA==",
+        "AAAAAQAAACpBIHBsYXllcidzIHJlY29yZCBhY3Jvc3MgZmluaXNoZWQgc2Vzc2lvbnMAAAAAAAAAAAALUGxheWVyU3RhdHMAAAAAAgAAAAAAAAAMZ2FtZXNfcGxheWVkAAAABAAAAAAAAAAEd2lucwAAAAQ=",
+        "AAAAAQAAAFVBIGdhbWUncyByZXN1bHQsIGtlcHQgaW4gcGVyc2lzdGVudCBzdG9yYWdlIHNvIGl0IG91dGxpdmVzIHRoZQp0ZW1wb3JhcnkgZ2FtZSBlbnRyaWVzAAAAAAAAAAAAAAxGaW5pc2hlZEdhbWUAAAADAAAAAAAAAA9maW5pc2hlZF9sZWRnZXIAAAAABAAAAAAAAAAXcmV2ZWFsX3dpbm5lcl9hdF9sZWRnZXIAAAAABAAAAAAAAAAGd2lubmVyAAAAAAAT",
+        "AAAAAQAAAH9BIEdyb3RoMTYgcHJvb2Ygb3ZlciBCTjI1NC4gUG9pbnRzIHVzZSB0aGUgaG9zdCdzIHVuY29tcHJlc3NlZApFdGhlcmV1bS1zdHlsZSBlbmNvZGluZzsgYHB1YmxpY19pbnB1dHNgIGFyZSBiaWctZW5kaWFuIHNjYWxhcnMuAAAAAAAAAAAMR3JvdGgxNlByb29mAAAABAAAAAAAAAABYQAAAAAAA+4AAABAAAAAAAAAAAFiAAAAAAAD7gAAAIAAAAAAAAAAAWMAAAAAAAPuAAAAQAAAAAAAAAANcHVibGljX2lucHV0cwAAAAAAA+oAAAPuAAAAIA==",
+        "AAAAAQAAADdSZWRhY3RlZCBzZXNzaW9uIHNuYXBzaG90IHRoYXQgaXMgc2FmZSB0byBzaG93IHB1YmxpY2x5AAAAAAAAAAAMT2JzZXJ2ZXJJbmZvAAAABwAAAAAAAAAFYWxpdmUAAAAAAAPqAAAAEwAAAAAAAAARYnVsbGV0X2NvbW1pdG1lbnQAAAAAAAPuAAAAIAAAAAAAAAAPYnVsbGV0X3Bvc2l0aW9uAAAAA+gAAAAEAAAAAAAAAA5jdXJyZW50X3BsYXllcgAAAAAD6AAAABMAAAAAAAAABXBoYXNlAAAAAAAABAAAAAAAAAAFcm91bmQAAAAAAAAEAAAAAAAAAAtzaG90c19maXJlZAAAAAAE",
+        "AAAAAQAAADxHcm90aDE2IHZlcmlmeWluZyBrZXkgZm9yIHRoZSBzaG90IGNpcmN1aXQsIHNldCBieSB0aGUgYWRtaW4AAAAAAAAADFZlcmlmeWluZ0tleQAAAAUAAAAAAAAABWFscGhhAAAAAAAD7gAAAEAAAAAAAAAABGJldGEAAAPuAAAAgAAAAAAAAAAFZGVsdGEAAAAAAAPuAAAAgAAAAAAAAAAFZ2FtbWEAAAAAAAPuAAAAgAAAAAAAAAACaWMAAAAAA+oAAAPuAAAAQA==",
+        "AAAABQAAACpBIHNlc3Npb24gbW92ZWQgYmV0d2VlbiBwaGFzZXMgKGBQSEFTRV8qYCkAAAAAAAAAAAAMUGhhc2VDaGFuZ2VkAAAAAQAAAA1waGFzZV9jaGFuZ2VkAAAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAACmZyb21fcGhhc2UAAAAAAAQAAAAAAAAAAAAAAAh0b19waGFzZQAAAAQAAAAAAAAAAAAAAAlhdF9sZWRnZXIAAAAAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAE5BIHBsYXllciB0b29rIGEgc2VhdCBpbiB0aGUgbG9iYnksIHNvIGNsaWVudHMgY2FuIGJ1aWxkIHRoZSByb3N0ZXIKZnJvbSBldmVudHMAAAAAAAAAAAAMUGxheWVySm9pbmVkAAAAAQAAAA1wbGF5ZXJfam9pbmVkAAAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAAAAAACnNlYXRfaW5kZXgAAAAAAAQAAAAAAAAAAAAAAA10b3RhbF9wbGF5ZXJzAAAAAAAABAAAAAAAAAAC",
+        "AAAAAQAAADZXaGF0IGEgY3lsaW5kZXIgd2lkZ2V0IG5lZWRzIHRvIGRyYXcgdGhlIGN1cnJlbnQgcm91bmQAAAAAAAAAAAANQ3lsaW5kZXJTdGF0ZQAAAAAAAAQAAAAAAAAADmJ1bGxldF92aXNpYmxlAAAAAAPoAAAABAAAAAAAAAAPY3VycmVudF9jaGFtYmVyAAAAAAQAAAAAAAAADmZpcmVkX2NoYW1iZXJzAAAAAAPqAAAABAAAAAAAAAAMbnVtX2NoYW1iZXJzAAAABA==",
+        "AAAAAQAAAIJTaGFyZWFibGUgb3BlbmluZyBvZiBhIGZpbmlzaGVkIGdhbWUncyBidWxsZXQgY29tbWl0bWVudC4gQW55b25lIGNhbgpyZS1jaGVjayBpdDogYFNIQTI1NihzYWx0IHx8IGJ1bGxldF9wb3NpdGlvbikgPT0gY29tbWl0bWVudGAuAAAAAAAAAAAADUZhaXJuZXNzUHJvb2YAAAAAAAAEAAAAAAAAAA9idWxsZXRfcG9zaXRpb24AAAAABAAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAALc2hhX21hdGNoZXMAAAAAAQ==",
+        "AAAAAQAAAAAAAAAAAAAADVBhcnRpZGFSdWxldGEAAAAAAAApAAAAAAAAAA1hY3Rpb25zX2NvdW50AAAAAAAD7AAAABMAAAAEAAAAAAAAAA9hd2FpdGluZ19yZWxvYWQAAAAAAQAAAAAAAAAKYm9uZF90b2tlbgAAAAAD6AAAABMAAAAAAAAAEWJ1bGxldF9jb21taXRtZW50AAAAAAAD7gAAACAAAAAAAAAADmJ1bGxldF9oaXN0b3J5AAAAAAPqAAAABAAAAAAAAAAPYnVsbGV0X3Bvc2l0aW9uAAAAAAQAAAAAAAAAC2J1bGxldF9zYWx0AAAAA+gAAAPuAAAAIAAAAAAAAAAGY29uZmlnAAAAAAfQAAAADVNlc3Npb25Db25maWcAAAAAAAAAAAAAD2N1cnJlbnRfY2hhbWJlcgAAAAAEAAAAAAAAAAxjdXJyZW50X3R1cm4AAAAEAAAAAAAAAAplbGltaW5hdGVkAAAAAAPqAAAAEwAAAAAAAAAOZmF0YWxfY2hhbWJlcnMAAAAAA+oAAAAGAAAAAAAAAA9maW5pc2hlZF9sZWRnZXIAAAAABAAAAAAAAAAQaG9ub3Jfc2hvdF90YWtlbgAAAAEAAAAAAAAABGhvc3QAAAATAAAAAAAAAAxodWJfb3ZlcnJpZGUAAAPoAAAAEwAAAAAAAAALaHViX3BsYXllcjEAAAAAEwAAAAAAAAALaHViX3BsYXllcjIAAAAAEwAAAAAAAAAWaW52YWxpZF9wcm9vZl9hdHRlbXB0cwAAAAAABAAAAAAAAAASbGFzdF9hY3Rpb25fbGVkZ2VyAAAAAAAEAAAAAAAAAApsYXN0X2FjdG9yAAAAAAATAAAAAAAAAAtudW1fYnVsbGV0cwAAAAAEAAAAAAAAAAxudW1fY2hhbWJlcnMAAAAEAAAAAAAAABNwZW5kaW5nX2VsaW1pbmF0aW9uAAAAA+gAAAATAAAAAAAAABRwZW5kaW5nX3VudGlsX2xlZGdlcgAAAAQAAAAAAAAABXBoYXNlAAAAAAAABAAAAAAAAAAQcGhhc2VfdGltZXN0YW1wcwAAA+wAAAAEAAAABAAAAAAAAAAHcGxheWVycwAAAAPqAAAH0AAAAAdKdWdhZG9yAAAAAAAAAAADcG90AAAAA+wAAAATAAAACwAAAAAAAAAXcmV2ZWFsX3dpbm5lcl9hdF9sZWRnZXIAAAAABAAAAAAAAAAFcm91bmQAAAAAAAAEAAAAAAAAAA1zYWx0X3JldmVhbGVkAAAAAAAAAQAAAAAAAAAFc2VhdHMAAAAAAAPsAAAAEwAAAAQAAAAAAAAAEHNlZWRfc2hvdHNfZmlyZWQAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAhzaG90X2xvZwAAA+oAAAfQAAAAClNob3RSZWNvcmQAAAAAAAAAAAALc2hvdHNfZmlyZWQAAAAABAAAAAAAAAAKc2tpcF92b3RlcwAAAAAD6gAAABMAAAAAAAAAFHR1cm5fZGVhZGxpbmVfbGVkZ2VyAAAABAAAAAAAAAAKdHVybl9vcmRlcgAAAAAD6gAAAAQAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
+        "AAAAAQAAAD5QZXItc2Vzc2lvbiBydWxlcywgY2hvc2VuIGJ5IHRoZSBob3N0IGJlZm9yZSBhbnlvbmUgZWxzZSBqb2lucwAAAAAAAAAAAA1TZXNzaW9uQ29uZmlnAAAAAAAAGwAAAHRFYWNoIGFsaXZlIHBsYXllciBtdXN0IHBheSB0aGlzIG11Y2ggcGVyIHJvdW5kIHdpdGggYHBhZ2FyX2FudGVgLApvciBiZSBlbGltaW5hdGVkIHdoZW4gdGhlIHJvdW5kIGVuZHMgKDAgPSBubyBhbnRlKQAAAA5hbnRlX3Blcl9yb3VuZAAAAAAACwAAADlUb2tlbiB0aGUgYW50ZSBpcyBwYWlkIGluIChyZXF1aXJlZCB3aGVuIHRoZSBhbnRlIGlzIHNldCkAAAAAAAAKYW50ZV90b2tlbgAAAAAD6AAAABMAAACUU3RhdGljIG1vZGU6IHNoaWZ0IHRoZSBob3N0J3MgY29tbWl0dGVkIGZpcnN0IGJ1bGxldCBieSBhCnBlci1zZXNzaW9uIG9mZnNldCwgc28gYSBjb21taXRtZW50IHJldXNlZCBhY3Jvc3Mgc2Vzc2lvbnMgc3RpbGwKbG9hZHMgZGlmZmVyZW50IGN5bGluZGVycwAAABJiaW5kX3Nlc3Npb25fbm9uY2UAAAAAAAEAAAA+TG9hZCBvbmUgbW9yZSBidWxsZXQgb24gZXZlcnkgcmVsb2FkLCB1cCB0byBgbnVtX2NoYW1iZXJzIC0gMWAAAAAAABFidWxsZXRfZXNjYWxhdGlvbgAAAAAAAAEAAACkTWVya2xlIHJvb3Qgb2YgdGhlIGhvc3QncyBmdXR1cmUgcmVsb2FkIHBvc2l0aW9ucy4gV2hlbiBzZXQsIGVhY2gKcmVsb2FkIHdhaXRzIGZvciB0aGUgaG9zdCB0byByZXZlYWwgdGhlIG5leHQgbGVhZiB3aXRoIGByZWNhcmdhcmAKaW5zdGVhZCBvZiBkZXJpdmluZyBpdCBvbi1jaGFpbi4AAAAaYnVsbGV0X3NlcXVlbmNlX2NvbW1pdG1lbnQAAAAAA+gAAAPuAAAAIAAAAG5EaXN0aW5jdCBmYXRhbCBjaGFtYmVycyBlYWNoIGF1dG8tcmVsb2FkIGRyYXdzLCBiZWxvdwpgTlVNX0NIQU1CRVJTYCAoMCA9IG9uZSBidWxsZXQsIG9yIHRoZSBlc2NhbGF0aW9uIGNvdW50KQAAAAAAEWJ1bGxldHNfcGVyX3JvdW5kAAAAAAAABAAAAGhMZWRnZXJzIGEgaGl0IHBsYXllciBzdGF5cyBwZW5kaW5nIGJlZm9yZSB0aGUgZWxpbWluYXRpb24gaXMgZmluYWwsCmZvciBkZWF0aCBhbmltYXRpb25zICgwID0gaW1tZWRpYXRlKQAAABllbGltaW5hdGlvbl9kZWxheV9sZWRnZXJzAAAAAAAABAAAAE5FbnRyeSBmZWUgZWFjaCBsYXRlciBqb2luZXIgcGF5cyB0aGUgaG9zdCBkaXJlY3RseSwgb3V0c2lkZSB0aGUKcG90ICgwID0gZnJlZSkAAAAAAAhob3N0X2ZlZQAAAAsAAAA6VG9rZW4gYGhvc3RfZmVlYCBpcyBwYWlkIGluIChyZXF1aXJlZCB3aGVuIHRoZSBmZWUgaXMgc2V0KQAAAAAADmhvc3RfZmVlX3Rva2VuAAAAAAPoAAAAEwAAAJVCdXktYmFjazogYmFzaXMgcG9pbnRzIG9mIHRoZWlyIHN0YWtlcyBhbiBlbGltaW5hdGVkIHBsYXllciBnZXRzCmJhY2sgb24gdGhlIHNwb3QsIG91dCBvZiB0aGUgcG90IChiZWxvdyAxMDAwMCwgc28gdGhlIHdpbm5lcgphbHdheXMga2VlcHMgc29tZXRoaW5nKQAAAAAAABBsb3Nlcl9yZWZ1bmRfYnBzAAAABAAAAGlGYWlsZWQgcHJvb2ZzIHRoZSBzaG9vdGVyIG1heSBzdWJtaXQgaW4gb25lIHR1cm4gYmVmb3JlIGJlaW5nCmtpY2tlZCAoMCA9IGEgYmFkIHByb29mIGlzIHNpbXBseSByZWplY3RlZCkAAAAAAAASbWF4X3Byb29mX2F0dGVtcHRzAAAAAAAEAAAAM1doZXRoZXIgdGhlIGJ1bGxldCBwb3NpdGlvbiBtYXkgYmUgc2hvd24gKGBNT0RFXypgKQAAAAAEbW9kZQAAAAQAAAArSG93IHRoZSBwb3QgcmVhY2hlcyB0aGUgd2lubmVyIChgUEFZT1VUXypgKQAAAAALcGF5b3V0X21vZGUAAAAABAAAAItQb3Qgc2hhcmVzIGluIGJhc2lzIHBvaW50cyBieSBmaW5pc2hpbmcgcG9zaXRpb24gKHdpbm5lciBmaXJzdCwKdGhlbiB0aGUgbGFzdCBlbGltaW5hdGVkLCAuLi4pLCBzdW1taW5nIHRvIDEwMDAwLiBFbXB0eSA9IHdpbm5lcgp0YWtlcyBhbGwuAAAAAAxwcml6ZV9sYWRkZXIAAAPqAAAABAAAADFXaGljaCB2ZXJpZmllciBgZGlzcGFyYXJgIHJ1bnMgKGBQUk9PRl9TWVNURU1fKmApAAAAAAAADHByb29mX3N5c3RlbQAAAAQAAABDTGFzdCBsZWRnZXIgbmV3IHBsYXllcnMgbWF5IGpvaW4gb24gKDAgPSBvcGVuIHVudGlsIGZ1bGwgb3IgbG9hZGVkKQAAAAAccmVnaXN0cmF0aW9uX2RlYWRsaW5lX2xlZGdlcgAAAAQAAAA6SG93IHRoZSBjeWxpbmRlciByZWxvYWRzIGFmdGVyIGFuIGVsaW1pbmF0aW9uIChgUkVMT0FEXypgKQAAAAAAC3JlbG9hZF9tb2RlAAAAAAQAAAB2TGVkZ2VycyBhZnRlciB0aGUgZmluaXNoIGJlZm9yZSBgcmV2ZWxhcl9iYWxhYCBpcyBhY2NlcHRlZCwgc28KYXVkaXRvcnMgZ2V0IGEgd2luZG93IHdoZXJlIHRoZSBjb21taXRtZW50IHN0YW5kcyBhbG9uZQAAAAAAFHJldmVhbF9hZnRlcl9sZWRnZXJzAAAABAAAAD1MZWRnZXJzIGFmdGVyIHRoZSBmaW5hbCBzaG90IGJlZm9yZSBxdWVyaWVzIHJldmVhbCB0aGUgd2lubmVyAAAAAAAAFHJldmVhbF9kZWxheV9sZWRnZXJzAAAABAAAAEJSZS1kcmF3IHRoZSBhbGl2ZSBwbGF5ZXJzJyB0dXJuIG9yZGVyIGZyb20gdGhlIHNlZWQgb24gZWFjaCByZWxvYWQAAAAAABJzaHVmZmxlX2VhY2hfcm91bmQAAAAAAAEAAABZV2hldGhlciBhIHBhc3NlZCBza2lwIHZvdGUgZWxpbWluYXRlcyB0aGUgY3VycmVudCBwbGF5ZXIgKGBmYWxzZWAKanVzdCBwYXNzZXMgdGhlaXIgdHVybikAAAAAAAAUc2tpcF92b3RlX2VsaW1pbmF0ZXMAAAABAAAAmkxlZGdlcnMgdGhlIGdhbWUgbXVzdCBzaXQgaWRsZSAoc2luY2UgYGxhc3RfYWN0aW9uX2xlZGdlcmApIGJlZm9yZQphIHNraXAgdm90ZSBtYXkgb3Blbi4gQXQgbGVhc3QgMSwgYW5kIGJlbG93IHRoZSBoYXJkIHR1cm4gdGltZW91dAp3aGVuIHR1cm5zIGFyZSB0aW1lZC4AAAAAABZza2lwX3ZvdGVfaWRsZV9sZWRnZXJzAAAAAAAEAAAAYU9uY2UgYHNob3RzX2ZpcmVkYCBleGNlZWRzIHRoaXMsIGVhY2ggcmVsb2FkIHJlbW92ZXMgb25lIGNoYW1iZXIKZnJvbSB0aGUgY3lsaW5kZXIgKDAgPSBkaXNhYmxlZCkAAAAAAAASc3VkZGVuX2RlYXRoX2FmdGVyAAAAAAAEAAAAhVR3byB0ZWFtcyAoam9pbmVycyBmaWxsIHRoZSBzbWFsbGVyIG9uZSwgdXAgdG8gMnYyKTsgdGhlIGdhbWUgZW5kcwp3aGVuIG9ubHkgb25lIHRlYW0gaGFzIGxpdmluZyBtZW1iZXJzIGFuZCB0aGF0IHRlYW0gc3BsaXRzIHRoZSBwb3QAAAAAAAAJdGVhbV9tb2RlAAAAAAAAAQAAAC1MZWRnZXJzIG9uZSB0aW1lb3V0IGFkZHMgdG8gdGhlIHR1cm4gZGVhZGxpbmUAAAAAAAAZdGltZW91dF9leHRlbnNpb25fbGVkZ2VycwAAAAAAAAQAAABbVGFjdGljYWwgdGltZW91dHMgKGBwZWRpcl90aWVtcG9gKSBlYWNoIHBsYXllciBnZXRzIHBlciBnYW1lLCB1cAp0byBgTUFYX1RBQ1RJQ0FMX1RJTUVPVVRTYAAAAAATdGltZW91dHNfcGVyX3BsYXllcgAAAAAEAAAAI0luaXRpYWwgdHVybiBvcmRlciAoYFRVUk5fT1JERVJfKmApAAAAAA90dXJuX29yZGVyX21vZGUAAAAABA==",
+        "AAAAAAAAAE5BcHByb3ZlIGFub3RoZXIgaHViIGZvciBzZXNzaW9ucyB0byByZXBvcnQgdG8uIEl0IG11c3QgYmUgYQpkZXBsb3llZCBjb250cmFjdC4AAAAAAAdhZGRfaHViAAAAAAEAAAAAAAAAA2h1YgAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAMxTdGFrZSBgYW1vdW50YCBvZiBhbiBhZG1pbi1hcHByb3ZlZCB0b2tlbiBpbnRvIHRoZSBwb3Qgd2hpbGUgdGhlCmxvYmJ5IGlzIG9wZW4uIExvYmJpZXMgbWF5IG1peCBjdXJyZW5jaWVzOyB0aGUgd2lubmVyIHJlY2VpdmVzIGVhY2gKdG9rZW4ncyBiYWxhbmNlIHNlcGFyYXRlbHkuIFJldHVybnMgdGhlIHBsYXllcidzIHRvdGFsIGluIGB0b2tlbl9hZGRyYC4AAAAHYXBvc3RhcgAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACnRva2VuX2FkZHIAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAACwAAAAM=",
         "AAAAAAAAAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
         "AAAAAAAAAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
         "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
-        "AAAAAAAAAaFUaGUgY3VycmVudCBwbGF5ZXIgcHVsbHMgdGhlIHRyaWdnZXIuIFRoZSBjb250cmFjdCBkZXRlcm1pbmVzCndoZXRoZXIgdGhpcyBjaGFtYmVyIGhvbGRzIHRoZSBidWxsZXQgKHN0YXRpYyBjeWxpbmRlcikuCmBjdXJyZW50X2NoYW1iZXJgIGluY3JlbWVudHMgZWFjaCBzaG90LiBXaGVuIGl0IGVxdWFscwpgYnVsbGV0X3Bvc2l0aW9uYCDihpIgZ3VhcmFudGVlZCBkZWF0aC4KCkFmdGVyIGVsaW1pbmF0aW9uLCBpZiAyKyBwbGF5ZXJzIHJlbWFpbiwgdGhlIGN5bGluZGVyIGF1dG8tcmVsb2Fkcwp3aXRoIGEgbmV3IGRldGVybWluaXN0aWMgYnVsbGV0IHBvc2l0aW9uIGZvciB0aGUgbmV4dCByb3VuZC4KCiMgUmV0dXJucwpgdHJ1ZWAgaWYgdGhlIHBsYXllciB3YXMgaGl0IChlbGltaW5hdGVkKSwgYGZhbHNlYCBpZiBzdXJ2aXZlZC4AAAAAAAAIZGlzcGFyYXIAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACHprX3Byb29mAAAD7gAAACAAAAABAAAD6QAAAAEAAAAD",
-        "AAAAAAAAABNHZXQgZnVsbCBnYW1lIHN0YXRlAAAAAAhnZXRfZ2FtZQAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAANUGFydGlkYVJ1bGV0YQAAAAAAAAM=",
+        "AAAABQAAAG1UaGUgY3lsaW5kZXIgYXV0by1yZWxvYWRlZCBhZnRlciBhbiBlbGltaW5hdGlvbi4gVGhlIG5ldyBwb3NpdGlvbiBpcwpvbmx5IGluY2x1ZGVkIGluIHN0YXRpYyAocHJhY3RpY2UpIG1vZGUuAAAAAAAAAAAAABBDeWxpbmRlclJlbG9hZGVkAAAAAQAAABFjeWxpbmRlcl9yZWxvYWRlZAAAAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAAAAAAAAVyb3VuZAAAAAAAAAQAAAAAAAAAAAAAAA9idWxsZXRfcG9zaXRpb24AAAAD6AAAAAQAAAAAAAAAAg==",
+        "AAAAAAAAA5dUaGUgY3VycmVudCBwbGF5ZXIgcHVsbHMgdGhlIHRyaWdnZXIuIFRoZSBjb250cmFjdCBkZXRlcm1pbmVzCndoZXRoZXIgdGhpcyBjaGFtYmVyIGhvbGRzIHRoZSBidWxsZXQgKHN0YXRpYyBjeWxpbmRlcikuCmBjdXJyZW50X2NoYW1iZXJgIGluY3JlbWVudHMgZWFjaCBzaG90LiBXaGVuIGl0IGVxdWFscwpgYnVsbGV0X3Bvc2l0aW9uYCDihpIgZ3VhcmFudGVlZCBkZWF0aC4KCkFmdGVyIGVsaW1pbmF0aW9uLCBpZiAyKyBwbGF5ZXJzIHJlbWFpbiwgdGhlIGN5bGluZGVyIGF1dG8tcmVsb2Fkcwp3aXRoIGEgbmV3IGRldGVybWluaXN0aWMgYnVsbGV0IHBvc2l0aW9uIGZvciB0aGUgbmV4dCByb3VuZC4KCkFuIGluc3VyZWQgc2hvb3RlciB3aG8gaXMgaGl0IHN1cnZpdmVzIGluc3RlYWQ6IHRoZSBwb2xpY3kgaXMKY29uc3VtZWQgYW5kIHRoZSBjeWxpbmRlciByZWxvYWRzLgoKV2l0aCBgbWF4X3Byb29mX2F0dGVtcHRzYCBzZXQsIGEgZmFpbGVkIHByb29mIGRvZXNuJ3QgZXJyb3IgKGFuCmVycm9yIHdvdWxkIHJvbGwgdGhlIGNvdW50IGJhY2spOiBpdCBjb3VudHMgYWdhaW5zdCB0aGUgc2hvb3RlcgooYFNIT1RfUFJPT0ZfUkVKRUNURURgLCB0dXJuIHVuY2hhbmdlZCksIGFuZCB0aGUgbGFzdCBhbGxvd2VkCmZhaWx1cmUga2lja3MgdGhlbSBsaWtlIGFuIEFGSyBwbGF5ZXIgKGBTSE9UX0tJQ0tFRGApLgoKQSBnYW1lIGZvdW5kIGluIHBsYXkgd2l0aCBvbmUgc2lkZSBsZWZ0LCBvciBub25lLCBpcyBvdmVyOiB0aGUKc2hvdCBpcyByZWZ1c2VkIHdpdGggYEdhbWVBbHJlYWR5RW5kZWRgLgoKIyBSZXR1cm5zCmBTSE9UX0hJVGAgaWYgdGhlIHBsYXllciB3YXMgaGl0LCBgU0hPVF9TVVJWSVZFRGAgaWYgdGhleSBzdXJ2aXZlZCwKb3Igb25lIG9mIHRoZSBuby1zaG90IG91dGNvbWVzIGFib3ZlIChgU0hPVF8qYCkuAAAAAAhkaXNwYXJhcgAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAIemtfcHJvb2YAAAAOAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAARBHZXQgZnVsbCBnYW1lIHN0YXRlLiBEdXJpbmcgYSByZXZlYWwgZGVsYXkgdGhlIGdhbWUgYWxyZWFkeQpyZWFkcyBhcyBmaW5pc2hlZCBidXQgYHdpbm5lcmAgaXMgd2l0aGhlbGQsIGFuZCBgYnVsbGV0X3NhbHRgIGlzCndpdGhoZWxkIHVudGlsIGByZXZlbGFyX2JhbGFgLiBIaWRkZW4tbW9kZSBnYW1lcyByZXBvcnQKYEJVTExFVF9QT1NJVElPTl9ISURERU5gIGFuZCBlbXB0eSBgYnVsbGV0X2hpc3RvcnlgIGFuZApgZmF0YWxfY2hhbWJlcnNgIHVudGlsIHRoZXkgZmluaXNoLgAAAAhnZXRfZ2FtZQAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAANUGFydGlkYVJ1bGV0YQAAAAAAAAM=",
+        "AAAAAAAAAGRUaGUgc2Vzc2lvbidzIGhvc3QsIHdobyBsb2FkcyB0aGUgcmV2b2x2ZXIuIFBhc3NlcyB0byB0aGUgZmlyc3QKc2VhdCB3aGVuIHRoZSBob3N0IGxlYXZlcyB0aGUgbG9iYnkuAAAACGdldF9ob3N0AAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAAD1HZXQgYSBwbGF5ZXIncyBzZWF0IChpbmRleCBpbnRvIGBwbGF5ZXJzYCkgdmlhIHRoZSBzZWF0IGluZGV4AAAAAAAACGdldF9zZWF0AAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAADxXaGV0aGVyIG9uZSBwbGF5ZXIgaXMgc3RpbGwgYWxpdmUsIHdpdGhvdXQgZmV0Y2hpbmcgdGhlIGxpc3QAAAAIaXNfYWxpdmUAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAAAEAAAAD",
+        "AAAAAAAAAfJXaXRoIGEgYGJ1bGxldF9zZXF1ZW5jZV9jb21taXRtZW50YCwgZXZlcnkgcmVsb2FkIHdhaXRzIGhlcmUuIFRoZQpob3N0IG9wZW5zIHJlbG9hZCBgcm91bmQgLSAxYCdzIGxlYWYsIGBjb21wdXRlX2J1bGxldF9oYXNoKHNhbHQsCnZhbHVlKWAsIHdpdGggYSBNZXJrbGUgcHJvb2YgYWdhaW5zdCB0aGUgcm9vdC4gVGhlIGN5bGluZGVyIGlzCmxvYWRlZCBsaWtlIGFueSBvdGhlciByZWxvYWQsIHdpdGggdGhlIHJvdW5kJ3MgYnVsbGV0IGNvdW50OiB0aGUKZmlyc3QgYnVsbGV0IGxhbmRzIGF0IGBjdXJyZW50X2NoYW1iZXIgKyB2YWx1ZSAlIGNoYW1iZXJzX2xlZnRgLCBzbwpsZWF2ZXMgY29tbWl0dGVkIHVwIGZyb250IGZpdCB3aGF0ZXZlciB0aGUgY3lsaW5kZXIgbG9va3MgbGlrZSBieQp0aGVuLCBhbmQgYW55IGZ1cnRoZXIgYnVsbGV0cyBhcmUgZHJhd24gZnJvbSB0aGUgbGVhZidzIHJlbWFpbmluZwpieXRlcy4gUmV0dXJucyB0aGUgbmV3IGJ1bGxldCBwb3NpdGlvbi4AAAAAAAhyZWNhcmdhcgAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABGhvc3QAAAATAAAAAAAAAAV2YWx1ZQAAAAAAAAQAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAAAAAAFcHJvb2YAAAAAAAPqAAAD7gAAACAAAAABAAAD6QAAAAQAAAAD",
         "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAEdSZWxvYWRzIHRoZSBzZXNzaW9uIGhhcyBnb25lIHRocm91Z2ggKDAgPSBzdGlsbCBvbiB0aGUgaG9zdCdzIGN5bGluZGVyKQAAAAAJZ2V0X3JvdW5kAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAABAAAAAM=",
         "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAAKZ2V0X2NvbmZpZwAAAAAAAAAAAAEAAAfQAAAABkNvbmZpZwAA",
+        "AAAAAAAAASpHZXQgdGhlIHdpbm5lciBvZiBhIGZpbmlzaGVkIGdhbWUsIG9uY2UgaXRzIHJldmVhbCBsZWRnZXIgaXMKcmVhY2hlZCAoYE5vbmVgIHdoaWxlIGl0IGlzIHN0aWxsIGJlaW5nIHBsYXllZCkuIEZhbGxzIGJhY2sgdG8gdGhlCnBlcnNpc3RlbnQgYEZpbmlzaGVkR2FtZWAgb25jZSB0aGUgbGl2ZSBlbnRyaWVzIGhhdmUgZXhwaXJlZC4gVGhlCmRlbGF5IG9ubHkgcGFjZXMgZnJvbnQtZW5kczogdGhlIGh1YiBpcyB0b2xkIGF0IHRoZSBmaW5hbCBzaG90IGFuZAp0aGUgYHdpbm5lcmAgZXZlbnQgaXMgcHVibGlzaGVkIHRoZW4uAAAAAAAKZ2V0X3dpbm5lcgAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAD6AAAABMAAAAD",
+        "AAAAAAAAAOtJbiBhbiBhbnRlIHNlc3Npb24sIGVhY2ggYWxpdmUgcGxheWVyIHBheXMgYXQgbGVhc3QKYGFudGVfcGVyX3JvdW5kYCBwZXIgcm91bmQgaW50byB0aGUgcG90IChhZGRpbmcgdG8gdGhlaXIgc3Rha2UpLgpBbnlvbmUgc2hvcnQgd2hlbiB0aGUgcm91bmQgZW5kcyBpcyBlbGltaW5hdGVkIGFuZCB0aGVpciBzdGFrZQpzdGF5cyBpbiB0aGUgcG90LiBSZXR1cm5zIHdoYXQgdGhleSd2ZSBwYWlkIHRoaXMgcm91bmQuAAAAAApwYWdhcl9hbnRlAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAQZEZWNvZGUgYW4gWERSLXNlcmlhbGl6ZWQgYFBhcnRpZGFSdWxldGFgIChlLmcuIGEgc2F2ZWQgYGdldF9nYW1lYApyZXN1bHQpIGZvciBpbmRleGVycyBhbmQgZGVidWdnaW5nIHNjcmlwdHMuIFdlbGwtZm9ybWVkIFhEUiBvZiBhbnkKb3RoZXIgc2hhcGUsIGluY2x1ZGluZyBhIGdhbWUgZnJvbSBhbiBvbGRlciBzY2hlbWEsIHJldHVybnMKYFVua25vd25TY2hlbWFgOyBieXRlcyB0aGF0IGFyZW4ndCBYRFIgYXQgYWxsIHN0aWxsIHRyYXAgaW4gdGhlIGhvc3QuAAAAAAAKcGFyc2VfZ2FtZQAAAAAAAQAAAAAAAAAEZGF0YQAAAA4AAAABAAAD6QAAB9AAAAANUGFydGlkYVJ1bGV0YQAAAAAAAAM=",
+        "AAAAAAAAAEhXaXRoZHJhdyBhIGh1YidzIGFwcHJvdmFsLiBTZXNzaW9ucyBhbHJlYWR5IGxvYWRlZCBrZWVwIHJlcG9ydGluZwp0byBpdC4AAAAKcmVtb3ZlX2h1YgAAAAAAAQAAAAAAAAADaHViAAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAAAAAAAKc2V0X2NvbmZpZwAAAAAAAQAAAAAAAAAGY29uZmlnAAAAAAfQAAAABkNvbmZpZwAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAD5SZWxlYXNlIG51bWJlciBvZiB0aGUgV0FTTSBjdXJyZW50bHkgbGl2ZSAoYENPTlRSQUNUX1ZFUlNJT05gKQAAAAAAC2dldF92ZXJzaW9uAAAAAAAAAAABAAAABA==",
+        "AAAAAAAAALpUaGUgY3VycmVudCBzaG9vdGVyIHNwZW5kcyBvbmUgb2YgdGhlaXIgdGltZW91dHMgdG8gcHVzaCB0aGUgdHVybgpkZWFkbGluZSBiYWNrIGJ5IGB0aW1lb3V0X2V4dGVuc2lvbl9sZWRnZXJzYC4gT25seSBtZWFuaW5nZnVsIHdoZW4KdHVybnMgYXJlIHRpbWVkLiBSZXR1cm5zIHRoZSB0aW1lb3V0cyB0aGV5IGhhdmUgbGVmdC4AAAAAAAxwZWRpcl90aWVtcG8AAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAUtPbmNlIHRoZSBnYW1lIGlzIGZpbmlzaGVkIHRoZSBob3N0IHB1Ymxpc2hlcyB0aGUgc2FsdCBiZWhpbmQgdGhlCmNvbW1pdG1lbnQsIHNvIGFueW9uZSBjYW4gY2hlY2sgdGhlIGluaXRpYWwgYnVsbGV0IHdhc24ndCBtb3ZlZC4KVGhlIHNhbHQgd2FzIHN0b3JlZCBhdCBsb2FkOyB0aGlzIG1hcmtzIGl0IHJldmVhbGVkLCBhZnRlciB3aGljaApxdWVyaWVzIHNlcnZlIGl0LiBBIHNhbHQgdGhhdCBkb2Vzbid0IG9wZW4gdGhlIGNvbW1pdG1lbnQgaXMKcmVmdXNlZC4gUmVmdXNlZCB1bnRpbCBgcmV2ZWFsX2FmdGVyX2xlZGdlcnNgIGhhdmUgcGFzc2VkIHNpbmNlCnRoZSBmaW5pc2guAAAAAAxyZXZlbGFyX2JhbGEAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAARob3N0AAAAEwAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAqdPbmNlIHRoZSBnYW1lIGhhcyBzYXQgaWRsZSBmb3IgYHNraXBfdm90ZV9pZGxlX2xlZGdlcnNgIChhIHNvZnQKdGhyZXNob2xkLCBzaG9ydCBvZiB0aGUgaGFyZCB0aW1lb3V0KSwgdGhlIG90aGVyIGFsaXZlIHBsYXllcnMgbWF5CnZvdGUgdG8gc2tpcCB3aG9ldmVyIGhvbGRzIHRoZSB0dXJuLCB0aW1lZCBnYW1lIG9yIG5vdC4gQmVmb3JlCnRoYXQgdGhlIGZpcnN0IHZvdGUgaXMgcmVmdXNlZCAoYFR1cm5Ob3RFeHBpcmVkYCksIHNvIGEgZnJlc2ggdHVybgpjYW4ndCBiZSB2b3RlZCBvdXQ7IG9uY2UgYSB2b3RlIGlzIG9wZW4sIGxhdGVyIHZvdGVzIGNvdW50IHVudGlsCnRoZSB0dXJuIG1vdmVzLiBBIG1ham9yaXR5IG9mIHRoZSBvdGhlcnMgc2tpcHMgdGhlIGN1cnJlbnQgcGxheWVyLgpFbGltaW5hdGluZyBpbnN0ZWFkLCBwZXIgYHNraXBfdm90ZV9lbGltaW5hdGVzYCwgdGFrZXMgYXQgbGVhc3QgdHdvCnZvdGVzIHRoYXQgYXJlIGFsc28gYSBzdHJpY3QgbWFqb3JpdHkgb2YgYWxsIGxpdmluZyBwbGF5ZXJzLCBzbyBhCmxvbmUgb3Bwb25lbnQgY2FuJ3Qgdm90ZSB0aGUgc2hvb3RlciBvdXQuIFZvdGVzIHJlc2V0IHdoZW4gYSB2b3RlCnBhc3Nlcy4gVGhlIGN1cnJlbnQgcGxheWVyIGNhbm5vdCB2b3RlIChgTm90UGxheWVyYCkuIFJldHVybnMKd2hldGhlciB0aGUgdm90ZSBwYXNzZWQuAAAAAAx2b3Rhcl9zYWx0YXIAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAV2b3RlcgAAAAAAABMAAAABAAAD6QAAAAEAAAAD",
         "AAAAAAAAABFHZXQgYWxpdmUgcGxheWVycwAAAAAAAAx3aG9faXNfYWxpdmUAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAPqAAAAEwAAAAM=",
         "AAAAAAAAACtDb25zdHJ1Y3Rvcjogc3RvcmUgYWRtaW4gKyBHYW1lIEh1YiBhZGRyZXNzAAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAAJhUaGUgZmlyc3QgcGxheWVyIChob3N0KSBzZXRzIHRoZSBidWxsZXQgcG9zaXRpb24gYW5kIHN0YXJ0cyB0aGUgZ2FtZS4KUmVxdWlyZXMgYXQgbGVhc3QgMiBwbGF5ZXJzIChtYXggMykuCkFsc28gcmVnaXN0ZXJzIHRoZSBzZXNzaW9uIHdpdGggdGhlIEdhbWUgSHViLgAAAA9jYXJnYXJfcmV2b2x2ZXIAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAABFidWxsZXRfY29tbWl0bWVudAAAAAAAA+4AAAAgAAAAAAAAAA9idWxsZXRfcG9zaXRpb24AAAAABAAAAAEAAAPpAAAAAgAAAAM=",
-        "AAAAAAAAAH9SZWdpc3RlciBhIHBsYXllciBpbnRvIHRoZSBzZXNzaW9uLiBTdXBwb3J0cyAyLTMgcGxheWVycy4KVGhlIGhvc3QgbXVzdCBjYWxsIGBjYXJnYXJfcmV2b2x2ZXJgIG9uY2UgZW5vdWdoIHBsYXllcnMgaGF2ZSBqb2luZWQuAAAAABJlbnRyYXJfYV9sYV9ydWxldGEAAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAGcG9pbnRzAAAAAAALAAAAAQAAA+kAAAAEAAAAAw==",
-        "AAAAAAAAAGRTSEEyNTYgY29tbWl0bWVudCBoZWxwZXIgKGZvciBvZmYtY2hhaW4gdXNlIGFuZCB0ZXN0aW5nKQpSZXR1cm5zIFNIQTI1NihzYWx0X2J5dGVzIHx8IHBvc2l0aW9uX2J5dGUpAAAAE2NvbXB1dGVfYnVsbGV0X2hhc2gAAAAAAgAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAAAAAAhwb3NpdGlvbgAAAAQAAAABAAAD7gAAACA=" ]),
+        "AAAAAAAAADJQZXItdG9rZW4gcG90IGJhbGFuY2VzLCBmb3IgbWl4ZWQtY3VycmVuY3kgbG9iYmllcwAAAAAADWdldF9tdWx0aV9wb3QAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAPqAAAD7QAAAAIAAAATAAAACwAAAAM=",
+        "AAAAAAAAALVEZXYtb25seTogZGVsZXRlIGEgc2Vzc2lvbidzIHN0b3JhZ2Ugc28gdGhlIHNhbWUgc2Vzc2lvbiBpZCBjYW4KYmUgcmVjcmVhdGVkIGZyZXNoLiBFc2Nyb3dlZCBib25kcyBhbmQgc3Rha2VzIGFyZSBub3QgcmVmdW5kZWQuCkZhaWxzIHdpdGggYERldk1vZGVEaXNhYmxlZGAgdW5sZXNzIGBkZXZfbW9kZWAgaXMgb24uAAAAAAAADXJlc2V0X3Nlc3Npb24AAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAjZXaXRoaW4gdGhlIGhvbm9yIHdpbmRvdyBhZnRlciB0aGUgZ2FtZSBlbmRzLCB0aGUgd2lubmVyIG1heSBmaXJlCm9uZSBtb3JlIGNoYW1iZXIuIFRoZSBjeWxpbmRlciBpcyBsb2FkZWQgdGhlIHdheSB0aGUgbmV4dCByb3VuZCdzCnJlbG9hZCB3b3VsZCBsb2FkIGl0LCBhbmQgdGhlIHByb29mIG11c3QgY292ZXIgdGhlIGNoYW1iZXIgZmlyZWQuClN1cnZpdmluZyBhZGRzIGBicmF2ZXJ5X2JvbnVzYCB0byB0aGUgd2lubmVyJ3MgcmVjb3JkZWQgcG9pbnRzOwpnZXR0aW5nIGhpdCB0YWtlcyBpdCBhd2F5IGFuZCBmb3JmZWl0cyBgaG9ub3JfZm9yZmVpdF9icHNgIG9mIHRoZQpwb3QgdG8gcHJvdG9jb2wgZmVlcy4gV2hpbGUgdGhlIHdpbmRvdyBpcyBvcGVuIHRoZSBwb3Qgc3RheXMgaW4KZXNjcm93LCBzbyBhIGhpdCBhbHdheXMgaGFzIGl0IGF0IHN0YWtlOyBpbiBgUEFZT1VUX0FVVE9gIHNlc3Npb25zCnRoZSByZXN0IGlzIHBhaWQgb3V0IG9uY2UgdGhlIHNob3QgbGFuZHMuCgojIFJldHVybnMKYHRydWVgIGlmIHRoZSB3aW5uZXIgd2FzIGhpdCwgYGZhbHNlYCBpZiB0aGV5IHN1cnZpdmVkLgAAAAAADXRpcm9fZGVfaG9ub3IAAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZ3aW5uZXIAAAAAABMAAAAAAAAACHprX3Byb29mAAAADgAAAAEAAAPpAAAAAQAAAAM=",
+        "AAAAAAAAALpEcnktcnVuIGBkaXNwYXJhcmAncyBjaGVja3MgZm9yIGBwbGF5ZXJgIHdpdGhvdXQgYSBwcm9vZiBvciBhbnkKc3RhdGUgY2hhbmdlOiBgT2tgIGlmIGEgc2hvdCB3b3VsZCBiZSBhY2NlcHRlZCwgZWxzZSB0aGUgZXhhY3QKZXJyb3IsIHNvIGEgVUkgY2FuIGRpc2FibGUgdGhlIGZpcmUgYnV0dG9uIHdpdGggdGhlIHJlYXNvbi4AAAAAAA12YWxpZGF0ZV9zaG90AAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAGZUcmFuc2ZlciB0aGUgcHJvdG9jb2wgZmVlcyBjb2xsZWN0ZWQgaW4gYHRva2VuYCB0byBgdG9gIGFuZCB6ZXJvCnRoZSBiYWxhbmNlLiBSZXR1cm5zIHRoZSBhbW91bnQgc2VudC4AAAAAAA13aXRoZHJhd19mZWVzAAAAAAAAAgAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAMBEdXJpbmcgcGxheSwgYW4gYWxpdmUgcGxheWVyIHBheXMgYSBwcmVtaXVtIChhdCBsZWFzdApgaW5zdXJhbmNlX3ByaWNlYCwgaW4gYW4gYXBwcm92ZWQgc3Rha2UgdG9rZW4pIGludG8gdGhlIHBvdC4gVGhlaXIKbmV4dCBoaXQgaXMgYWJzb3JiZWQgaW5zdGVhZCBvZiBlbGltaW5hdGluZyB0aGVtLiBPbmUgcG9saWN5IGF0IGEKdGltZS4AAAAOY29tcHJhcl9zZWd1cm8AAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAKdG9rZW5fYWRkcgAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAGxSb3VuZCwgY2hhbWJlciBhbmQgc2hvdCBjb3VudGVycyBpbiBvbmUgcmVhZC4gU2FmZSB0byBzaG93IGluIGFueQptb2RlOiB0aGUgYnVsbGV0IHBvc2l0aW9uIGlzIG5vdCBpbmNsdWRlZC4AAAAOZ2V0X3JvdW5kX2luZm8AAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAJUm91bmRJbmZvAAAAAAAAAw==",
+        "AAAAAAAAAEpXaGV0aGVyIGEgc2Vzc2lvbiBoYXMgYmVlbiBjcmVhdGVkICh3aXRob3V0IHRoZSBgR2FtZU5vdEZvdW5kYCByb3VuZC10cmlwKQAAAAAADnNlc3Npb25fZXhpc3RzAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAE=",
+        "AAAAAAAAALBTbmFwc2hvdCBmb3Igc3RyZWFtaW5nIG92ZXJsYXlzLCBwb2xsZWQgZWFjaCB0aWNrLiBDYXJyaWVzIG9ubHkKd2hhdCBpcyBzYWZlIHRvIGRpc3BsYXk6IHRoZSBidWxsZXQgcG9zaXRpb24gaXMgd2l0aGhlbGQgaW4KaGlkZGVuIG1vZGUsIGFuZCB0aGUgd2lubmVyIGlzIGxlZnQgdG8gYGdldF93aW5uZXJgLgAAAA5zdWJzY3JpYmVfaW5mbwAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAAxPYnNlcnZlckluZm8AAAAD",
+        "AAAAAAAAAL9BZG1pbiBhYm9ydDogbGlrZSBgcmVzb2x2ZXJfbWFudWFsbWVudGVgLCBidXQgdGhlIGNvbnRyYWN0IHBpY2tzCnRoZSB3aW5uZXIgdGhlIGh1YiBpcyB0b2xkIGFib3V0IOKAlCB0aGUgc3Vydml2b3Igd2l0aCB0aGUgbW9zdApwb2ludHMsIHRpZXMgZ29pbmcgdG8gdGhlIGxvd2VzdCBhZGRyZXNzLiBSZXR1cm5zIHRoYXQgd2lubmVyLgAAAAAPYWJvcnRhcl9wYXJ0aWRhAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAD3Jlc29sdXRpb25fbW9kZQAAAAAEAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAAY9GdWxsIGZhaXJuZXNzIGF1ZGl0LiBHaXZlbiB0aGUgaG9zdCdzIHNhbHQsIGNoZWNrcyB0aGUgY29tbWl0bWVudApvcGVucyB0byB0aGUgaW5pdGlhbCBidWxsZXQsIHJlLWRlcml2ZXMgZXZlcnkgcmVsb2FkIHBvc2l0aW9uCihzdWRkZW4gZGVhdGggaW5jbHVkZWQpIGFuZCByZXBsYXlzIHRoZSBzaG90IGxvZywgY29uZmlybWluZyBlYWNoCnJlY29yZGVkIGhpdCBvciBtaXNzIGlzIHdoYXQgdGhlIGN5bGluZGVyIGRpY3RhdGVkLiBSZXR1cm5zIGBmYWxzZWAKb24gdGhlIGZpcnN0IGluY29uc2lzdGVuY3kuCgpBIHJlbG9hZCBtYXkgbWF0Y2ggZWl0aGVyIHJlbG9hZCBtb2RlLCBzaW5jZSB0aGUgYWRtaW4gY2FuIHN3aXRjaAptb2RlcyBtaWQtZ2FtZSB3aXRoIGBjYW1iaWFyX3JlbG9hZF9tb2RlYC4AAAAAD2F1ZGl0YXJfcGFydGlkYQAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAARzYWx0AAAD7gAAACAAAAABAAAD6QAAAAEAAAAD",
+        "AAAAAAAAAkdUaGUgZmlyc3QgcGxheWVyIChob3N0KSBzZXRzIHRoZSBidWxsZXQgcG9zaXRpb24gYW5kIHN0YXJ0cyB0aGUgZ2FtZS4KUmVxdWlyZXMgYXQgbGVhc3QgMiBwbGF5ZXJzIChtYXggMykuIGBzYWx0YCBtdXN0IG9wZW4KYGJ1bGxldF9jb21taXRtZW50YCB0byBgYnVsbGV0X3Bvc2l0aW9uYC4gSXQgaXMgc3RvcmVkIHNvIGl0IGNhbgpsYXRlciBiZSByZXZlYWxlZCwgYW5kIHF1ZXJpZXMgaGlkZSBpdCB1bnRpbCBgcmV2ZWxhcl9iYWxhYC4KQWxzbyByZWdpc3RlcnMgdGhlIHNlc3Npb24gd2l0aCB0aGUgR2FtZSBIdWIg4oCUIGBodWJfb3ZlcnJpZGVgLCBpZgpnaXZlbiwgcmVwbGFjZXMgdGhlIGdsb2JhbCBodWIgZm9yIHRoaXMgc2Vzc2lvbiAoZS5nLiBhCnRvdXJuYW1lbnQncyBvd24gaHViKSBhbmQgbXVzdCBiZSBvbmUgb2YgdGhlIGFwcHJvdmVkIGh1YnMuIFdpdGgKYHNodWZmbGVfc2VhdGluZ2AgZXZlcnkgc2VhdCBpcyBkcmF3biBhdCByYW5kb20gYmVmb3JlIHBsYXkgYmVnaW5zLApzbyBhbnlvbmUgbWF5IHNob290IGZpcnN0LCBhbmQgdGhlIGh1YiBwYWlyIGZvbGxvd3MgdGhlIG5ldyBmaXJzdAp0d28gc2VhdHMuAAAAAA9jYXJnYXJfcmV2b2x2ZXIAAAAABwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAABFidWxsZXRfY29tbWl0bWVudAAAAAAAA+4AAAAgAAAAAAAAAA9idWxsZXRfcG9zaXRpb24AAAAABAAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAAAAAAxodWJfb3ZlcnJpZGUAAAPoAAAAEwAAAAAAAAAPc2h1ZmZsZV9zZWF0aW5nAAAAAAEAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAADhQcm90b2NvbCBmZWVzIGNvbGxlY3RlZCBpbiBgdG9rZW5gIGFuZCBub3QgeWV0IHdpdGhkcmF3bgAAAA9nZXRfZmVlX2JhbGFuY2UAAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAG9HZXQgdGhlIHR3byBwbGF5ZXJzIHJlZ2lzdGVyZWQgd2l0aCB0aGUgR2FtZSBIdWIgZm9yIHRoaXMgc2Vzc2lvbi4KT25seSBtZWFuaW5nZnVsIG9uY2UgdGhlIHJldm9sdmVyIGlzIGxvYWRlZC4AAAAAD2dldF9odWJfcGFpcmluZwAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAPtAAAAAgAAABMAAAATAAAAAw==",
+        "AAAAAAAAAFFTZXNzaW9uIGlkcyB0aGUgcGxheWVyIGhhcyB3b24sIG9sZGVzdCBmaXJzdCAodGhlIGxhdGVzdApgTUFYX1dJTlNfSElTVE9SWWAgb25seSkAAAAAAAAPZ2V0X3BsYXllcl93aW5zAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPqAAAABA==",
+        "AAAAAAAAAHNTaG90cyBmaXJlZCBzbyBmYXIsIGZhdGFsIG9uZXMgaW5jbHVkZWQuIFJlYWRzIG9ubHkgdGhlIGR5bmFtaWMKaGFsZiBvZiB0aGUgZ2FtZSwgc28gaXQgaXMgY2hlYXBlciB0aGFuIGBnZXRfZ2FtZWAuAAAAAA9nZXRfc2hvdHNfZmlyZWQAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAATVJbiBgUEFZT1VUX0NMQUlNYCBzZXNzaW9ucyB0aGUgcG90IHN0YXlzIGluIGVzY3JvdyBhZnRlciB0aGUgZ2FtZQplbmRzOyB0aGUgd2lubmVyIHB1bGxzIGl0IGhlcmUuIFNhZmVyIGZvciBjb250cmFjdC1hY2NvdW50IHdpbm5lcnMKdGhhdCBtaWdodCByZWplY3QgYSBwdXNoZWQgdHJhbnNmZXIuIEEgcG90IGhlbGQgZm9yIHRoZSBob25vcgp3aW5kb3cgaXMgcHVsbGVkIGhlcmUgdG9vLCBhbmQgY2xhaW1pbmcgaXQgcGFzc2VzIG9uIHRoZSBob25vcgpzaG90LiBSZXR1cm5zIGVhY2ggdG9rZW4gYW5kIHRoZSBhbW91bnQgcGFpZCBpbiBpdC4AAAAAAAAPcmVjbGFtYXJfcHJlbWlvAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABndpbm5lcgAAAAAAEwAAAAEAAAPpAAAD6gAAA+0AAAACAAAAEwAAAAsAAAAD",
+        "AAAAAAAAALpTZW5kIHRva2VucyB0aGF0IHJlYWNoZWQgdGhlIGNvbnRyYWN0IG91dHNpZGUgYW55IGdhbWUgdG8gYHRvYC4KT25seSB0aGUgc3VycGx1cyBvdmVyIGB0b3RhbF9saWFiaWxpdGllc2AgYW5kIHRoZSBmZWUgYmFsYW5jZSBjYW4KbGVhdmUsIHNvIGFjdGl2ZSBwb3RzLCBoZWxkIGJvbmRzIGFuZCBmZWVzIHN0YXkgY292ZXJlZC4AAAAAAA9yZWN1cGVyYXJfdG9rZW4AAAAAAwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAUFDaGVjayBhIHNlc3Npb24ncyBzdGF0ZSBpbnZhcmlhbnRzIChmb3IgZnV6emluZyBhbmQgZGVidWdnaW5nKToKc2VhdCBpbmRleCBtYXRjaGVzIGBwbGF5ZXJzYCwgZWxpbWluYXRlZCBwbGF5ZXJzIGFyZSBleGFjdGx5IHRoZQpkZWFkIG9uZXMsIHRoZSB0dXJuIHBvaW50cyBhdCBhIGxpdmluZyBwbGF5ZXIgYW5kIHRoZSBjaGFtYmVyIGFuZApidWxsZXQgYXJlIGluIHJhbmdlIHdoaWxlIHBsYXlpbmcsIGFuZCBhIHdpbm5lciBleGlzdHMgaWZmIHRoZQpnYW1lIGlzIGZpbmlzaGVkLiBSZXR1cm5zIGBJbmNvbnNpc3RlbnRTdGF0ZWAgb24gYW55IHZpb2xhdGlvbi4AAAAAAAAQY2hlY2tfaW52YXJpYW50cwAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAMJBbnlvbmUgbWF5IGNhbGwgdGhpcyBvbmNlIHRoZSBjdXJyZW50IHR1cm4ncyBkZWFkbGluZSBoYXMgcGFzc2VkLgpUaGUgQUZLIHBsYXllciBpcyBlbGltaW5hdGVkIGFuZCB0aGVpciBib25kIGdvZXMgdG8gdGhlIHBvdDsgdGhlCm5leHQgcGxheWVyIGZhY2VzIHRoZSBzYW1lIGNoYW1iZXIuIFJldHVybnMgdGhlIGtpY2tlZCBhZGRyZXNzLgAAAAAAEGV4cHVsc2FyX3Bvcl9hZmsAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAAaJUaGUgc2VlZCB0aGUgY3VycmVudCByb3VuZCdzIGJ1bGxldCB3YXMgZGVyaXZlZCBmcm9tOgpgU0hBMjU2KHNlc3Npb25faWRfYmUgfHwgc2hvdHNfZmlyZWRfYmUpYCwgd2l0aCBgc2hvdHNfZmlyZWRgIGFzIG9mCnRoZSBoaXQgdGhhdCB0cmlnZ2VyZWQgdGhlIHJlbG9hZCB0aGF0IGxvYWRlZCB0aGUgbGl2ZSBidWxsZXQgKGEKaGl0IHN0aWxsIGF3YWl0aW5nIGNvbmZpcm1hdGlvbiwgb3Igb25lIHRoYXQgZW5kZWQgdGhlIGdhbWUsIGhhc24ndApyZWxvYWRlZCkuIEluIHJlc2V0IG1vZGUgd2l0aCBvbmUgYnVsbGV0LApgYnVsbGV0X3Bvc2l0aW9uID09IHNlZWRbMF0gJSBudW1fY2hhbWJlcnNgLiBTdGF0aWMgbW9kZSBvbmx5LCBhbmQKb25seSBhZnRlciBhIHJlbG9hZCAodGhlIGhvc3QgcGlja3Mgcm91bmQgMCdzIGJ1bGxldCkuAAAAAAAQZ2V0X2N1cnJlbnRfc2VlZAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAA+4AAAAgAAAAAw==",
+        "AAAAAAAAAJpXYWl0aW5nIHNlc3Npb25zIHdpdGggYSBmcmVlIHNlYXQsIG9sZGVzdCBmaXJzdCwgZm9yIGxvYmJ5CmJyb3dzZXJzLiBJZHMgd2hvc2UgbG9iYnkgZXhwaXJlZCwgc3RhcnRlZCBvciBjbG9zZWQgcmVnaXN0cmF0aW9uCmFyZSBwcnVuZWQgYXMgdGhleSBhcmUgZm91bmQuAAAAAAAQZ2V0X29wZW5fbG9iYmllcwAAAAAAAAABAAAD6gAAAAQ=",
+        "AAAAAAAAADlBIHBsYXllcidzIGdhbWVzIHBsYXllZCBhbmQgd2lucyBhY3Jvc3MgZmluaXNoZWQgc2Vzc2lvbnMAAAAAAAAQZ2V0X3BsYXllcl9zdGF0cwAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAfQAAAAC1BsYXllclN0YXRzAA==",
+        "AAAAAAAAAENOZXQgYW1vdW50IHN0YWtlZCBpbiBgdG9rZW5gIGFjcm9zcyBhbGwgc2Vzc2lvbnMgKHJlZnVuZHMgZXhjbHVkZWQpAAAAABBnZXRfdG9rZW5fdm9sdW1lAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAHVTdGF0ZS1jaGFuZ2luZyBjYWxscyBlYWNoIHBsYXllciBoYXMgbWFkZSBpbiB0aGUgc2Vzc2lvbiwgZm9yCm9yZ2FuaXplcnMgcmVjb25jaWxpbmcgc3BvbnNvcmVkIGZlZXMuIEFjY291bnRpbmcgb25seS4AAAAAAAARZ2V0X2FjdGlvbl9jb3VudHMAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAPqAAAD7QAAAAIAAAATAAAABAAAAAM=",
+        "AAAAAAAAAC1IdWJzIGBjYXJnYXJfcmV2b2x2ZXJgIG1heSByb3V0ZSBhIHNlc3Npb24gdG8AAAAAAAARZ2V0X2FwcHJvdmVkX2h1YnMAAAAAAAAAAAAAAQAAA+oAAAAT",
+        "AAAAAAAAAD1UaGUgc2FsdCB0aGUgaG9zdCBwdWJsaXNoZWQgd2l0aCBgcmV2ZWxhcl9iYWxhYCwgZm9yIGF1ZGl0b3JzAAAAAAAAEWdldF9yZXZlYWxlZF9zYWx0AAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAD7gAAACAAAAAD",
+        "AAAAAAAAADpIb3cgbWFueSBzaG90cyB0aGUgd2lubmVyIHN1cnZpdmVkLCBmb3IgdGhlIHJlc3VsdHMgc2NyZWVuAAAAAAARZ2V0X3dpbm5lcl9tYXJnaW4AAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAMJNaWdyYXRpb24tc2FmZXR5IHRvb2w6IGlmIGFuIGluLWZsaWdodCBnYW1lJ3MgYGN1cnJlbnRfdHVybmAgbm8KbG9uZ2VyIHBvaW50cyBhdCBhIGxpdmluZyBwbGF5ZXIgKGUuZy4gc3RvcmVkIHVuZGVyIG9sZGVyIHR1cm4KbG9naWMpLCBtb3ZlIGl0IHRvIHRoZSBuZXh0IGFsaXZlIHNlYXQuIFJldHVybnMgdGhlIHJlc3VsdGluZyB0dXJuLgAAAAAAEXJlY29uY2lsaWFyX3R1cm5vAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAKBJbnN0YWxsIHRoZSBHcm90aDE2IHZlcmlmeWluZyBrZXkgdXNlZCBieSBgUFJPT0ZfU1lTVEVNX0dST1RIMTZgCnNlc3Npb25zLiBJdCBtdXN0IGNhcnJ5IG9uZSBJQyBwb2ludCBwZXIgcHVibGljIGlucHV0IHBsdXMgb25lLCBhbGwKb2YgaXRzIHBvaW50cyBvbiB0aGUgY3VydmUuAAAAEXNldF92ZXJpZnlpbmdfa2V5AAAAAAAAAQAAAAAAAAACdmsAAAAAB9AAAAAMVmVyaWZ5aW5nS2V5AAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAONFdmVyeXRoaW5nIHRoZSBjb250cmFjdCBvd2VzLCBwZXIgdG9rZW4sIGFjcm9zcyB0aGUgYWN0aXZlLXNlc3Npb24KaW5kZXg6IHBvdHMgKHN0YWtlcywgYW50ZXMsIGluc3VyYW5jZSBwcmVtaXVtcyBhbmQgZm9yZmVpdGVkIGJvbmRzKQpwbHVzIGJvbmRzIHN0aWxsIGhlbGQgZm9yIHBsYXllcnMuIFNob3VsZCBuZXZlciBleGNlZWQgdGhlCmNvbnRyYWN0J3MgYmFsYW5jZSBvZiBlYWNoIHRva2VuLgAAAAARdG90YWxfbGlhYmlsaXRpZXMAAAAAAAAAAAAAAQAAA+kAAAPsAAAAEwAAAAsAAAAD",
+        "AAAAAAAAAH5QdWxscyBsZWZ0IGluIHRoZSBjdXJyZW50IGN5bGluZGVyIGJlZm9yZSBpdCBoYXMgdG8gYmUgcmVsb2FkZWQsCmJ1bGxldCBvciBub3QuIFJldmVhbHMgbm90aGluZyBhYm91dCB0aGUgYnVsbGV0LCBzbyBhbnkgbW9kZS4AAAAAABJjaGFtYmVyc19yZW1haW5pbmcAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAKVUaGUgaG9zdCAoZmlyc3QgcGxheWVyKSBjaG9vc2VzIHRoZSBzZXNzaW9uIHJ1bGVzLiBPbmx5IGFsbG93ZWQKd2hpbGUgdGhlIGhvc3QgaXMgYWxvbmUgaW4gdGhlIGxvYmJ5LCBzbyBub2JvZHkgam9pbnMgdW5kZXIgb25lCnNldCBvZiBydWxlcyBhbmQgcGxheXMgdW5kZXIgYW5vdGhlci4AAAAAAAASY29uZmlndXJhcl9wYXJ0aWRhAAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAARob3N0AAAAEwAAAAAAAAAGY29uZmlnAAAAAAfQAAAADVNlc3Npb25Db25maWcAAAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAPJSZWdpc3RlciBhIHBsYXllciBpbnRvIHRoZSBzZXNzaW9uLiBTdXBwb3J0cyAyLTMgcGxheWVycy4KVGhlIGhvc3QgbXVzdCBjYWxsIGBjYXJnYXJfcmV2b2x2ZXJgIG9uY2UgZW5vdWdoIHBsYXllcnMgaGF2ZSBqb2luZWQuCmBhdmF0YXJgIHBpY2tzIGEgdmlzdWFsIHNsb3QgdGhhdCBubyBvdGhlciBwbGF5ZXIgaW4gdGhlIHNlc3Npb24gaG9sZHMuCmBwb2ludHNgIG11c3QgYmUgaW4gYDAuLj1NQVhfSk9JTl9QT0lOVFNgLgAAAAAAEmVudHJhcl9hX2xhX3J1bGV0YQAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZwb2ludHMAAAAAAAsAAAAAAAAABmF2YXRhcgAAAAAABAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAALNUaGUgY3lsaW5kZXIgYXMgZHJhd24gYnkgYSB3aWRnZXQuIENoYW1iZXJzIGJlZm9yZQpgY3VycmVudF9jaGFtYmVyYCBoYXZlIGJlZW4gZmlyZWQsIGluY2x1ZGluZyB0aG9zZSBhIGNvbnRpbnVlLW1vZGUKcmVsb2FkIHNraXBwZWQgcGFzdDsgdGhlIGJ1bGxldCBpcyBzaG93biBpbiBzdGF0aWMgbW9kZSBvbmx5LgAAAAASZ2V0X2N5bGluZGVyX3N0YXRlAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAADUN5bGluZGVyU3RhdGUAAAAAAAAD",
+        "AAAAAAAAAFtUaGUgcmV2ZWFsZWQgY29tbWl0bWVudCBvcGVuaW5nLCBwYWNrYWdlZCBmb3Igc2hhcmluZy4gT25seQphdmFpbGFibGUgYWZ0ZXIgYHJldmVsYXJfYmFsYWAuAAAAABJnZXRfZmFpcm5lc3NfcHJvb2YAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAANRmFpcm5lc3NQcm9vZgAAAAAAAAM=",
+        "AAAAAAAAALhFdmVyeSBjaGFtYmVyIGxvYWRlZCBpbiBgcm91bmRgICgwIGlzIHRoZSBob3N0J3MgbG9hZCwgdGhlbiBvbmUgcGVyCnJlbG9hZCksIGluIGFzY2VuZGluZyBvcmRlci4gQXZhaWxhYmxlIG9uY2UgdGhlIHJvdW5kIGlzIG92ZXI7CmhpZGRlbi1tb2RlIGdhbWVzIGRpc2Nsb3NlIG5vdGhpbmcgdW50aWwgdGhleSBmaW5pc2guAAAAEmdldF9mYXRhbF9jaGFtYmVycwAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAFcm91bmQAAAAAAAAEAAAAAQAAA+kAAAPqAAAABAAAAAM=",
+        "AAAAAAAAAINgKHBoYXNlLCBsZWRnZXIpYCBmb3IgZXZlcnkgcGhhc2UgdGhlIHNlc3Npb24gaGFzIGVudGVyZWQsIGluIHBoYXNlCm9yZGVyIOKAlCBlLmcuIGhvdyBsb25nIHRoZSBsb2JieSB3YWl0ZWQgYW5kIGhvdyBsb25nIHBsYXkgdG9vawAAAAASZ2V0X3BoYXNlX3RpbWVsaW5lAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAPqAAAD7QAAAAIAAAAEAAAABAAAAAM=",
+        "AAAAAAAAAERUaGUgcnVsZXMgYSBsb2JieSB3YXMgY29uZmlndXJlZCB3aXRoLCB3aXRob3V0IHRoZSByZXN0IG9mIHRoZSBzdGF0ZQAAABJnZXRfc2Vzc2lvbl9jb25maWcAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAANU2Vzc2lvbkNvbmZpZwAAAAAAAAM=",
+        "AAAAAAAAALhMZWF2ZSBhIGxvYmJ5IHRoYXQgaGFzbid0IHN0YXJ0ZWQgeWV0LiBUaGUgcGxheWVyJ3MgYm9uZCBpcwpyZWZ1bmRlZC4gSWYgdGhlIGhvc3QgbGVhdmVzLCB0aGUgbmV4dCBwbGF5ZXIgYmVjb21lcyBob3N0OyBhbgplbXB0aWVkIGxvYmJ5IGlzIGRlbGV0ZWQuIFJldHVybnMgdGhlIHJlbWFpbmluZyBwbGF5ZXIgY291bnQuAAAAEnNhbGlyX2RlX2xhX3J1bGV0YQAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAL1QcmUtZmxpZ2h0IGZvciBgZGlzcGFyYXJgOiB3b3VsZCB0aGlzIHByb29mIHBhc3MgdGhlIHByb29mIGdhdGUgZm9yCnRoZSBjaGFtYmVyIGFib3V0IHRvIGJlIGZpcmVkPyBBIG1hbGZvcm1lZCwgb3ZlcnNpemVkIG9yIG1pc21hdGNoZWQKcHJvb2YgaXMgYGZhbHNlYDsgZ2FtZXMgbm90IGluIHBsYXkgYXJlIGBXcm9uZ1BoYXNlYC4AAAAAAAASd291bGRfYWNjZXB0X3Byb29mAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAh6a19wcm9vZgAAAA4AAAABAAAD6QAAAAEAAAAD",
+        "AAAAAAAAASRMaXZlLXJlcGFpciB0b29sOiBzd2l0Y2ggYW4gaW4tcHJvZ3Jlc3MgZ2FtZSdzIGByZWxvYWRfbW9kZWAuIFRoZQpjaGFuZ2UgYXBwbGllcyBmcm9tIHRoZSBuZXh0IHJlbG9hZDsgaWYgdGhlIGN1cnJlbnQgcm91bmQncwpjaGFtYmVyIG9yIGJ1bGxldCBpcyBvdXQgb2Ygc3RlcCAodGhlIGJ1bGxldCBhbHJlYWR5IHBhc3NlZCwgb3IKZWl0aGVyIGJleW9uZCB0aGUgY3lsaW5kZXIpLCB0aGUgcm91bmQgcmVzdGFydHMgYXQgY2hhbWJlciAwLgpSZXR1cm5zIHRoZSByZXN1bHRpbmcgYGN1cnJlbnRfY2hhbWJlcmAuAAAAE2NhbWJpYXJfcmVsb2FkX21vZGUAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAIbmV3X21vZGUAAAAEAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAALNXaGV0aGVyIG5vIHNlc3Npb24gaGFzIGxvYWRlZCBgY29tbWl0bWVudGAgeWV0LiBPbmx5IGNvbW1pdG1lbnRzCmxvYWRlZCB3aGlsZSBgcmVqZWN0X3JldXNlZF9jb21taXRtZW50c2AgaXMgb24gYXJlIHRyYWNrZWQsIGFuZApvbmx5IHRoZSBsYXRlc3QgYE1BWF9UUkFDS0VEX0NPTU1JVE1FTlRTYCBvZiB0aGVtLgAAAAATY29tbWl0bWVudF9pc19mcmVzaAAAAAABAAAAAAAAAApjb21taXRtZW50AAAAAAPuAAAAIAAAAAEAAAAB",
+        "AAAAAAAAAGRTSEEyNTYgY29tbWl0bWVudCBoZWxwZXIgKGZvciBvZmYtY2hhaW4gdXNlIGFuZCB0ZXN0aW5nKQpSZXR1cm5zIFNIQTI1NihzYWx0X2J5dGVzIHx8IHBvc2l0aW9uX2J5dGUpAAAAE2NvbXB1dGVfYnVsbGV0X2hhc2gAAAAAAgAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAAAAAAhwb3NpdGlvbgAAAAQAAAABAAAD7gAAACA=",
+        "AAAAAAAAAK9FdmVyeSBidWxsZXQgcG9zaXRpb24gdGhlIHNlc3Npb24gdXNlZCwgaW4gb3JkZXI6IHRoZSBob3N0J3MKaW5pdGlhbCBwb3NpdGlvbiBmb2xsb3dlZCBieSBlYWNoIGF1dG8tcmVsb2FkJ3MgU0hBMjU2LWRlcml2ZWQKcG9zaXRpb24uIE9ubHkgYXZhaWxhYmxlIG9uY2UgdGhlIGdhbWUgaXMgZmluaXNoZWQuAAAAABNnZXRfYnVsbGV0X3NlcXVlbmNlAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAA+oAAAAEAAAAAw==",
+        "AAAAAAAAAMhXaGF0IGVhY2ggc2VhdGVkIHBsYXllciBtdXN0IGRvIG5leHQsIGluIHNlYXQgb3JkZXIsIHNvIGEgVUkgY2FuCnNob3cgd2hhdCBpcyBob2xkaW5nIHRoZSBnYW1lIHVwIChgQUNUSU9OXypgKS4gV2hpbGUgdGhlIGhvc3Qgb3dlcwphIHJlbG9hZCBvciBhIGhpdCBpcyBwZW5kaW5nIGNvbmZpcm1hdGlvbiwgbm9ib2R5IGlzIGFza2VkIHRvIHNob290LgAAABNnZXRfcGVuZGluZ19hY3Rpb25zAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAA+oAAAPtAAAAAgAAABMAAAAEAAAAAw==",
+        "AAAAAAAAANhJZiB0aGUgaG9zdCBoYXNuJ3QgY2FsbGVkIGByZWNhcmdhcmAgYnkgdGhlIHJlbG9hZCBkZWFkbGluZSwgYW55CmxpdmluZyBwbGF5ZXIgbWF5IHJlbG9hZCBpbnN0ZWFkLiBUaGUgYnVsbGV0IGlzIGRlcml2ZWQgb24tY2hhaW4KZXhhY3RseSBhcyBpbiBhIHNlc3Npb24gd2l0aG91dCBhIHByZWxvYWRlZCBzZXF1ZW5jZS4gUmV0dXJucyB0aGUKbmV3IGJ1bGxldCBwb3NpdGlvbi4AAAAUcmVjYXJnYXJfcG9yX2RlZmVjdG8AAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAARlBZG1pbiBkaXNwdXRlIHRvb2w6IGVuZCBhbiBpbi1wcm9ncmVzcyBnYW1lIHdpdGggYSBkZWNsYXJlZAp3aW5uZXIgKHJlcG9ydGVkIHRvIHRoZSBodWIgYXMgdXN1YWwpIGFuZCBzZXR0bGUgdGhlIHBvdCBwZXIKYHJlc29sdXRpb25fbW9kZWAgKGBSRVNPTFZFXypgKS4gV2hhdGV2ZXIgdGhlIHJlZnVuZCBvciBzcGxpdApsZWF2ZXMsIHN1Y2ggYXMgZm9yZmVpdGVkIGJvbmRzIG9yIHJvdW5kaW5nIGR1c3QsIGZvbGxvd3MgdGhlCnNlc3Npb24ncyBwYXlvdXQgbW9kZSB0byB0aGUgd2lubmVyLgAAAAAAABRyZXNvbHZlcl9tYW51YWxtZW50ZQAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABndpbm5lcgAAAAAAEwAAAAAAAAAPcmVzb2x1dGlvbl9tb2RlAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAW1XaGV0aGVyIGEgZ2FtZSB3aXRoIHRoZXNlIHBhcmFtZXRlcnMgbXVzdCBlbmQgd2l0aCBhIHdpbm5lciwgZm9yCmhvc3RzIGNoZWNraW5nIHRoZWlyIHNldHRpbmdzIGJlZm9yZSBsb2FkaW5nLiBUcnVlIHdoZW4gdGhlcmUgaXMKYXQgbGVhc3Qgb25lIGJ1bGxldCB0aGF0IGZpdHMgdGhlIGN5bGluZGVyLCB0aGUgcmVsb2FkIG1vZGUgaXMKa25vd24gKGJvdGggbW9kZXMgYWx3YXlzIHJlbG9hZCBhIGJ1bGxldCBpbnRvIGEgY2hhbWJlciBzdGlsbCB0bwpiZSBmaXJlZCkgYW5kIGF0IGxlYXN0IHR3byBwbGF5ZXJzIGFyZSBzZWF0ZWQuIEV2ZXJ5IGhpdCB0aGVuCnJlbW92ZXMgYSBwbGF5ZXIgd2l0aGluIGBudW1fY2hhbWJlcnNgIHB1bGxzLgAAAAAAABVjb25maWdfaXNfdGVybWluYXRpbmcAAAAAAAAEAAAAAAAAAAxudW1fY2hhbWJlcnMAAAAEAAAAAAAAAAtudW1fYnVsbGV0cwAAAAAEAAAAAAAAAAtyZWxvYWRfbW9kZQAAAAAEAAAAAAAAAAtudW1fcGxheWVycwAAAAAEAAAAAQAAAAE=",
+        "AAAAAAAAAL1BbnlvbmUgbWF5IGNhbGwgdGhpcyBvbmNlIGEgcGVuZGluZyBoaXQncyBkZWxheSBoYXMgcGFzc2VkLiBUaGUKaGl0IHBsYXllciBpcyBlbGltaW5hdGVkIGFuZCB0aGUgZ2FtZSByZWxvYWRzIG9yIGVuZHMgZXhhY3RseSBhcwphbiBpbW1lZGlhdGUgaGl0IHdvdWxkIGhhdmUuIFJldHVybnMgdGhlIGVsaW1pbmF0ZWQgYWRkcmVzcy4AAAAAAAAVY29uZmlybWFyX2VsaW1pbmFjaW9uAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAAFpVbmZpbmlzaGVkIHNlc3Npb25zIHRoZSBwbGF5ZXIgaXMgc2VhdGVkIGluLCBhcyBjaGVja2VkIGFnYWluc3QKYG1heF9hY3RpdmVfZ2FtZXNgIG9uIGpvaW4AAAAAABVnZXRfYWN0aXZlX2dhbWVfY291bnQAAAAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAABA==",
+        "AAAAAAAAAEpMZWRnZXIgb2YgdGhlIHNlc3Npb24ncyBtb3N0IHJlY2VudCBzdGF0ZSBjaGFuZ2UsIGZvciBzdGFsZW5lc3MKbW9uaXRvcmluZwAAAAAAFmdldF9sYXN0X2FjdGlvbl9sZWRnZXIAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAALNUaGUgcG9zaXRpb24gYSByZXNldC1tb2RlLCBvbmUtYnVsbGV0IHJlbG9hZCBwaWNrczoKYFNIQTI1NihzZXNzaW9uX2lkX2JlIHx8IHNob3RzX2ZpcmVkX2JlKVswXSAlIG51bV9jaGFtYmVyc2AsIHdpdGgKYHNob3RzX2ZpcmVkYCBhcyBvZiB0aGUgaGl0LiBMZXRzIGNsaWVudHMgcmUtZGVyaXZlIGEgcmVsb2FkLgAAAAAXY29tcHV0ZV9yZWxvYWRfcG9zaXRpb24AAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAALc2hvdHNfZmlyZWQAAAAABAAAAAAAAAAMbnVtX2NoYW1iZXJzAAAABAAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAIFQdWxscyBsZWZ0IGJlZm9yZSB0aGUgYnVsbGV0IGluIHRoZSBjdXJyZW50IHJvdW5kICgiTiBjbGlja3MgdG8KYm9vbSIpLiBTdGF0aWMgbW9kZSBvbmx5LCBzaW5jZSBpdCByZXZlYWxzIHRoZSBidWxsZXQncyBkaXN0YW5jZS4AAAAAAAAXc2FmZV9jaGFtYmVyc19yZW1haW5pbmcAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAABAAAAAM=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    get_hub: this.txFromJSON<string>,
+    add_hub: this.txFromJSON<Result<void>>,
+        apostar: this.txFromJSON<Result<i128>>,
+        get_hub: this.txFromJSON<string>,
         set_hub: this.txFromJSON<null>,
         upgrade: this.txFromJSON<null>,
-        disparar: this.txFromJSON<Result<boolean>>,
+        disparar: this.txFromJSON<Result<u32>>,
         get_game: this.txFromJSON<Result<PartidaRuleta>>,
+        get_host: this.txFromJSON<Result<string>>,
+        get_seat: this.txFromJSON<Result<u32>>,
+        is_alive: this.txFromJSON<Result<boolean>>,
+        recargar: this.txFromJSON<Result<u32>>,
         get_admin: this.txFromJSON<string>,
+        get_round: this.txFromJSON<Result<u32>>,
         set_admin: this.txFromJSON<null>,
+        get_config: this.txFromJSON<Config>,
+        get_winner: this.txFromJSON<Result<Option<string>>>,
+        pagar_ante: this.txFromJSON<Result<i128>>,
+        parse_game: this.txFromJSON<Result<PartidaRuleta>>,
+        remove_hub: this.txFromJSON<Result<void>>,
+        set_config: this.txFromJSON<Result<void>>,
+        get_version: this.txFromJSON<u32>,
+        pedir_tiempo: this.txFromJSON<Result<u32>>,
+        revelar_bala: this.txFromJSON<Result<void>>,
+        votar_saltar: this.txFromJSON<Result<boolean>>,
         who_is_alive: this.txFromJSON<Result<Array<string>>>,
+        get_multi_pot: this.txFromJSON<Result<Array<readonly [string, i128]>>>,
+        reset_session: this.txFromJSON<Result<void>>,
+        tiro_de_honor: this.txFromJSON<Result<boolean>>,
+        validate_shot: this.txFromJSON<Result<void>>,
+        withdraw_fees: this.txFromJSON<Result<i128>>,
+        comprar_seguro: this.txFromJSON<Result<void>>,
+        get_round_info: this.txFromJSON<Result<RoundInfo>>,
+        session_exists: this.txFromJSON<boolean>,
+        subscribe_info: this.txFromJSON<Result<ObserverInfo>>,
+        abortar_partida: this.txFromJSON<Result<string>>,
+        auditar_partida: this.txFromJSON<Result<boolean>>,
         cargar_revolver: this.txFromJSON<Result<void>>,
+        get_fee_balance: this.txFromJSON<i128>,
+        get_hub_pairing: this.txFromJSON<Result<readonly [string, string]>>,
+        get_player_wins: this.txFromJSON<Array<u32>>,
+        get_shots_fired: this.txFromJSON<Result<u32>>,
+        reclamar_premio: this.txFromJSON<Result<Array<readonly [string, i128]>>>,
+        recuperar_token: this.txFromJSON<Result<void>>,
+        check_invariants: this.txFromJSON<Result<void>>,
+        expulsar_por_afk: this.txFromJSON<Result<string>>,
+        get_current_seed: this.txFromJSON<Result<Buffer>>,
+        get_open_lobbies: this.txFromJSON<Array<u32>>,
+        get_player_stats: this.txFromJSON<PlayerStats>,
+        get_token_volume: this.txFromJSON<i128>,
+        get_action_counts: this.txFromJSON<Result<Array<readonly [string, u32]>>>,
+        get_approved_hubs: this.txFromJSON<Array<string>>,
+        get_revealed_salt: this.txFromJSON<Result<Buffer>>,
+        get_winner_margin: this.txFromJSON<Result<u32>>,
+        reconciliar_turno: this.txFromJSON<Result<u32>>,
+        set_verifying_key: this.txFromJSON<Result<void>>,
+        total_liabilities: this.txFromJSON<Result<Map<string, i128>>>,
+        chambers_remaining: this.txFromJSON<Result<u32>>,
+        configurar_partida: this.txFromJSON<Result<void>>,
         entrar_a_la_ruleta: this.txFromJSON<Result<u32>>,
-        compute_bullet_hash: this.txFromJSON<Buffer>
+        get_cylinder_state: this.txFromJSON<Result<CylinderState>>,
+        get_fairness_proof: this.txFromJSON<Result<FairnessProof>>,
+        get_fatal_chambers: this.txFromJSON<Result<Array<u32>>>,
+        get_phase_timeline: this.txFromJSON<Result<Array<readonly [u32, u32]>>>,
+        get_session_config: this.txFromJSON<Result<SessionConfig>>,
+        salir_de_la_ruleta: this.txFromJSON<Result<u32>>,
+        would_accept_proof: this.txFromJSON<Result<boolean>>,
+        cambiar_reload_mode: this.txFromJSON<Result<u32>>,
+        commitment_is_fresh: this.txFromJSON<boolean>,
+        compute_bullet_hash: this.txFromJSON<Buffer>,
+        get_bullet_sequence: this.txFromJSON<Result<Array<u32>>>,
+        get_pending_actions: this.txFromJSON<Result<Array<readonly [string, u32]>>>,
+        recargar_por_defecto: this.txFromJSON<Result<u32>>,
+        resolver_manualmente: this.txFromJSON<Result<void>>,
+        config_is_terminating: this.txFromJSON<boolean>,
+        confirmar_eliminacion: this.txFromJSON<Result<string>>,
+        get_active_game_count: this.txFromJSON<u32>,
+        get_last_action_ledger: this.txFromJSON<Result<u32>>,
+        compute_reload_position: this.txFromJSON<Result<u32>>,
+        safe_chambers_remaining: this.txFromJSON<Result<u32>>
   }
 }
\ No newline at end of file
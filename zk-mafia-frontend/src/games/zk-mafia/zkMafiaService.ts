@@ -17,6 +17,12 @@ export const PHASE_LABELS: Record<number, string> = {
   [PHASE_FINISHED]: '💀 Finished',
 };
 
+// Shot outcome codes returned by disparar (SHOT_* in the contract)
+export const SHOT_SURVIVED = 0;
+export const SHOT_HIT = 1;
+export const SHOT_PROOF_REJECTED = 2;
+export const SHOT_KICKED = 3;
+
 /** Game state from the contract */
 export interface PartidaRuleta {
   players: Jugador[];
@@ -140,11 +146,12 @@ export class ZkMafiaService {
   // Transaction methods
   // ========================================================================
 
-  /** Join the lobby: entrar_a_la_ruleta */
+  /** Join the lobby: entrar_a_la_ruleta (avatar must be free in the session) */
   async entrarALaRuleta(
     sessionId: number,
     player: string,
     points: bigint,
+    avatar: number,
     signer: Pick<ClientOptions, 'signTransaction' | 'signAuthEntry'>
   ) {
     const client = this.createSigningClient(player, signer);
@@ -152,18 +159,20 @@ export class ZkMafiaService {
       session_id: sessionId,
       player,
       points,
+      avatar,
     }, DEFAULT_METHOD_OPTIONS);
 
     const validUntil = await calculateValidUntilLedger(RPC_URL, 5);
     return await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntil);
   }
 
-  /** Host loads the revolver: cargar_revolver */
+  /** Host loads the revolver: cargar_revolver (salt must open the commitment) */
   async cargarRevolver(
     sessionId: number,
     player: string,
     bulletCommitment: Buffer,
     bulletPosition: number,
+    salt: Buffer,
     signer: Pick<ClientOptions, 'signTransaction' | 'signAuthEntry'>
   ) {
     const client = this.createSigningClient(player, signer);
@@ -172,19 +181,22 @@ export class ZkMafiaService {
       player,
       bullet_commitment: bulletCommitment,
       bullet_position: bulletPosition,
+      salt,
+      hub_override: undefined,
+      shuffle_seating: false,
     }, DEFAULT_METHOD_OPTIONS);
 
     const validUntil = await calculateValidUntilLedger(RPC_URL, 5);
     return await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntil);
   }
 
-  /** Pull the trigger: disparar (contract determines hit/miss via static cylinder) */
+  /** Pull the trigger: disparar. Resolves to the contract's SHOT_* outcome code. */
   async disparar(
     sessionId: number,
     player: string,
     zkProof: Buffer,
     signer: Pick<ClientOptions, 'signTransaction' | 'signAuthEntry'>
-  ) {
+  ): Promise<number> {
     const client = this.createSigningClient(player, signer);
     const tx = await client.disparar({
       session_id: sessionId,
@@ -193,6 +205,7 @@ export class ZkMafiaService {
     }, DEFAULT_METHOD_OPTIONS);
 
     const validUntil = await calculateValidUntilLedger(RPC_URL, 5);
-    return await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntil);
+    const sent = await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntil);
+    return sent.result.unwrap();
   }
 }